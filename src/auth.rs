@@ -1,41 +1,144 @@
 use std::str::FromStr;
 
+use alloy::consensus::SignableTransaction;
+use alloy::network::{EthereumWallet, TxSigner};
+use alloy::primitives::{Address, B256, ChainId, Signature};
 use alloy::providers::ProviderBuilder;
+use alloy::signers::local::PrivateKeySigner;
+use alloy::sol;
 use anyhow::{Context, Result};
+use async_trait::async_trait;
 use polymarket_client_sdk::auth::state::Authenticated;
-use polymarket_client_sdk::auth::{LocalSigner, Normal, Signer as _};
-use polymarket_client_sdk::clob::types::SignatureType;
-use polymarket_client_sdk::{POLYGON, clob};
+use polymarket_client_sdk::auth::{LocalSigner, Normal, Signer};
+use polymarket_client_sdk::clob::types::{Order, SignableOrder, SignatureType, SignedOrder};
+use polymarket_client_sdk::{clob, derive_proxy_wallet};
 
-use crate::config;
+use crate::config::{self, KeySource};
+use crate::error::CliError;
+use crate::remote_signer::RemoteSigner;
 
-const DEFAULT_RPC_URL: &str = "https://polygon.drpc.org";
+sol! {
+    #[sol(rpc)]
+    interface ISafe {
+        function isOwner(address owner) external view returns (bool);
+    }
+}
 
 fn rpc_url() -> String {
-    std::env::var("POLYMARKET_RPC_URL").unwrap_or_else(|_| DEFAULT_RPC_URL.to_string())
+    config::settings().rpc_url().to_string()
+}
+
+/// An unauthenticated CLOB client for the configured endpoint.
+pub fn clob_client() -> Result<clob::Client> {
+    let url = config::settings().clob_url();
+    clob::Client::new(&url, clob::Config::default()).context(CliError::invalid_argument(format!(
+        "Invalid clob_url `{url}`"
+    )))
 }
 
-fn parse_signature_type(s: &str) -> SignatureType {
-    match s {
-        config::DEFAULT_SIGNATURE_TYPE => SignatureType::Proxy,
-        "gnosis-safe" => SignatureType::GnosisSafe,
-        _ => SignatureType::Eoa,
+impl From<config::SignatureType> for SignatureType {
+    fn from(signature_type: config::SignatureType) -> Self {
+        match signature_type {
+            config::SignatureType::Eoa => Self::Eoa,
+            config::SignatureType::Proxy => Self::Proxy,
+            config::SignatureType::GnosisSafe => Self::GnosisSafe,
+        }
     }
 }
 
-pub fn resolve_signer(
-    private_key: Option<&str>,
-) -> Result<impl polymarket_client_sdk::auth::Signer> {
-    let (key, _) = config::resolve_key(private_key)?;
-    let key = key.ok_or_else(|| anyhow::anyhow!("{}", config::NO_WALLET_MSG))?;
+/// Signs orders and transactions with a local key or through a remote signer.
+pub enum WalletSigner {
+    Local(PrivateKeySigner),
+    Remote(RemoteSigner),
+}
+
+impl WalletSigner {
+    /// Tell a remote signer `order` is about to be signed.
+    fn expect_order(&self, order: &Order) {
+        if let Self::Remote(signer) = self {
+            signer.expect_order(order);
+        }
+    }
+}
+
+#[async_trait]
+impl Signer for WalletSigner {
+    async fn sign_hash(&self, hash: &B256) -> alloy::signers::Result<Signature> {
+        match self {
+            Self::Local(signer) => signer.sign_hash(hash).await,
+            Self::Remote(signer) => signer.sign_hash(hash).await,
+        }
+    }
+
+    fn address(&self) -> Address {
+        match self {
+            Self::Local(signer) => Signer::address(signer),
+            Self::Remote(signer) => Signer::address(signer),
+        }
+    }
+
+    fn chain_id(&self) -> Option<ChainId> {
+        match self {
+            Self::Local(signer) => signer.chain_id(),
+            Self::Remote(signer) => signer.chain_id(),
+        }
+    }
+
+    fn set_chain_id(&mut self, chain_id: Option<ChainId>) {
+        match self {
+            Self::Local(signer) => signer.set_chain_id(chain_id),
+            Self::Remote(signer) => signer.set_chain_id(chain_id),
+        }
+    }
+}
+
+#[async_trait]
+impl TxSigner<Signature> for WalletSigner {
+    fn address(&self) -> Address {
+        Signer::address(self)
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut dyn SignableTransaction<Signature>,
+    ) -> alloy::signers::Result<Signature> {
+        match self {
+            Self::Local(signer) => signer.sign_transaction(tx).await,
+            Self::Remote(signer) => signer.sign_transaction(tx).await,
+        }
+    }
+}
+
+pub fn resolve_signer(private_key: Option<&str>) -> Result<WalletSigner> {
+    if let (None, KeySource::RemoteSigner) = config::resolve_key(private_key)? {
+        let profile = config::load_profile()?.expect("remote signer source implies a profile");
+        let (Some(url), Some(address)) = (profile.signer_url, profile.address) else {
+            anyhow::bail!(CliError::invalid_argument(
+                "A remote signer profile needs an `address`"
+            ));
+        };
+        let signer = RemoteSigner::new(url, address).with_chain_id(Some(config::chain().id()));
+        return Ok(WalletSigner::Remote(signer));
+    }
+    let key = config::require_key(private_key)?;
     LocalSigner::from_str(&key)
-        .context("Invalid private key")
-        .map(|s| s.with_chain_id(Some(POLYGON)))
+        .context(CliError::auth("Invalid private key"))
+        .map(|s| WalletSigner::Local(s.with_chain_id(Some(config::chain().id()))))
+}
+
+/// Sign `order` for `client`, letting a remote signer know what it signs.
+pub async fn sign_order(
+    client: &clob::Client<Authenticated<Normal>>,
+    signer: &WalletSigner,
+    order: SignableOrder,
+) -> Result<SignedOrder> {
+    signer.expect_order(&order.order);
+    Ok(client.sign(signer, order).await?)
 }
 
 pub async fn authenticated_clob_client(
     private_key: Option<&str>,
-    signature_type_flag: Option<&str>,
+    signature_type_flag: Option<config::SignatureType>,
 ) -> Result<clob::Client<Authenticated<Normal>>> {
     let signer = resolve_signer(private_key)?;
     authenticate_with_signer(&signer, signature_type_flag).await
@@ -43,18 +146,75 @@ pub async fn authenticated_clob_client(
 
 pub async fn authenticate_with_signer(
     signer: &(impl polymarket_client_sdk::auth::Signer + Sync),
-    signature_type_flag: Option<&str>,
+    signature_type_flag: Option<config::SignatureType>,
 ) -> Result<clob::Client<Authenticated<Normal>>> {
-    let sig_type = parse_signature_type(&config::resolve_signature_type(signature_type_flag)?);
+    let signature_type = config::resolve_signature_type(signature_type_flag)?;
+    let funder = config::funder()?;
+
+    // The proxy wallet is derived unless a funder is given, and Amoy has no proxy factory.
+    let chain = config::chain();
+    if signature_type == config::SignatureType::Proxy
+        && funder.is_none()
+        && derive_proxy_wallet(Address::ZERO, chain.id()).is_none()
+    {
+        anyhow::bail!(CliError::invalid_argument(format!(
+            "Proxy wallets aren't available on {}; use --signature-type eoa or gnosis-safe",
+            chain.name()
+        )));
+    }
 
-    clob::Client::default()
+    let mut builder = clob_client()?
         .authentication_builder(signer)
-        .signature_type(sig_type)
+        .signature_type(signature_type.into());
+    if let Some(funder) = funder {
+        verify_funder(signer.address(), funder, signature_type).await?;
+        builder = builder.funder(funder);
+    }
+    builder
         .authenticate()
         .await
         .context("Failed to authenticate with Polymarket CLOB")
 }
 
+/// Check that `eoa` controls `funder` under `signature_type`: it is the EOA
+/// itself, the wallet derived from it, or a Safe it owns. Returns whether
+/// that could be verified; a proxy deployed any other way has no on-chain
+/// owner to check.
+pub async fn verify_funder(
+    eoa: Address,
+    funder: Address,
+    signature_type: config::SignatureType,
+) -> Result<bool> {
+    let chain = config::chain();
+    match signature_type {
+        _ if config::derived_wallet(eoa, signature_type) == Some(funder) => Ok(true),
+        config::SignatureType::Proxy => Ok(false),
+        config::SignatureType::GnosisSafe => {
+            let provider = create_readonly_provider().await?;
+            match ISafe::new(funder, provider).isOwner(eoa).call().await {
+                Ok(true) => Ok(true),
+                Ok(false) => Err(CliError::invalid_argument(format!(
+                    "{eoa} isn't an owner of the Safe {funder}"
+                ))
+                .into()),
+                Err(e @ alloy::contract::Error::TransportError(_)) => {
+                    Err(e).context("Failed to check the Safe's owners")
+                }
+                Err(_) => Err(CliError::invalid_argument(format!(
+                    "{funder} isn't a Safe on {}",
+                    chain.name()
+                ))
+                .into()),
+            }
+        }
+        config::SignatureType::Eoa if funder == eoa => Ok(true),
+        config::SignatureType::Eoa => Err(CliError::invalid_argument(format!(
+            "With the eoa signature type the funder must be the key's own address {eoa}"
+        ))
+        .into()),
+    }
+}
+
 pub async fn create_readonly_provider() -> Result<impl alloy::providers::Provider + Clone> {
     ProviderBuilder::new()
         .connect(&rpc_url())
@@ -65,13 +225,9 @@ pub async fn create_readonly_provider() -> Result<impl alloy::providers::Provide
 pub async fn create_provider(
     private_key: Option<&str>,
 ) -> Result<impl alloy::providers::Provider + Clone> {
-    let (key, _) = config::resolve_key(private_key)?;
-    let key = key.ok_or_else(|| anyhow::anyhow!("{}", config::NO_WALLET_MSG))?;
-    let signer = LocalSigner::from_str(&key)
-        .context("Invalid private key")?
-        .with_chain_id(Some(POLYGON));
+    let signer = resolve_signer(private_key)?;
     ProviderBuilder::new()
-        .wallet(signer)
+        .wallet(EthereumWallet::new(signer))
         .connect(&rpc_url())
         .await
         .context("Failed to connect to Polygon RPC with wallet")
@@ -82,25 +238,26 @@ mod tests {
     use super::*;
 
     #[test]
-    fn parse_signature_type_proxy() {
-        assert_eq!(parse_signature_type("proxy"), SignatureType::Proxy);
+    fn signature_type_proxy() {
+        assert_eq!(
+            SignatureType::from(config::SignatureType::Proxy),
+            SignatureType::Proxy
+        );
     }
 
     #[test]
-    fn parse_signature_type_gnosis_safe() {
+    fn signature_type_gnosis_safe() {
         assert_eq!(
-            parse_signature_type("gnosis-safe"),
+            SignatureType::from(config::SignatureType::GnosisSafe),
             SignatureType::GnosisSafe
         );
     }
 
     #[test]
-    fn parse_signature_type_eoa() {
-        assert_eq!(parse_signature_type("eoa"), SignatureType::Eoa);
-    }
-
-    #[test]
-    fn parse_signature_type_unknown_defaults_to_eoa() {
-        assert_eq!(parse_signature_type("unknown"), SignatureType::Eoa);
+    fn signature_type_eoa() {
+        assert_eq!(
+            SignatureType::from(config::SignatureType::Eoa),
+            SignatureType::Eoa
+        );
     }
 }
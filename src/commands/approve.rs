@@ -4,16 +4,18 @@
 use alloy::primitives::U256;
 use alloy::sol;
 use anyhow::{Context, Result};
-use clap::{Args, Subcommand};
-use polymarket_client_sdk::types::{Address, address};
-use polymarket_client_sdk::{POLYGON, contract_config};
+use clap::{Args, Subcommand, ValueEnum};
+use polymarket_client_sdk::types::Address;
 
-use crate::auth;
+use super::ctf::{parse_usdc_amount, raw_to_usdc};
+use crate::config::SignatureType;
+use crate::error::CliError;
 use crate::output::OutputFormat;
-use crate::output::approve::{ApprovalStatus, print_approval_status, print_tx_result};
-
-/// Polygon USDC (same address as `USDC_ADDRESS_STR`; `address!` requires a literal).
-const USDC_ADDRESS: Address = address!("0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174");
+use crate::output::approve::{
+    ApprovalStatus, print_approval_status, print_simulation, print_skipped, print_tx_result,
+};
+use crate::tx::{self, Call, Sender, TxArgs};
+use crate::{auth, config};
 
 sol! {
     #[sol(rpc)]
@@ -39,29 +41,68 @@ pub struct ApproveArgs {
 pub enum ApproveCommand {
     /// Check current contract approvals for a wallet
     Check {
-        /// Wallet address to check (defaults to configured wallet)
+        /// Wallet address to check (defaults to the wallet that trades: the
+        /// proxy or Safe for those signature types)
+        #[arg(conflicts_with = "all")]
         address: Option<Address>,
+        /// Show the key's EOA next to its proxy or Safe
+        #[arg(long)]
+        all: bool,
+    },
+    /// Approve contracts for trading, skipping approvals already in place
+    /// (sends on-chain transactions)
+    Set {
+        /// Contract to approve
+        #[arg(long, value_enum, default_value_t = Target::All)]
+        target: Target,
+        /// USDC allowance to grant, e.g. 500 [default: unlimited]
+        #[arg(long)]
+        amount: Option<String>,
+        #[command(flatten)]
+        tx: TxArgs,
+    },
+    /// Withdraw USDC allowances and CTF approvals (sends on-chain transactions)
+    Revoke {
+        /// Contract to revoke
+        #[arg(long, value_enum, default_value_t = Target::All)]
+        target: Target,
+        #[command(flatten)]
+        tx: TxArgs,
     },
-    /// Approve all required contracts for trading (sends on-chain transactions)
-    Set,
+}
+
+/// The contracts that need approvals to trade.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum Target {
+    /// The CTF exchange
+    Exchange,
+    /// The neg-risk CTF exchange
+    NegRiskExchange,
+    /// The neg-risk adapter
+    NegRiskAdapter,
+    /// Every contract above
+    All,
 }
 
 struct ApprovalTarget {
+    target: Target,
     name: &'static str,
     address: Address,
 }
 
-fn approval_targets() -> Result<Vec<ApprovalTarget>> {
-    let config = contract_config(POLYGON, false).context("No contract config for Polygon")?;
-    let neg_risk_config =
-        contract_config(POLYGON, true).context("No neg-risk contract config for Polygon")?;
+fn approval_targets() -> Vec<ApprovalTarget> {
+    let chain = config::chain();
+    let config = chain.contracts(false);
+    let neg_risk_config = chain.contracts(true);
 
     let mut targets = vec![
         ApprovalTarget {
+            target: Target::Exchange,
             name: "CTF Exchange",
             address: config.exchange,
         },
         ApprovalTarget {
+            target: Target::NegRiskExchange,
             name: "Neg Risk Exchange",
             address: neg_risk_config.exchange,
         },
@@ -69,11 +110,32 @@ fn approval_targets() -> Result<Vec<ApprovalTarget>> {
 
     if let Some(adapter) = neg_risk_config.neg_risk_adapter {
         targets.push(ApprovalTarget {
+            target: Target::NegRiskAdapter,
             name: "Neg Risk Adapter",
             address: adapter,
         });
     }
 
+    targets
+}
+
+/// The approval targets `target` selects.
+fn selected_targets(target: Target) -> Result<Vec<ApprovalTarget>> {
+    let targets: Vec<_> = approval_targets()
+        .into_iter()
+        .filter(|t| target == Target::All || t.target == target)
+        .collect();
+    anyhow::ensure!(
+        !targets.is_empty(),
+        CliError::invalid_argument(format!(
+            "There's no {} on {}",
+            target
+                .to_possible_value()
+                .map(|v| v.get_name().to_string())
+                .unwrap_or_default(),
+            config::chain().name()
+        ))
+    );
     Ok(targets)
 }
 
@@ -81,135 +143,454 @@ pub async fn execute(
     args: ApproveArgs,
     output: OutputFormat,
     private_key: Option<&str>,
+    signature_type: Option<SignatureType>,
 ) -> Result<()> {
     match args.command {
-        ApproveCommand::Check { address } => check(address, private_key, output).await,
-        ApproveCommand::Set => set(private_key, output).await,
+        ApproveCommand::Check { address, all } => {
+            check(address, all, private_key, signature_type, output).await
+        }
+        ApproveCommand::Set {
+            target,
+            amount,
+            tx: options,
+        } => {
+            tx::set_options(options)?;
+            set(private_key, signature_type, target, amount, output).await
+        }
+        ApproveCommand::Revoke {
+            target,
+            tx: options,
+        } => {
+            tx::set_options(options)?;
+            revoke(private_key, signature_type, target, output).await
+        }
+    }
+}
+
+/// The wallets `approve check` reads: `address`, else the wallet that
+/// trades, preceded by the EOA with `all`.
+fn owners(
+    address: Option<Address>,
+    all: bool,
+    private_key: Option<&str>,
+    signature_type_flag: Option<SignatureType>,
+) -> Result<Vec<(Option<SignatureType>, Address)>> {
+    if let Some(address) = address {
+        return Ok(vec![(None, address)]);
+    }
+    let signature_type = config::resolve_signature_type(signature_type_flag)?;
+    let funder = config::resolve_funder(private_key, signature_type_flag)?;
+    let mut owners = Vec::new();
+    if all {
+        let (eoa, _) = config::resolve_address(private_key)?;
+        let eoa = eoa.ok_or_else(|| CliError::auth(config::NO_WALLET_MSG))?;
+        if eoa != funder {
+            owners.push((Some(SignatureType::Eoa), eoa));
+        }
     }
+    owners.push((Some(signature_type), funder));
+    Ok(owners)
 }
 
 async fn check(
-    address_arg: Option<Address>,
+    address: Option<Address>,
+    all: bool,
     private_key: Option<&str>,
+    signature_type: Option<SignatureType>,
     output: OutputFormat,
 ) -> Result<()> {
-    let owner: Address = if let Some(addr) = address_arg {
-        addr
-    } else {
-        let signer = auth::resolve_signer(private_key)?;
-        polymarket_client_sdk::auth::Signer::address(&signer)
-    };
+    let owners = owners(address, all, private_key, signature_type)?;
 
     let provider = auth::create_readonly_provider().await?;
-    let config = contract_config(POLYGON, false).context("No contract config for Polygon")?;
+    let chain = config::chain();
 
-    let usdc = IERC20::new(USDC_ADDRESS, provider.clone());
-    let ctf = IERC1155::new(config.conditional_tokens, provider.clone());
+    let usdc = IERC20::new(chain.usdc(), provider.clone());
+    let ctf = IERC1155::new(chain.contracts(false).conditional_tokens, provider.clone());
 
-    let targets = approval_targets()?;
+    let targets = approval_targets();
     let mut statuses = Vec::new();
 
-    for target in &targets {
-        let (usdc_allowance, usdc_error) = match usdc.allowance(owner, target.address).call().await
-        {
-            Ok(val) => (val, None),
-            Err(e) => (U256::ZERO, Some(e.to_string())),
-        };
+    for &(wallet, owner) in &owners {
+        for target in &targets {
+            let (usdc_allowance, usdc_error) =
+                match usdc.allowance(owner, target.address).call().await {
+                    Ok(val) => (val, None),
+                    Err(e) => (U256::ZERO, Some(e.to_string())),
+                };
+
+            let (ctf_approved, ctf_error) =
+                match ctf.isApprovedForAll(owner, target.address).call().await {
+                    Ok(val) => (val, None),
+                    Err(e) => (false, Some(e.to_string())),
+                };
+
+            statuses.push(ApprovalStatus {
+                wallet,
+                owner,
+                contract_name: target.name.to_string(),
+                contract_address: format!("{}", target.address),
+                usdc_allowance,
+                usdc_unlimited: Grant::Allowance(U256::MAX).is_met(usdc_allowance, false),
+                ctf_approved,
+                usdc_error,
+                ctf_error,
+            });
+        }
+    }
 
-        let (ctf_approved, ctf_error) =
-            match ctf.isApprovedForAll(owner, target.address).call().await {
-                Ok(val) => (val, None),
-                Err(e) => (false, Some(e.to_string())),
-            };
-
-        statuses.push(ApprovalStatus {
-            contract_name: target.name.to_string(),
-            contract_address: format!("{}", target.address),
-            usdc_allowance,
-            ctf_approved,
-            usdc_error,
-            ctf_error,
-        });
+    print_approval_status(&owners, &statuses, &output)
+}
+
+/// Allowances above this count as unlimited: USDC spends them down from
+/// `U256::MAX`.
+const UNLIMITED: U256 = U256::from_limbs([0, 0, 0, 1 << 63]);
+
+/// What a step sets: a USDC allowance or CTF operator approval.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Grant {
+    Allowance(U256),
+    Operator(bool),
+}
+
+impl Grant {
+    /// Whether an allowance of `allowance`, or operator approval
+    /// `approved`, already does what this grant would.
+    fn is_met(self, allowance: U256, approved: bool) -> bool {
+        match self {
+            Self::Allowance(U256::MAX) => allowance >= UNLIMITED,
+            Self::Allowance(amount) => allowance == amount,
+            Self::Operator(operator) => approved == operator,
+        }
     }
+}
 
-    print_approval_status(&statuses, &output)
+/// One transaction of `approve set` or `approve revoke`.
+struct Step {
+    label: String,
+    token: &'static str,
+    kind: &'static str,
+    contract: &'static str,
+    spender: Address,
+    grant: Grant,
 }
 
-async fn set(private_key: Option<&str>, output: OutputFormat) -> Result<()> {
-    let provider = auth::create_provider(private_key).await?;
-    let config = contract_config(POLYGON, false).context("No contract config for Polygon")?;
+impl Step {
+    fn call(&self) -> Call {
+        let chain = config::chain();
+        match self.grant {
+            Grant::Allowance(value) => Call::new(
+                chain.usdc(),
+                &IERC20::approveCall {
+                    spender: self.spender,
+                    value,
+                },
+            ),
+            Grant::Operator(approved) => Call::new(
+                chain.contracts(false).conditional_tokens,
+                &IERC1155::setApprovalForAllCall {
+                    operator: self.spender,
+                    approved,
+                },
+            ),
+        }
+    }
+}
+
+/// A USDC allowance of `allowance` and CTF approval `approved` for each of
+/// `targets`.
+fn steps(targets: Vec<ApprovalTarget>, allowance: U256, approved: bool) -> Vec<Step> {
+    targets
+        .into_iter()
+        .flat_map(|target| {
+            [
+                Step {
+                    label: format!("USDC \u{2192} {}", target.name),
+                    token: "USDC",
+                    kind: "erc20",
+                    contract: target.name,
+                    spender: target.address,
+                    grant: Grant::Allowance(allowance),
+                },
+                Step {
+                    label: format!("CTF  \u{2192} {}", target.name),
+                    token: "CTF",
+                    kind: "erc1155",
+                    contract: target.name,
+                    spender: target.address,
+                    grant: Grant::Operator(approved),
+                },
+            ]
+        })
+        .collect()
+}
+
+/// Which of `steps` `owner`'s approvals already meet.
+async fn already_met(owner: Address, steps: &[Step]) -> Result<Vec<bool>> {
+    let provider = auth::create_readonly_provider().await?;
+    let chain = config::chain();
+    let usdc = IERC20::new(chain.usdc(), &provider);
+    let ctf = IERC1155::new(chain.contracts(false).conditional_tokens, &provider);
+    let mut met = Vec::with_capacity(steps.len());
+    for step in steps {
+        let done = match step.grant {
+            Grant::Allowance(_) => {
+                let allowance = usdc
+                    .allowance(owner, step.spender)
+                    .call()
+                    .await
+                    .context("Failed to read the USDC allowance")?;
+                step.grant.is_met(allowance, false)
+            }
+            Grant::Operator(_) => {
+                let approved = ctf
+                    .isApprovedForAll(owner, step.spender)
+                    .call()
+                    .await
+                    .context("Failed to read the CTF approval")?;
+                step.grant.is_met(U256::ZERO, approved)
+            }
+        };
+        met.push(done);
+    }
+    Ok(met)
+}
 
-    let usdc = IERC20::new(USDC_ADDRESS, provider.clone());
-    let ctf = IERC1155::new(config.conditional_tokens, provider.clone());
+async fn set(
+    private_key: Option<&str>,
+    signature_type: Option<SignatureType>,
+    target: Target,
+    amount: Option<String>,
+    output: OutputFormat,
+) -> Result<()> {
+    let allowance = match amount {
+        Some(amount) => parse_usdc_amount(&amount)?,
+        None => U256::MAX,
+    };
+    let targets = selected_targets(target)?;
+    let done = approved_message(target, &contract_names(&targets), allowance)?;
+    let steps = steps(targets, allowance, true);
+    run(
+        private_key,
+        signature_type,
+        steps,
+        "Approving contracts",
+        &done,
+        output,
+    )
+    .await
+}
 
-    let targets = approval_targets()?;
-    let total = targets.len() * 2;
+/// `"A"`, `"A and B"`, `"A, B and C"`.
+fn contract_names(targets: &[ApprovalTarget]) -> String {
+    let names: Vec<_> = targets.iter().map(|t| t.name).collect();
+    match names.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{} and {last}", rest.join(", ")),
+        _ => names.concat(),
+    }
+}
+
+/// What `approve set` reports once done: ready to trade only when every
+/// contract got an unlimited allowance.
+fn approved_message(target: Target, names: &str, allowance: U256) -> Result<String> {
+    Ok(match allowance {
+        U256::MAX if target == Target::All => {
+            "All contracts approved. You're ready to trade.".to_string()
+        }
+        U256::MAX => format!("Approved {names}."),
+        _ => format!(
+            "Approved {names} with a USDC allowance of {}.",
+            raw_to_usdc(allowance)?.normalize()
+        ),
+    })
+}
+
+async fn revoke(
+    private_key: Option<&str>,
+    signature_type: Option<SignatureType>,
+    target: Target,
+    output: OutputFormat,
+) -> Result<()> {
+    let targets = selected_targets(target)?;
+    let done = format!("Revoked the approvals of {}.", contract_names(&targets));
+    let steps = steps(targets, U256::ZERO, false);
+    run(
+        private_key,
+        signature_type,
+        steps,
+        "Revoking approvals",
+        &done,
+        output,
+    )
+    .await
+}
+
+/// Send the steps the trading wallet's approvals don't already meet,
+/// through the proxy or Safe when that's the wallet.
+async fn run(
+    private_key: Option<&str>,
+    signature_type: Option<SignatureType>,
+    steps: Vec<Step>,
+    heading: &str,
+    done: &str,
+    output: OutputFormat,
+) -> Result<()> {
+    let sender = Sender::resolve(private_key, signature_type).await?;
+    let met = already_met(sender.address(), &steps).await?;
+    if tx::simulating() {
+        return simulate(sender, steps, &met, output).await;
+    }
+    let provider = auth::create_provider(private_key).await?;
+    let total = steps.len();
 
     if matches!(output, OutputFormat::Table) {
-        println!("Approving contracts...\n");
+        println!("{heading} for {}...\n", sender.address());
     }
 
     let mut results: Vec<serde_json::Value> = Vec::new();
-    let mut step = 0;
-
-    for target in &targets {
-        step += 1;
-        let label = format!("USDC \u{2192} {}", target.name);
-        let tx_hash = usdc
-            .approve(target.address, U256::MAX)
-            .send()
-            .await
-            .context(format!("Failed to send USDC approval for {}", target.name))?
-            .watch()
+    for ((step, item), met) in (1..).zip(steps).zip(met) {
+        if met {
+            match output {
+                OutputFormat::Table => print_skipped(step, total, &item.label),
+                OutputFormat::Json => results.push(serde_json::json!({
+                    "step": step,
+                    "type": item.kind,
+                    "contract": item.contract,
+                    "skipped": true,
+                })),
+            }
+            continue;
+        }
+        let sent = tx::send(&provider, sender, item.call())
             .await
             .context(format!(
-                "Failed to confirm USDC approval for {}",
-                target.name
+                "{} approval for {} failed",
+                item.token, item.contract
             ))?;
 
         match output {
-            OutputFormat::Table => print_tx_result(step, total, &label, tx_hash),
+            OutputFormat::Table => print_tx_result(step, total, &item.label, &sent),
             OutputFormat::Json => results.push(serde_json::json!({
                 "step": step,
-                "type": "erc20",
-                "contract": target.name,
-                "tx_hash": format!("{tx_hash}"),
+                "type": item.kind,
+                "contract": item.contract,
+                "tx_hash": format!("{}", sent.transaction_hash),
+                "gas_used": sent.gas_used,
+                "fee": sent.fee.to_string(),
             })),
         }
+    }
 
-        step += 1;
-        let label = format!("CTF  \u{2192} {}", target.name);
-        let tx_hash = ctf
-            .setApprovalForAll(target.address, true)
-            .send()
-            .await
-            .context(format!("Failed to send CTF approval for {}", target.name))?
-            .watch()
-            .await
-            .context(format!(
-                "Failed to confirm CTF approval for {}",
-                target.name
-            ))?;
+    match output {
+        OutputFormat::Table => {
+            println!("\n{done}");
+        }
+        OutputFormat::Json => {
+            crate::output::print_json(&results)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// `--simulate`: check every step still needed without sending it.
+async fn simulate(
+    sender: Sender,
+    steps: Vec<Step>,
+    met: &[bool],
+    output: OutputFormat,
+) -> Result<()> {
+    let provider = auth::create_readonly_provider().await?;
+    let total = steps.len();
+
+    if matches!(output, OutputFormat::Table) {
+        println!("Simulating approvals for {}...\n", sender.address());
+    }
 
+    let mut results: Vec<serde_json::Value> = Vec::new();
+    let mut reverted = 0;
+    for ((step, item), &met) in (1..).zip(steps).zip(met) {
+        if met {
+            match output {
+                OutputFormat::Table => print_skipped(step, total, &item.label),
+                OutputFormat::Json => results.push(serde_json::json!({
+                    "step": step,
+                    "type": item.kind,
+                    "contract": item.contract,
+                    "skipped": true,
+                })),
+            }
+            continue;
+        }
+        let simulation = tx::simulate(&provider, sender, &item.call()).await?;
+        if simulation.revert_reason.is_some() {
+            reverted += 1;
+        }
         match output {
-            OutputFormat::Table => print_tx_result(step, total, &label, tx_hash),
+            OutputFormat::Table => print_simulation(step, total, &item.label, &simulation),
             OutputFormat::Json => results.push(serde_json::json!({
                 "step": step,
-                "type": "erc1155",
-                "contract": target.name,
-                "tx_hash": format!("{tx_hash}"),
+                "type": item.kind,
+                "contract": item.contract,
+                "simulated": true,
+                "gas_estimate": simulation.gas_estimate,
+                "revert_reason": simulation.revert_reason,
             })),
         }
     }
 
     match output {
-        OutputFormat::Table => {
-            println!("\nAll contracts approved. You're ready to trade.");
+        OutputFormat::Table if reverted == 0 => {
+            println!("\nAll approvals would succeed. Nothing was sent.");
         }
+        OutputFormat::Table => {}
         OutputFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(&results)?);
+            crate::output::print_json(&results)?;
         }
     }
 
+    anyhow::ensure!(
+        reverted == 0,
+        "{reverted} of {total} approvals would revert"
+    );
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unlimited_allowance_is_met_after_spending() {
+        let grant = Grant::Allowance(U256::MAX);
+        assert!(grant.is_met(U256::MAX, false));
+        assert!(grant.is_met(U256::MAX - U256::from(1_000_000_000u64), false));
+        assert!(!grant.is_met(U256::from(500_000_000u64), false));
+    }
+
+    #[test]
+    fn exact_allowance_and_operator_approval() {
+        let amount = U256::from(500_000_000u64);
+        assert!(Grant::Allowance(amount).is_met(amount, false));
+        assert!(!Grant::Allowance(amount).is_met(U256::MAX, false));
+        assert!(Grant::Allowance(U256::ZERO).is_met(U256::ZERO, true));
+        assert!(Grant::Operator(true).is_met(U256::ZERO, true));
+        assert!(!Grant::Operator(false).is_met(U256::ZERO, true));
+    }
+
+    #[test]
+    fn only_unlimited_approval_of_all_is_ready_to_trade() {
+        let all = "CTF Exchange, Neg Risk Exchange and Neg Risk Adapter";
+        assert_eq!(
+            approved_message(Target::All, all, U256::MAX).unwrap(),
+            "All contracts approved. You're ready to trade."
+        );
+        assert_eq!(
+            approved_message(Target::All, all, U256::from(500_000_000u64)).unwrap(),
+            format!("Approved {all} with a USDC allowance of 500.")
+        );
+        assert_eq!(
+            approved_message(Target::NegRiskExchange, "Neg Risk Exchange", U256::MAX).unwrap(),
+            "Approved Neg Risk Exchange."
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-use crate::auth;
+use crate::error::{CliError, ErrorCode};
 use crate::output::OutputFormat;
 use crate::output::clob::{
     print_account_status, print_api_keys, print_balance, print_batch_prices, print_cancel_result,
@@ -13,10 +13,11 @@ use crate::output::clob::{
     print_rewards, print_server_time, print_simplified_markets, print_spread, print_spreads,
     print_tick_size, print_trades, print_user_earnings_markets,
 };
+use crate::{auth, config};
 use anyhow::Result;
 use chrono::NaiveDate;
 use clap::{Args, Subcommand};
-use polymarket_client_sdk::clob;
+use polymarket_client_sdk::clob::types::response::PostOrderResponse;
 use polymarket_client_sdk::clob::types::{
     Amount, AssetType, Interval, OrderType, Side, TimeRange,
     request::{
@@ -473,16 +474,39 @@ impl From<CliAssetType> for AssetType {
 }
 
 fn parse_token_id(s: &str) -> Result<U256> {
-    U256::from_str(s).map_err(|_| anyhow::anyhow!("Invalid token ID: {s}"))
+    Ok(U256::from_str(s)
+        .map_err(|_| CliError::invalid_argument(format!("Invalid token ID: {s}")))?)
 }
 
 fn parse_token_ids(s: &str) -> Result<Vec<U256>> {
     s.split(',').map(|t| parse_token_id(t.trim())).collect()
 }
 
+/// Fails with [`ErrorCode::OrderRejected`] unless the exchange accepted every order.
+fn ensure_accepted(results: &[PostOrderResponse]) -> Result<()> {
+    let rejected: Vec<String> = results
+        .iter()
+        .filter(|r| !r.success)
+        .map(|r| {
+            r.error_msg
+                .clone()
+                .filter(|m| !m.is_empty())
+                .unwrap_or_else(|| "rejected".to_string())
+        })
+        .collect();
+    if rejected.is_empty() {
+        return Ok(());
+    }
+    Err(CliError::new(
+        ErrorCode::OrderRejected,
+        format!("Order rejected: {}", rejected.join("; ")),
+    )
+    .into())
+}
+
 fn parse_date(s: &str) -> Result<NaiveDate> {
     NaiveDate::parse_from_str(s, "%Y-%m-%d")
-        .map_err(|_| anyhow::anyhow!("Invalid date: expected YYYY-MM-DD format"))
+        .map_err(|_| CliError::invalid_argument("Invalid date: expected YYYY-MM-DD format").into())
 }
 
 #[allow(clippy::too_many_lines)]
@@ -490,10 +514,10 @@ pub async fn execute(
     args: ClobArgs,
     output: OutputFormat,
     private_key: Option<&str>,
-    signature_type: Option<&str>,
+    signature_type: Option<config::SignatureType>,
 ) -> Result<()> {
     // Unauthenticated client — cheap to construct, used by read commands and CreateApiKey.
-    let unauth = clob::Client::default();
+    let unauth = auth::clob_client()?;
     let output = &output;
 
     match args.command {
@@ -671,10 +695,10 @@ pub async fn execute(
             let signer = auth::resolve_signer(private_key)?;
             let client = auth::authenticate_with_signer(&signer, signature_type).await?;
 
-            let price_dec =
-                Decimal::from_str(&price).map_err(|_| anyhow::anyhow!("Invalid price: {price}"))?;
-            let size_dec =
-                Decimal::from_str(&size).map_err(|_| anyhow::anyhow!("Invalid size: {size}"))?;
+            let price_dec = Decimal::from_str(&price)
+                .map_err(|_| CliError::invalid_argument(format!("Invalid price: {price}")))?;
+            let size_dec = Decimal::from_str(&size)
+                .map_err(|_| CliError::invalid_argument(format!("Invalid size: {size}")))?;
 
             let order = client
                 .limit_order()
@@ -686,9 +710,10 @@ pub async fn execute(
                 .post_only(post_only)
                 .build()
                 .await?;
-            let order = client.sign(&signer, order).await?;
+            let order = auth::sign_order(&client, &signer, order).await?;
             let result = client.post_order(order).await?;
             print_post_order_result(&result, output)?;
+            ensure_accepted(std::slice::from_ref(&result))?;
         }
 
         ClobCommand::PostOrders {
@@ -718,10 +743,11 @@ pub async fn execute(
             for ((token_id, price_str), size_str) in
                 token_ids.into_iter().zip(price_strs).zip(size_strs)
             {
-                let price_dec = Decimal::from_str(price_str)
-                    .map_err(|_| anyhow::anyhow!("Invalid price: {price_str}"))?;
+                let price_dec = Decimal::from_str(price_str).map_err(|_| {
+                    CliError::invalid_argument(format!("Invalid price: {price_str}"))
+                })?;
                 let size_dec = Decimal::from_str(size_str)
-                    .map_err(|_| anyhow::anyhow!("Invalid size: {size_str}"))?;
+                    .map_err(|_| CliError::invalid_argument(format!("Invalid size: {size_str}")))?;
 
                 let order = client
                     .limit_order()
@@ -732,11 +758,12 @@ pub async fn execute(
                     .order_type(sdk_order_type.clone())
                     .build()
                     .await?;
-                signed_orders.push(client.sign(&signer, order).await?);
+                signed_orders.push(auth::sign_order(&client, &signer, order).await?);
             }
 
             let results = client.post_orders(signed_orders).await?;
             print_post_orders_result(&results, output)?;
+            ensure_accepted(&results)?;
         }
 
         ClobCommand::MarketOrder {
@@ -749,7 +776,7 @@ pub async fn execute(
             let client = auth::authenticate_with_signer(&signer, signature_type).await?;
 
             let amount_dec = Decimal::from_str(&amount)
-                .map_err(|_| anyhow::anyhow!("Invalid amount: {amount}"))?;
+                .map_err(|_| CliError::invalid_argument(format!("Invalid amount: {amount}")))?;
             let sdk_side = Side::from(side);
             let parsed_amount = if matches!(sdk_side, Side::Sell) {
                 Amount::shares(amount_dec)?
@@ -765,9 +792,10 @@ pub async fn execute(
                 .order_type(OrderType::from(order_type))
                 .build()
                 .await?;
-            let order = client.sign(&signer, order).await?;
+            let order = auth::sign_order(&client, &signer, order).await?;
             let result = client.post_order(order).await?;
             print_post_order_result(&result, output)?;
+            ensure_accepted(std::slice::from_ref(&result))?;
         }
 
         // ── Authenticated trading commands (no signer needed) ───────────
@@ -855,7 +883,7 @@ pub async fn execute(
             match output {
                 OutputFormat::Table => println!("Balance allowance updated."),
                 OutputFormat::Json => {
-                    println!("{}", serde_json::json!({"success": true}));
+                    crate::output::print_json(&serde_json::json!({"success": true}))?;
                 }
             }
         }
@@ -877,7 +905,7 @@ pub async fn execute(
             match output {
                 OutputFormat::Table => println!("Notifications deleted."),
                 OutputFormat::Json => {
-                    println!("{}", serde_json::json!({"success": true}));
+                    crate::output::print_json(&serde_json::json!({"success": true}))?;
                 }
             }
         }
@@ -8,6 +8,7 @@ use polymarket_client_sdk::gamma::{
     },
 };
 
+use crate::config;
 use crate::output::OutputFormat;
 use crate::output::comments::{print_comment, print_comments};
 
@@ -29,9 +30,9 @@ pub enum CommentsCommand {
         #[arg(long)]
         entity_id: String,
 
-        /// Max results
-        #[arg(long, default_value = "25")]
-        limit: i32,
+        /// Max results [default: the `limit` setting, or 25]
+        #[arg(long)]
+        limit: Option<i32>,
 
         /// Pagination offset
         #[arg(long)]
@@ -57,9 +58,9 @@ pub enum CommentsCommand {
         /// Wallet address (0x...)
         address: polymarket_client_sdk::types::Address,
 
-        /// Max results
-        #[arg(long, default_value = "25")]
-        limit: i32,
+        /// Max results [default: the `limit` setting, or 25]
+        #[arg(long)]
+        limit: Option<i32>,
 
         /// Pagination offset
         #[arg(long)]
@@ -109,7 +110,7 @@ pub async fn execute(
             let request = CommentsRequest::builder()
                 .parent_entity_type(ParentEntityType::from(entity_type))
                 .parent_entity_id(entity_id)
-                .limit(limit)
+                .limit(limit.unwrap_or_else(config::page_size))
                 .maybe_offset(offset)
                 .maybe_order(order)
                 .ascending(ascending)
@@ -139,7 +140,7 @@ pub async fn execute(
         } => {
             let request = CommentsByUserAddressRequest::builder()
                 .user_address(address)
-                .limit(limit)
+                .limit(limit.unwrap_or_else(config::page_size))
                 .maybe_offset(offset)
                 .maybe_order(order)
                 .ascending(ascending)
@@ -0,0 +1,438 @@
+use anyhow::{Context, Result, bail};
+use clap::{Args, Subcommand};
+use serde_json::Value;
+use tabled::settings::Style;
+use tabled::{Table, Tabled};
+
+use crate::config::{self, Config};
+use crate::error::CliError;
+use crate::output::{DASH, OutputFormat, print_json};
+
+#[derive(Args)]
+pub struct ConfigArgs {
+    #[command(subcommand)]
+    pub command: ConfigCommand,
+}
+
+#[derive(Subcommand)]
+pub enum ConfigCommand {
+    /// List all settings with their values and where each comes from
+    List,
+    /// Print the value of a setting
+    Get {
+        /// Setting name, e.g. `limit`
+        key: String,
+    },
+    /// Change a setting (for one profile with --profile)
+    Set {
+        /// Setting name, e.g. `limit`
+        key: String,
+        /// New value
+        value: String,
+    },
+    /// Reset a setting to its default (for one profile with --profile)
+    Unset {
+        /// Setting name, e.g. `limit`
+        key: String,
+    },
+    /// Open config.json in $VISUAL or $EDITOR
+    Edit,
+}
+
+#[derive(Clone, Copy)]
+enum Kind {
+    Integer,
+    Url,
+    Text,
+}
+
+struct Key {
+    name: &'static str,
+    /// Section of `config.json` holding the key: `settings` or `display`.
+    section: &'static str,
+    kind: Kind,
+    description: &'static str,
+}
+
+const KEYS: &[Key] = &[
+    Key {
+        name: "output",
+        section: "settings",
+        kind: Kind::Text,
+        description: "Output format without --output: table, json or template",
+    },
+    Key {
+        name: "limit",
+        section: "settings",
+        kind: Kind::Integer,
+        description: "Page size of list commands without --limit",
+    },
+    Key {
+        name: "timeout",
+        section: "settings",
+        kind: Kind::Integer,
+        description: "Seconds before a network command gives up (not transactions)",
+    },
+    Key {
+        name: "rpc_url",
+        section: "settings",
+        kind: Kind::Url,
+        description: "Polygon JSON-RPC endpoint",
+    },
+    Key {
+        name: "gamma_url",
+        section: "settings",
+        kind: Kind::Url,
+        description: "Gamma API: markets, events, tags",
+    },
+    Key {
+        name: "clob_url",
+        section: "settings",
+        kind: Kind::Url,
+        description: "CLOB API: order books and trading",
+    },
+    Key {
+        name: "data_url",
+        section: "settings",
+        kind: Kind::Url,
+        description: "Data API: positions, trades, leaderboards",
+    },
+    Key {
+        name: "bridge_url",
+        section: "settings",
+        kind: Kind::Url,
+        description: "Bridge API",
+    },
+    Key {
+        name: "timezone",
+        section: "display",
+        kind: Kind::Text,
+        description: "Timezone for dates in tables: utc, local or an offset",
+    },
+    Key {
+        name: "numbers",
+        section: "display",
+        kind: Kind::Text,
+        description: "Dollar amounts in tables: abbreviated or full",
+    },
+    Key {
+        name: "price_style",
+        section: "display",
+        kind: Kind::Text,
+        description: "Prices in tables: probability, cents, decimal, american or fractional",
+    },
+];
+
+impl Key {
+    fn find(name: &str) -> Result<&'static Key> {
+        KEYS.iter().find(|k| k.name == name).ok_or_else(|| {
+            let names: Vec<_> = KEYS.iter().map(|k| k.name).collect();
+            CliError::invalid_argument(format!(
+                "Unknown setting `{name}`. Settings: {}",
+                names.join(", ")
+            ))
+            .into()
+        })
+    }
+
+    /// Value used when no config sets the key; null when it depends on the command.
+    fn default_value(&self) -> Value {
+        match self.name {
+            "output" => "table".into(),
+            "limit" => config::DEFAULT_LIMIT.into(),
+            "rpc_url" => config::chain().default_rpc_url().into(),
+            "gamma_url" => config::DEFAULT_GAMMA_URL.into(),
+            "clob_url" => config::chain().default_clob_url().into(),
+            "data_url" => config::DEFAULT_DATA_URL.into(),
+            "bridge_url" => config::DEFAULT_BRIDGE_URL.into(),
+            "timezone" => "utc".into(),
+            "numbers" => "abbreviated".into(),
+            _ => Value::Null,
+        }
+    }
+
+    /// Environment variable overriding the key, for endpoints.
+    fn env_var(&self) -> Option<&'static str> {
+        match self.name {
+            "rpc_url" => Some(config::RPC_URL_ENV_VAR),
+            "gamma_url" => Some(config::GAMMA_URL_ENV_VAR),
+            "clob_url" => Some(config::CLOB_URL_ENV_VAR),
+            "data_url" => Some(config::DATA_URL_ENV_VAR),
+            "bridge_url" => Some(config::BRIDGE_URL_ENV_VAR),
+            _ => None,
+        }
+    }
+
+    /// `raw` as the JSON value stored in `config.json`.
+    fn parse(&self, raw: &str) -> Result<Value> {
+        match self.kind {
+            Kind::Integer => raw.parse::<u64>().map(Value::from).map_err(|_| {
+                CliError::invalid_argument(format!("`{}` must be a whole number", self.name)).into()
+            }),
+            Kind::Url => {
+                reqwest::Url::parse(raw).map_err(|e| {
+                    CliError::invalid_argument(format!("Invalid URL for `{}`: {e}", self.name))
+                })?;
+                Ok(raw.into())
+            }
+            Kind::Text => Ok(raw.into()),
+        }
+    }
+}
+
+/// Effective value of `key` and where it comes from: its environment
+/// variable, the profile in use, the top-level section, or the default.
+fn resolve(key: &Key, config: &Value, profile: &str) -> (Value, String) {
+    if let Some(var) = key.env_var()
+        && let Ok(url) = std::env::var(var)
+        && !url.is_empty()
+    {
+        return (url.into(), var.to_string());
+    }
+    let in_profile = config["profiles"][profile][key.section].get(key.name);
+    if let Some(value) = in_profile {
+        return (value.clone(), format!("profile {profile}"));
+    }
+    match config[key.section].get(key.name) {
+        Some(value) => (value.clone(), "config".to_string()),
+        None => (key.default_value(), "default".to_string()),
+    }
+}
+
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::Null => DASH.to_string(),
+        Value::String(s) => s.clone(),
+        v => v.to_string(),
+    }
+}
+
+pub fn execute(args: ConfigArgs, output: OutputFormat) -> Result<()> {
+    match args.command {
+        ConfigCommand::List => cmd_list(output),
+        ConfigCommand::Get { key } => cmd_get(output, Key::find(&key)?),
+        ConfigCommand::Set { key, value } => {
+            let key = Key::find(&key)?;
+            let value = key.parse(&value)?;
+            cmd_update(output, key, Some(value))
+        }
+        ConfigCommand::Unset { key } => cmd_update(output, Key::find(&key)?, None),
+        ConfigCommand::Edit => cmd_edit(output),
+    }
+}
+
+fn load() -> Result<(Value, String)> {
+    let config = config::load_config()?.unwrap_or_default();
+    Ok((serde_json::to_value(&config)?, config.profile_name()))
+}
+
+fn cmd_list(output: OutputFormat) -> Result<()> {
+    let (config, profile) = load()?;
+    let settings: Vec<_> = KEYS
+        .iter()
+        .map(|key| {
+            let (value, source) = resolve(key, &config, &profile);
+            (key, value, source)
+        })
+        .collect();
+
+    match output {
+        OutputFormat::Json => {
+            let settings: Vec<_> = settings
+                .iter()
+                .map(|(key, value, source)| {
+                    serde_json::json!({
+                        "key": key.name,
+                        "value": value,
+                        "source": source,
+                        "description": key.description,
+                    })
+                })
+                .collect();
+            print_json(&settings)?;
+        }
+        OutputFormat::Table => {
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "Setting")]
+                key: &'static str,
+                #[tabled(rename = "Value")]
+                value: String,
+                #[tabled(rename = "Source")]
+                source: String,
+                #[tabled(rename = "Description")]
+                description: &'static str,
+            }
+            let rows = settings.iter().map(|(key, value, source)| Row {
+                key: key.name,
+                value: display_value(value),
+                source: source.clone(),
+                description: key.description,
+            });
+            let table = Table::new(rows).with(Style::rounded()).to_string();
+            println!("{table}");
+        }
+    }
+    Ok(())
+}
+
+fn cmd_get(output: OutputFormat, key: &Key) -> Result<()> {
+    let (config, profile) = load()?;
+    let (value, source) = resolve(key, &config, &profile);
+
+    match output {
+        OutputFormat::Table => println!("{}", display_value(&value)),
+        OutputFormat::Json => print_json(&serde_json::json!({
+            "key": key.name,
+            "value": value,
+            "source": source,
+        }))?,
+    }
+    Ok(())
+}
+
+/// Set `key` to `value`, or remove it with `None`, in the profile requested
+/// with `--profile` or else the top-level section.
+fn cmd_update(output: OutputFormat, key: &Key, value: Option<Value>) -> Result<()> {
+    let config = config::load_config()?.unwrap_or_default();
+    let profile = config::requested_profile();
+    if let Some(name) = &profile
+        && !config.profiles.contains_key(name)
+    {
+        return Err(config::profile_not_found(name));
+    }
+
+    let mut json = serde_json::to_value(&config)?;
+    let target = match &profile {
+        Some(name) => &mut json["profiles"][name.as_str()],
+        None => &mut json,
+    };
+    match &value {
+        Some(value) => target[key.section][key.name] = value.clone(),
+        None => {
+            if let Some(section) = target[key.section].as_object_mut() {
+                section.remove(key.name);
+            }
+        }
+    }
+    let config: Config = serde_json::from_value(json).map_err(|e| {
+        CliError::invalid_argument(format!("Invalid value for `{}`: {e}", key.name))
+    })?;
+    config::save_config(&config)?;
+
+    match output {
+        OutputFormat::Table => {
+            let scope = profile
+                .as_ref()
+                .map_or_else(String::new, |p| format!(" for profile `{p}`"));
+            match &value {
+                Some(value) => println!("Set `{}` to {}{scope}.", key.name, display_value(value)),
+                None => println!("Unset `{}`{scope}.", key.name),
+            }
+        }
+        OutputFormat::Json => print_json(&serde_json::json!({
+            "key": key.name,
+            "value": value,
+            "profile": profile,
+        }))?,
+    }
+    Ok(())
+}
+
+fn cmd_edit(output: OutputFormat) -> Result<()> {
+    let path = config::config_path()?;
+    if !path.exists() {
+        config::save_config(&Config::default())?;
+    }
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string());
+
+    // Through the shell, so an editor with arguments like `code --wait` works.
+    #[cfg(unix)]
+    let status = std::process::Command::new("sh")
+        .args(["-c", &format!("{editor} \"$1\""), "sh"])
+        .arg(&path)
+        .status();
+    #[cfg(not(unix))]
+    let status = std::process::Command::new("cmd")
+        .args(["/C", &editor])
+        .arg(&path)
+        .status();
+    let status = status.context(format!("Failed to run editor `{editor}`"))?;
+    if !status.success() {
+        bail!("Editor `{editor}` exited with {status}");
+    }
+
+    config::load_config().context(format!(
+        "{} is no longer valid; run `polymarket config edit` to fix it",
+        path.display()
+    ))?;
+    match output {
+        OutputFormat::Table => println!("Saved {}", path.display()),
+        OutputFormat::Json => {
+            print_json(&serde_json::json!({"path": path.display().to_string()}))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_key_is_a_config_field() {
+        for key in KEYS {
+            let value = key.parse(match key.kind {
+                Kind::Integer => "10",
+                Kind::Url => "http://localhost:8080",
+                Kind::Text => match key.name {
+                    "output" => "json",
+                    "timezone" => "utc",
+                    "numbers" => "full",
+                    _ => "cents",
+                },
+            });
+            let mut json = serde_json::to_value(Config::default()).unwrap();
+            json[key.section][key.name] = value.unwrap();
+            let config: Config = serde_json::from_value(json).unwrap();
+            let round_trip = serde_json::to_value(&config).unwrap();
+            assert!(
+                round_trip[key.section].get(key.name).is_some(),
+                "`{}` isn't stored in `{}`",
+                key.name,
+                key.section
+            );
+        }
+    }
+
+    #[test]
+    fn profile_value_overrides_top_level() {
+        let config = serde_json::json!({
+            "settings": {"limit": 50},
+            "profiles": {"desk": {"settings": {"limit": 100}}},
+        });
+        let limit = Key::find("limit").unwrap();
+        assert_eq!(
+            resolve(limit, &config, "desk"),
+            (Value::from(100), "profile desk".to_string())
+        );
+        assert_eq!(
+            resolve(limit, &config, "other"),
+            (Value::from(50), "config".to_string())
+        );
+        let output = Key::find("output").unwrap();
+        assert_eq!(
+            resolve(output, &config, "desk"),
+            (Value::from("table"), "default".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_checks_kind() {
+        assert!(Key::find("limit").unwrap().parse("abc").is_err());
+        assert!(Key::find("gamma_url").unwrap().parse("not a url").is_err());
+        assert!(Key::find("nope").is_err());
+    }
+}
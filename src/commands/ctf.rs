@@ -1,19 +1,108 @@
-use alloy::primitives::U256;
+#![allow(clippy::exhaustive_enums, reason = "Generated by sol! macro")]
+#![allow(clippy::exhaustive_structs, reason = "Generated by sol! macro")]
+
+use std::collections::BTreeMap;
+
+use alloy::primitives::{Bytes, U256};
+use alloy::sol;
 use anyhow::{Context, Result};
 use clap::{Args, Subcommand};
+use polymarket_client_sdk::ctf;
 use polymarket_client_sdk::ctf::types::{
-    CollectionIdRequest, ConditionIdRequest, MergePositionsRequest, PositionIdRequest,
-    RedeemNegRiskRequest, RedeemPositionsRequest, SplitPositionRequest,
+    CollectionIdRequest, ConditionIdRequest, PositionIdRequest,
 };
+use polymarket_client_sdk::data;
+use polymarket_client_sdk::data::types::request::PositionsRequest;
+use polymarket_client_sdk::data::types::response::Position;
+use polymarket_client_sdk::gamma;
+use polymarket_client_sdk::gamma::types::request::MarketsRequest;
+use polymarket_client_sdk::gamma::types::response::Market;
 use polymarket_client_sdk::types::{Address, B256};
-use polymarket_client_sdk::{POLYGON, ctf};
 use rust_decimal::Decimal;
 
-use crate::auth;
-use crate::output::OutputFormat;
-use crate::output::ctf as ctf_output;
+use crate::config::SignatureType;
+use crate::error::{CliError, ErrorCode};
+use crate::output::ctf::{
+    self as ctf_output, BalanceChange, MergeItem, RedeemOutcome, Redemption, Resolution,
+};
+use crate::output::{OutputFormat, truncate};
+use crate::tx::{self, Call, Sender, Sent, TxArgs};
+use crate::{auth, config};
+
+use super::USDC_DECIMALS;
+
+sol! {
+    #[sol(rpc)]
+    interface IConditionalTokens {
+        function balanceOf(address account, uint256 id) external view returns (uint256);
+        function payoutDenominator(bytes32 conditionId) external view returns (uint256);
+        function payoutNumerators(bytes32 conditionId, uint256 index) external view returns (uint256);
+        function getOutcomeSlotCount(bytes32 conditionId) external view returns (uint256);
+        function getCollectionId(
+            bytes32 parentCollectionId,
+            bytes32 conditionId,
+            uint256 indexSet
+        ) external view returns (bytes32);
+        function getPositionId(address collateralToken, bytes32 collectionId) external pure returns (uint256);
+        function splitPosition(
+            address collateralToken,
+            bytes32 parentCollectionId,
+            bytes32 conditionId,
+            uint256[] partition,
+            uint256 amount
+        ) external;
+        function mergePositions(
+            address collateralToken,
+            bytes32 parentCollectionId,
+            bytes32 conditionId,
+            uint256[] partition,
+            uint256 amount
+        ) external;
+        function redeemPositions(
+            address collateralToken,
+            bytes32 parentCollectionId,
+            bytes32 conditionId,
+            uint256[] indexSets
+        ) external;
+        function safeTransferFrom(
+            address from,
+            address to,
+            uint256 id,
+            uint256 value,
+            bytes data
+        ) external;
+    }
+
+    #[sol(rpc)]
+    interface INegRiskAdapter {
+        function wcol() external view returns (address);
+        function mergePositions(bytes32 conditionId, uint256 amount) external;
+        function redeemPositions(bytes32 conditionId, uint256[] amounts) external;
+    }
+}
+
+fn conditional_tokens() -> Address {
+    config::chain().contracts(false).conditional_tokens
+}
+
+fn neg_risk_adapter() -> Result<Address> {
+    config::chain()
+        .contracts(true)
+        .neg_risk_adapter
+        .context("This chain has no neg-risk adapter")
+}
 
-use super::{USDC_ADDRESS_STR, USDC_DECIMALS};
+fn redeem_call(condition_id: B256) -> Call {
+    Call::new(
+        conditional_tokens(),
+        &IConditionalTokens::redeemPositionsCall {
+            collateralToken: config::chain().usdc(),
+            parentCollectionId: B256::ZERO,
+            conditionId: condition_id,
+            indexSets: binary_u256_vec(),
+        },
+    )
+}
 
 #[derive(Args)]
 pub struct CtfArgs {
@@ -31,15 +120,17 @@ pub enum CtfCommand {
         /// Amount in USDC (e.g. 10 for $10)
         #[arg(long)]
         amount: String,
-        /// Collateral token address (defaults to USDC)
-        #[arg(long, default_value = USDC_ADDRESS_STR)]
-        collateral: Address,
+        /// Collateral token address (defaults to the chain's USDC)
+        #[arg(long)]
+        collateral: Option<Address>,
         /// Custom partition as comma-separated index sets (e.g. "1,2" for binary, "1,2,4" for 3-outcome)
         #[arg(long)]
         partition: Option<String>,
         /// Parent collection ID for nested positions (defaults to zero)
         #[arg(long)]
         parent_collection: Option<B256>,
+        #[command(flatten)]
+        tx: TxArgs,
     },
     /// Merge outcome tokens back into collateral
     Merge {
@@ -49,30 +140,34 @@ pub enum CtfCommand {
         /// Amount in USDC (e.g. 10 for $10)
         #[arg(long)]
         amount: String,
-        /// Collateral token address (defaults to USDC)
-        #[arg(long, default_value = USDC_ADDRESS_STR)]
-        collateral: Address,
+        /// Collateral token address (defaults to the chain's USDC)
+        #[arg(long)]
+        collateral: Option<Address>,
         /// Custom partition as comma-separated index sets (e.g. "1,2" for binary, "1,2,4" for 3-outcome)
         #[arg(long)]
         partition: Option<String>,
         /// Parent collection ID for nested positions (defaults to zero)
         #[arg(long)]
         parent_collection: Option<B256>,
+        #[command(flatten)]
+        tx: TxArgs,
     },
     /// Redeem winning tokens after market resolution
     Redeem {
         /// Condition ID (0x-prefixed 32-byte hex)
         #[arg(long)]
         condition: B256,
-        /// Collateral token address (defaults to USDC)
-        #[arg(long, default_value = USDC_ADDRESS_STR)]
-        collateral: Address,
+        /// Collateral token address (defaults to the chain's USDC)
+        #[arg(long)]
+        collateral: Option<Address>,
         /// Custom index sets as comma-separated values (e.g. "1,2" for binary, "1" for YES only)
         #[arg(long)]
         index_sets: Option<String>,
         /// Parent collection ID for nested positions (defaults to zero)
         #[arg(long)]
         parent_collection: Option<B256>,
+        #[command(flatten)]
+        tx: TxArgs,
     },
     /// Redeem neg-risk positions
     RedeemNegRisk {
@@ -82,6 +177,47 @@ pub enum CtfCommand {
         /// Comma-separated amounts in USDC for each outcome (e.g. "10,5")
         #[arg(long)]
         amounts: String,
+        #[command(flatten)]
+        tx: TxArgs,
+    },
+    /// Redeem every resolved position of the wallet
+    RedeemAll {
+        /// List what would be redeemed and the expected payout without sending transactions
+        #[arg(long)]
+        dry_run: bool,
+        #[command(flatten)]
+        tx: TxArgs,
+    },
+    /// Merge complete outcome sets back into USDC in every market
+    MergeAll {
+        /// Tokens of each outcome to keep in every market, in shares (e.g. 5)
+        #[arg(long, default_value = "0")]
+        keep: String,
+        /// List what would be merged without sending transactions
+        #[arg(long)]
+        dry_run: bool,
+        #[command(flatten)]
+        tx: TxArgs,
+    },
+    /// Show whether a condition is resolved on chain, its payouts and the
+    /// gamma API's resolution next to them
+    Condition {
+        /// Condition ID (0x-prefixed 32-byte hex)
+        condition: B256,
+    },
+    /// Send outcome tokens to another address
+    Transfer {
+        /// Position ID (ERC1155 token ID) to send
+        #[arg(long)]
+        position: U256,
+        /// Tokens to send, in shares (e.g. 5)
+        #[arg(long)]
+        amount: String,
+        /// Recipient address
+        #[arg(long)]
+        to: Address,
+        #[command(flatten)]
+        tx: TxArgs,
     },
     /// Calculate a condition ID from oracle, question, and outcome count
     ConditionId {
@@ -109,9 +245,9 @@ pub enum CtfCommand {
     },
     /// Calculate a position ID (ERC1155 token ID) from collateral and collection
     PositionId {
-        /// Collateral token address (defaults to USDC)
-        #[arg(long, default_value = USDC_ADDRESS_STR)]
-        collateral: Address,
+        /// Collateral token address (defaults to the chain's USDC)
+        #[arg(long)]
+        collateral: Option<Address>,
         /// Collection ID (0x-prefixed 32-byte hex)
         #[arg(long)]
         collection: B256,
@@ -123,17 +259,25 @@ fn usdc_to_raw(val: Decimal) -> Result<U256> {
     let raw = val * multiplier;
     anyhow::ensure!(
         raw.fract().is_zero(),
-        "Amount {val} exceeds USDC precision (max 6 decimal places)"
+        CliError::invalid_argument(format!(
+            "Amount {val} exceeds USDC precision (max 6 decimal places)"
+        ))
     );
     let raw_u64: u64 = raw
         .try_into()
-        .map_err(|_| anyhow::anyhow!("Amount too large: {val}"))?;
+        .map_err(|_| CliError::invalid_argument(format!("Amount too large: {val}")))?;
     Ok(U256::from(raw_u64))
 }
 
-fn parse_usdc_amount(s: &str) -> Result<U256> {
-    let val: Decimal = s.trim().parse().context(format!("Invalid amount: {s}"))?;
-    anyhow::ensure!(val > Decimal::ZERO, "Amount must be positive");
+pub(crate) fn parse_usdc_amount(s: &str) -> Result<U256> {
+    let val: Decimal = s
+        .trim()
+        .parse()
+        .context(CliError::invalid_argument(format!("Invalid amount: {s}")))?;
+    anyhow::ensure!(
+        val > Decimal::ZERO,
+        CliError::invalid_argument("Amount must be positive")
+    );
     usdc_to_raw(val)
 }
 
@@ -141,12 +285,12 @@ fn parse_usdc_amounts(s: &str) -> Result<Vec<U256>> {
     s.split(',')
         .map(|part| {
             let trimmed = part.trim();
-            let val: Decimal = trimmed
-                .parse()
-                .context(format!("Invalid amount: {trimmed}"))?;
+            let val: Decimal = trimmed.parse().context(CliError::invalid_argument(format!(
+                "Invalid amount: {trimmed}"
+            )))?;
             anyhow::ensure!(
                 val >= Decimal::ZERO,
-                "Amount must be non-negative: {trimmed}"
+                CliError::invalid_argument(format!("Amount must be non-negative: {trimmed}"))
             );
             usdc_to_raw(val)
         })
@@ -157,9 +301,9 @@ fn parse_u256_csv(s: &str) -> Result<Vec<U256>> {
     s.split(',')
         .map(|part| {
             let trimmed = part.trim();
-            let val: u64 = trimmed
-                .parse()
-                .context(format!("Invalid value: {trimmed}"))?;
+            let val: u64 = trimmed.parse().context(CliError::invalid_argument(format!(
+                "Invalid value: {trimmed}"
+            )))?;
             Ok(U256::from(val))
         })
         .collect()
@@ -171,7 +315,564 @@ fn binary_u256_vec() -> Vec<U256> {
     DEFAULT_BINARY_SETS.iter().map(|&n| U256::from(n)).collect()
 }
 
-pub async fn execute(args: CtfArgs, output: OutputFormat, private_key: Option<&str>) -> Result<()> {
+/// Page size of the data API `positions` endpoint.
+const POSITIONS_PAGE: i32 = 500;
+
+/// Positions of `user` from the data API, grouped by condition.
+pub(crate) async fn positions_by_condition(
+    client: &data::Client,
+    user: Address,
+    redeemable_only: bool,
+) -> Result<BTreeMap<B256, Vec<Position>>> {
+    let mut by_condition: BTreeMap<B256, Vec<Position>> = BTreeMap::new();
+    let mut offset = 0;
+    loop {
+        let request = PositionsRequest::builder()
+            .user(user)
+            .maybe_redeemable(redeemable_only.then_some(true))
+            .size_threshold(Decimal::ZERO)
+            .limit(POSITIONS_PAGE)?
+            .offset(offset)?
+            .build();
+        let page = client
+            .positions(&request)
+            .await
+            .context("Failed to list positions")?;
+        let done = page.len() < POSITIONS_PAGE as usize;
+        for position in page {
+            by_condition
+                .entry(position.condition_id)
+                .or_default()
+                .push(position);
+        }
+        offset += POSITIONS_PAGE;
+        if done || offset > 10_000 {
+            return Ok(by_condition);
+        }
+    }
+}
+
+fn redemption(condition_id: B256, positions: &[Position]) -> Redemption {
+    Redemption {
+        condition_id,
+        title: positions[0].title.clone(),
+        neg_risk: positions.iter().any(|p| p.negative_risk),
+        outcomes: positions
+            .iter()
+            .map(|p| RedeemOutcome {
+                outcome: p.outcome.clone(),
+                outcome_index: p.outcome_index,
+                size: p.size,
+            })
+            .collect(),
+        payout: positions.iter().map(|p| p.size * p.cur_price).sum(),
+        sent: None,
+        gas_estimate: None,
+        error: None,
+    }
+}
+
+/// Per-outcome amounts for `redeem_neg_risk`: the on-chain balance of each
+/// held outcome token at its outcome index.
+async fn neg_risk_amounts(
+    provider: &(impl alloy::providers::Provider + Clone),
+    owner: Address,
+    positions: &[Position],
+) -> Result<Vec<U256>> {
+    let ctf = IConditionalTokens::new(conditional_tokens(), provider);
+    let slots = positions
+        .iter()
+        .filter_map(|p| usize::try_from(p.outcome_index).ok())
+        .max()
+        .map_or(2, |max| (max + 1).max(2));
+    let mut amounts = vec![U256::ZERO; slots];
+    for position in positions {
+        let index = usize::try_from(position.outcome_index)
+            .context(format!("Invalid outcome index {}", position.outcome_index))?;
+        amounts[index] = ctf
+            .balanceOf(owner, position.asset)
+            .call()
+            .await
+            .context("Failed to read outcome token balance")?;
+    }
+    Ok(amounts)
+}
+
+/// What became of one call of a batch.
+enum Submitted {
+    Sent(Sent),
+    /// Passed `--simulate`, with its gas estimate.
+    Simulated(Option<u64>),
+}
+
+/// Send each call in turn, or with `--simulate` only check them. Calls that
+/// couldn't be built, or revert, fail on their own.
+async fn submit_all(
+    private_key: Option<&str>,
+    sender: Sender,
+    calls: Vec<Result<Call>>,
+) -> Result<Vec<Result<Submitted>>> {
+    let mut results = Vec::with_capacity(calls.len());
+    if tx::simulating() {
+        let provider = auth::create_readonly_provider().await?;
+        for call in calls {
+            results.push(
+                async {
+                    let simulation = tx::simulate(&provider, sender, &call?).await?;
+                    match simulation.revert_reason {
+                        Some(reason) => anyhow::bail!("Would revert: {reason}"),
+                        None => Ok(Submitted::Simulated(simulation.gas_estimate)),
+                    }
+                }
+                .await,
+            );
+        }
+    } else {
+        let provider = auth::create_provider(private_key).await?;
+        for call in calls {
+            results.push(
+                async { Ok(Submitted::Sent(tx::send(&provider, sender, call?).await?)) }.await,
+            );
+        }
+    }
+    Ok(results)
+}
+
+async fn redeem_all(
+    data: &data::Client,
+    output: OutputFormat,
+    private_key: Option<&str>,
+    sender: Sender,
+    dry_run: bool,
+) -> Result<()> {
+    let owner = sender.address();
+    let groups = positions_by_condition(data, owner, true).await?;
+    let mut redemptions: Vec<Redemption> = groups
+        .iter()
+        .map(|(condition, positions)| redemption(*condition, positions))
+        .collect();
+
+    if !dry_run && !redemptions.is_empty() {
+        let reader = auth::create_readonly_provider().await?;
+        let mut calls = Vec::new();
+        for redemption in &redemptions {
+            let positions = &groups[&redemption.condition_id];
+            calls.push(
+                async {
+                    if !redemption.neg_risk {
+                        return Ok(redeem_call(redemption.condition_id));
+                    }
+                    let amounts = neg_risk_amounts(&reader, owner, positions).await?;
+                    Ok(Call::new(
+                        neg_risk_adapter()?,
+                        &INegRiskAdapter::redeemPositionsCall {
+                            conditionId: redemption.condition_id,
+                            amounts,
+                        },
+                    ))
+                }
+                .await,
+            );
+        }
+        let results = submit_all(private_key, sender, calls).await?;
+        for (redemption, result) in redemptions.iter_mut().zip(results) {
+            match result {
+                Ok(Submitted::Sent(sent)) => redemption.sent = Some(sent),
+                Ok(Submitted::Simulated(gas)) => redemption.gas_estimate = gas,
+                Err(e) => redemption.error = Some(format!("{e:#}")),
+            }
+        }
+    }
+
+    let simulated = tx::simulating() && !dry_run;
+    ctf_output::print_redeem_all(owner, &redemptions, dry_run, simulated, &output)?;
+    let failed = redemptions.iter().filter(|r| r.error.is_some()).count();
+    anyhow::ensure!(
+        failed == 0,
+        "{failed} of {} redemptions failed",
+        redemptions.len()
+    );
+    Ok(())
+}
+
+pub(crate) fn raw_to_usdc(raw: U256) -> Result<Decimal> {
+    let raw = i64::try_from(raw).map_err(|_| anyhow::anyhow!("Amount {raw} is too large"))?;
+    Ok(Decimal::new(raw, USDC_DECIMALS).normalize())
+}
+
+/// Every outcome of a condition with `slots` outcomes, as index sets.
+fn full_partition(slots: u64) -> Vec<U256> {
+    (0..slots).map(|i| U256::from(1u64) << i).collect()
+}
+
+/// `owner`'s balance of each outcome token of `condition`, backed by
+/// `collateral`.
+async fn outcome_balances(
+    ctf: &IConditionalTokens::IConditionalTokensInstance<&impl alloy::providers::Provider>,
+    owner: Address,
+    collateral: Address,
+    condition: B256,
+) -> Result<Vec<U256>> {
+    let slots = ctf
+        .getOutcomeSlotCount(condition)
+        .call()
+        .await
+        .context("Failed to read the condition")?;
+    let mut balances = Vec::new();
+    for index_set in full_partition(slots.to::<u64>()) {
+        let collection = ctf
+            .getCollectionId(B256::ZERO, condition, index_set)
+            .call()
+            .await?;
+        let position = ctf.getPositionId(collateral, collection).call().await?;
+        balances.push(
+            ctf.balanceOf(owner, position)
+                .call()
+                .await
+                .context("Failed to read outcome token balance")?,
+        );
+    }
+    Ok(balances)
+}
+
+async fn merge_all(
+    data: &data::Client,
+    output: OutputFormat,
+    private_key: Option<&str>,
+    sender: Sender,
+    keep: &str,
+    dry_run: bool,
+) -> Result<()> {
+    let keep: Decimal = keep
+        .trim()
+        .parse()
+        .context(CliError::invalid_argument(format!(
+            "Invalid amount: {keep}"
+        )))?;
+    anyhow::ensure!(
+        keep >= Decimal::ZERO,
+        CliError::invalid_argument("--keep must not be negative")
+    );
+    let keep = usdc_to_raw(keep)?;
+
+    let owner = sender.address();
+    let groups = positions_by_condition(data, owner, false).await?;
+    let reader = auth::create_readonly_provider().await?;
+    let ctf_tokens = IConditionalTokens::new(conditional_tokens(), &reader);
+
+    // Token balances come from the chain, for every outcome of the
+    // condition: the data API only tells us which markets the wallet has
+    // touched.
+    let mut wrapped_collateral = None;
+    let mut merges = Vec::new();
+    let mut amounts = Vec::new();
+    for (condition_id, positions) in &groups {
+        let position = &positions[0];
+        // Neg-risk tokens are backed by the adapter's wrapped collateral.
+        let collateral = if position.negative_risk {
+            match wrapped_collateral {
+                Some(wcol) => wcol,
+                None => {
+                    let adapter = INegRiskAdapter::new(neg_risk_adapter()?, &reader);
+                    let wcol = adapter
+                        .wcol()
+                        .call()
+                        .await
+                        .context("Failed to read the wrapped collateral")?;
+                    *wrapped_collateral.insert(wcol)
+                }
+            }
+        } else {
+            config::chain().usdc()
+        };
+        let balances = outcome_balances(&ctf_tokens, owner, collateral, *condition_id).await?;
+        let held = balances.iter().copied().min().unwrap_or_default();
+        if held <= keep {
+            continue;
+        }
+        let amount = held - keep;
+        amounts.push((amount, full_partition(balances.len() as u64)));
+        // An amount too large to show fails this merge alone.
+        let (usdc, error) = match raw_to_usdc(amount) {
+            Ok(usdc) => (usdc, None),
+            Err(e) => (Decimal::ZERO, Some(format!("{e:#}"))),
+        };
+        merges.push(MergeItem {
+            condition_id: *condition_id,
+            title: position.title.clone(),
+            neg_risk: position.negative_risk,
+            amount: usdc,
+            sent: None,
+            gas_estimate: None,
+            error,
+        });
+    }
+
+    if !dry_run && merges.iter().any(|m| m.error.is_none()) {
+        let calls = merges
+            .iter()
+            .zip(amounts)
+            .filter(|(merge, _)| merge.error.is_none())
+            .map(|(merge, (amount, partition))| {
+                // Neg-risk positions are backed by the adapter's wrapped
+                // collateral, so they merge through the adapter.
+                if merge.neg_risk {
+                    neg_risk_adapter().map(|adapter| {
+                        Call::new(
+                            adapter,
+                            &INegRiskAdapter::mergePositionsCall {
+                                conditionId: merge.condition_id,
+                                amount,
+                            },
+                        )
+                    })
+                } else {
+                    Ok(Call::new(
+                        conditional_tokens(),
+                        &IConditionalTokens::mergePositionsCall {
+                            collateralToken: config::chain().usdc(),
+                            parentCollectionId: B256::ZERO,
+                            conditionId: merge.condition_id,
+                            partition,
+                            amount,
+                        },
+                    ))
+                }
+            });
+        let results = submit_all(private_key, sender, calls.collect()).await?;
+        let pending = merges.iter_mut().filter(|m| m.error.is_none());
+        for (merge, result) in pending.zip(results) {
+            match result {
+                Ok(Submitted::Sent(sent)) => merge.sent = Some(sent),
+                Ok(Submitted::Simulated(gas)) => merge.gas_estimate = gas,
+                Err(e) => merge.error = Some(format!("{e:#}")),
+            }
+        }
+    }
+
+    let simulated = tx::simulating() && !dry_run;
+    ctf_output::print_merge_all(owner, &merges, dry_run, simulated, &output)?;
+    let failed = merges.iter().filter(|m| m.error.is_some()).count();
+    anyhow::ensure!(failed == 0, "{failed} of {} merges failed", merges.len());
+    Ok(())
+}
+
+fn collateral_name(collateral: Address, parent: B256) -> String {
+    if !parent.is_zero() {
+        "Parent position tokens".to_string()
+    } else if collateral == config::chain().usdc() {
+        "USDC".to_string()
+    } else {
+        format!("{collateral} tokens")
+    }
+}
+
+fn index_set_tokens(index_set: U256) -> String {
+    format!("Index set {index_set} tokens")
+}
+
+/// Balance changes of splitting `amount` of collateral into `partition`,
+/// or of merging it back.
+fn split_changes(
+    collateral: Address,
+    parent: B256,
+    partition: &[U256],
+    amount: U256,
+    merge: bool,
+) -> Result<Vec<BalanceChange>> {
+    let amount = raw_to_usdc(amount)?;
+    let (into, from) = if merge {
+        (amount, -amount)
+    } else {
+        (-amount, amount)
+    };
+    Ok(std::iter::once(BalanceChange {
+        asset: collateral_name(collateral, parent),
+        amount: into,
+    })
+    .chain(partition.iter().map(|&set| BalanceChange {
+        asset: index_set_tokens(set),
+        amount: from,
+    }))
+    .collect())
+}
+
+/// Payout numerators and denominator of `condition`; `None` while it's
+/// unresolved.
+async fn payouts(
+    ctf: &IConditionalTokens::IConditionalTokensInstance<&impl alloy::providers::Provider>,
+    condition: B256,
+) -> Result<Option<(Vec<U256>, U256)>> {
+    let denominator = ctf.payoutDenominator(condition).call().await?;
+    if denominator.is_zero() {
+        return Ok(None);
+    }
+    let slots = ctf.getOutcomeSlotCount(condition).call().await?;
+    let mut numerators = Vec::new();
+    for index in 0..slots.to::<u64>() {
+        numerators.push(
+            ctf.payoutNumerators(condition, U256::from(index))
+                .call()
+                .await?,
+        );
+    }
+    Ok(Some((numerators, denominator)))
+}
+
+/// The payout `condition` records on the ConditionalTokens contract.
+async fn resolution(condition: B256) -> Result<Resolution> {
+    let provider = auth::create_readonly_provider().await?;
+    let ctf = IConditionalTokens::new(conditional_tokens(), &provider);
+    let slots = ctf
+        .getOutcomeSlotCount(condition)
+        .call()
+        .await
+        .context("Failed to read the condition")?;
+    anyhow::ensure!(
+        !slots.is_zero(),
+        CliError::new(
+            ErrorCode::NotFound,
+            format!(
+                "Condition {condition} isn't prepared on the {} ConditionalTokens contract",
+                config::chain().name()
+            )
+        )
+    );
+    // Unresolved conditions still list every outcome, with nothing paid.
+    let (payout_numerators, payout_denominator) = payouts(&ctf, condition)
+        .await?
+        .unwrap_or_else(|| (vec![U256::ZERO; slots.to::<usize>()], U256::ZERO));
+    Ok(Resolution {
+        condition_id: condition,
+        payout_numerators,
+        payout_denominator,
+    })
+}
+
+/// The gamma market of `condition`, if it lists one.
+async fn gamma_market(gamma: &gamma::Client, condition: B256) -> Result<Option<Market>> {
+    let request = MarketsRequest::builder()
+        .condition_ids(vec![condition])
+        .build();
+    Ok(gamma.markets(&request).await?.into_iter().next())
+}
+
+/// Payout of `amount` tokens of `index_set`.
+fn payout(amount: U256, index_set: U256, numerators: &[U256], denominator: U256) -> U256 {
+    let share: U256 = numerators
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| index_set.bit(*i))
+        .map(|(_, n)| *n)
+        .sum();
+    amount * share / denominator
+}
+
+/// Balance changes of redeeming `owner`'s tokens of `index_sets`, read from
+/// the chain.
+async fn redeem_changes(
+    owner: Address,
+    collateral: Address,
+    parent: B256,
+    condition: B256,
+    index_sets: &[U256],
+) -> Result<Vec<BalanceChange>> {
+    let provider = auth::create_readonly_provider().await?;
+    let ctf = IConditionalTokens::new(conditional_tokens(), &provider);
+    let Some((numerators, denominator)) = payouts(&ctf, condition).await? else {
+        return Ok(Vec::new());
+    };
+    let mut total = U256::ZERO;
+    let mut changes = Vec::new();
+    for &index_set in index_sets {
+        let collection = ctf
+            .getCollectionId(parent, condition, index_set)
+            .call()
+            .await?;
+        let position = ctf.getPositionId(collateral, collection).call().await?;
+        let balance = ctf.balanceOf(owner, position).call().await?;
+        total += payout(balance, index_set, &numerators, denominator);
+        changes.push(BalanceChange {
+            asset: index_set_tokens(index_set),
+            amount: -raw_to_usdc(balance)?,
+        });
+    }
+    changes.insert(
+        0,
+        BalanceChange {
+            asset: collateral_name(collateral, parent),
+            amount: raw_to_usdc(total)?,
+        },
+    );
+    Ok(changes)
+}
+
+/// Balance changes of redeeming `amounts` of each outcome of a neg-risk
+/// market.
+async fn redeem_neg_risk_changes(condition: B256, amounts: &[U256]) -> Result<Vec<BalanceChange>> {
+    let provider = auth::create_readonly_provider().await?;
+    let ctf = IConditionalTokens::new(conditional_tokens(), &provider);
+    let Some((numerators, denominator)) = payouts(&ctf, condition).await? else {
+        return Ok(Vec::new());
+    };
+    let mut total = U256::ZERO;
+    let mut changes = Vec::new();
+    for (index, &amount) in amounts.iter().enumerate() {
+        let index_set = U256::from(1u64) << index;
+        total += payout(amount, index_set, &numerators, denominator);
+        changes.push(BalanceChange {
+            asset: index_set_tokens(index_set),
+            amount: -raw_to_usdc(amount)?,
+        });
+    }
+    changes.insert(
+        0,
+        BalanceChange {
+            asset: "USDC".to_string(),
+            amount: raw_to_usdc(total)?,
+        },
+    );
+    Ok(changes)
+}
+
+/// Send `call`, or with `--simulate` check it and report the balance
+/// `changes`, which are only worked out then.
+pub(crate) async fn execute_call(
+    operation: &'static str,
+    failure: &'static str,
+    private_key: Option<&str>,
+    sender: Sender,
+    call: Call,
+    changes: impl Future<Output = Result<Vec<BalanceChange>>>,
+    output: &OutputFormat,
+) -> Result<()> {
+    if tx::simulating() {
+        let provider = auth::create_readonly_provider().await?;
+        let simulation = tx::simulate(&provider, sender, &call).await?;
+        let changes = match simulation.revert_reason {
+            None => changes.await?,
+            Some(_) => Vec::new(),
+        };
+        ctf_output::print_simulation(operation, sender, &simulation, &changes, output)?;
+        if let Some(reason) = simulation.revert_reason {
+            anyhow::bail!("The {operation} would revert: {reason}");
+        }
+        return Ok(());
+    }
+    let provider = auth::create_provider(private_key).await?;
+    let sent = tx::send(&provider, sender, call).await.context(failure)?;
+    ctf_output::print_tx_result(operation, sender, &sent, output)
+}
+
+pub async fn execute(
+    args: CtfArgs,
+    output: OutputFormat,
+    private_key: Option<&str>,
+    signature_type: Option<SignatureType>,
+    gamma: impl FnOnce() -> Result<gamma::Client>,
+    data: impl FnOnce() -> Result<data::Client>,
+) -> Result<()> {
+    let sender = || Sender::resolve(private_key, signature_type);
     match args.command {
         CtfCommand::Split {
             condition,
@@ -179,31 +880,34 @@ pub async fn execute(args: CtfArgs, output: OutputFormat, private_key: Option<&s
             collateral,
             partition,
             parent_collection,
+            tx: options,
         } => {
-            let usdc_amount = parse_usdc_amount(&amount)?;
+            tx::set_options(options)?;
+            let collateral = collateral.unwrap_or_else(|| config::chain().usdc());
             let parent = parent_collection.unwrap_or_default();
             let partition = match partition {
                 Some(p) => parse_u256_csv(&p)?,
                 None => binary_u256_vec(),
             };
-
-            let provider = auth::create_provider(private_key).await?;
-            let client = ctf::Client::new(provider, POLYGON)?;
-
-            let req = SplitPositionRequest::builder()
-                .collateral_token(collateral)
-                .parent_collection_id(parent)
-                .condition_id(condition)
-                .partition(partition)
-                .amount(usdc_amount)
-                .build();
-
-            let resp = client
-                .split_position(&req)
-                .await
-                .context("Split position failed")?;
-
-            ctf_output::print_tx_result("split", resp.transaction_hash, resp.block_number, &output)
+            let amount = parse_usdc_amount(&amount)?;
+            let changes = split_changes(collateral, parent, &partition, amount, false)?;
+            let call = IConditionalTokens::splitPositionCall {
+                collateralToken: collateral,
+                parentCollectionId: parent,
+                conditionId: condition,
+                partition,
+                amount,
+            };
+            execute_call(
+                "split",
+                "Split position failed",
+                private_key,
+                sender().await?,
+                Call::new(conditional_tokens(), &call),
+                async { Ok(changes) },
+                &output,
+            )
+            .await
         }
         CtfCommand::Merge {
             condition,
@@ -211,83 +915,158 @@ pub async fn execute(args: CtfArgs, output: OutputFormat, private_key: Option<&s
             collateral,
             partition,
             parent_collection,
+            tx: options,
         } => {
-            let usdc_amount = parse_usdc_amount(&amount)?;
+            tx::set_options(options)?;
+            let collateral = collateral.unwrap_or_else(|| config::chain().usdc());
             let parent = parent_collection.unwrap_or_default();
             let partition = match partition {
                 Some(p) => parse_u256_csv(&p)?,
                 None => binary_u256_vec(),
             };
-
-            let provider = auth::create_provider(private_key).await?;
-            let client = ctf::Client::new(provider, POLYGON)?;
-
-            let req = MergePositionsRequest::builder()
-                .collateral_token(collateral)
-                .parent_collection_id(parent)
-                .condition_id(condition)
-                .partition(partition)
-                .amount(usdc_amount)
-                .build();
-
-            let resp = client
-                .merge_positions(&req)
-                .await
-                .context("Merge positions failed")?;
-
-            ctf_output::print_tx_result("merge", resp.transaction_hash, resp.block_number, &output)
+            let amount = parse_usdc_amount(&amount)?;
+            let changes = split_changes(collateral, parent, &partition, amount, true)?;
+            let call = IConditionalTokens::mergePositionsCall {
+                collateralToken: collateral,
+                parentCollectionId: parent,
+                conditionId: condition,
+                partition,
+                amount,
+            };
+            execute_call(
+                "merge",
+                "Merge positions failed",
+                private_key,
+                sender().await?,
+                Call::new(conditional_tokens(), &call),
+                async { Ok(changes) },
+                &output,
+            )
+            .await
         }
         CtfCommand::Redeem {
             condition,
             collateral,
             index_sets,
             parent_collection,
+            tx: options,
         } => {
+            tx::set_options(options)?;
+            let collateral = collateral.unwrap_or_else(|| config::chain().usdc());
             let parent = parent_collection.unwrap_or_default();
             let index_sets = match index_sets {
                 Some(s) => parse_u256_csv(&s)?,
                 None => binary_u256_vec(),
             };
-
-            let provider = auth::create_provider(private_key).await?;
-            let client = ctf::Client::new(provider, POLYGON)?;
-
-            let req = RedeemPositionsRequest::builder()
-                .collateral_token(collateral)
-                .parent_collection_id(parent)
-                .condition_id(condition)
-                .index_sets(index_sets)
-                .build();
-
-            let resp = client
-                .redeem_positions(&req)
-                .await
-                .context("Redeem positions failed")?;
-
-            ctf_output::print_tx_result("redeem", resp.transaction_hash, resp.block_number, &output)
+            let sender = sender().await?;
+            let changes =
+                redeem_changes(sender.address(), collateral, parent, condition, &index_sets);
+            let call = IConditionalTokens::redeemPositionsCall {
+                collateralToken: collateral,
+                parentCollectionId: parent,
+                conditionId: condition,
+                indexSets: index_sets.clone(),
+            };
+            execute_call(
+                "redeem",
+                "Redeem positions failed",
+                private_key,
+                sender,
+                Call::new(conditional_tokens(), &call),
+                changes,
+                &output,
+            )
+            .await
         }
-        CtfCommand::RedeemNegRisk { condition, amounts } => {
+        CtfCommand::RedeemNegRisk {
+            condition,
+            amounts,
+            tx: options,
+        } => {
+            tx::set_options(options)?;
             let amounts = parse_usdc_amounts(&amounts)?;
-
-            let provider = auth::create_provider(private_key).await?;
-            let client = ctf::Client::with_neg_risk(provider, POLYGON)?;
-
-            let req = RedeemNegRiskRequest::builder()
-                .condition_id(condition)
-                .amounts(amounts)
-                .build();
-
-            let resp = client
-                .redeem_neg_risk(&req)
-                .await
-                .context("Redeem neg-risk positions failed")?;
-
-            ctf_output::print_tx_result(
+            let changes = redeem_neg_risk_changes(condition, &amounts);
+            let call = INegRiskAdapter::redeemPositionsCall {
+                conditionId: condition,
+                amounts: amounts.clone(),
+            };
+            execute_call(
                 "redeem-neg-risk",
-                resp.transaction_hash,
-                resp.block_number,
+                "Redeem neg-risk positions failed",
+                private_key,
+                sender().await?,
+                Call::new(neg_risk_adapter()?, &call),
+                changes,
                 &output,
             )
+            .await
+        }
+        CtfCommand::RedeemAll {
+            dry_run,
+            tx: options,
+        } => {
+            tx::set_options(options)?;
+            redeem_all(&data()?, output, private_key, sender().await?, dry_run).await
+        }
+        CtfCommand::MergeAll {
+            keep,
+            dry_run,
+            tx: options,
+        } => {
+            tx::set_options(options)?;
+            merge_all(
+                &data()?,
+                output,
+                private_key,
+                sender().await?,
+                &keep,
+                dry_run,
+            )
+            .await
+        }
+        CtfCommand::Condition { condition } => {
+            let resolution = resolution(condition).await?;
+            // The chain is the authority; gamma's view is shown when it's reachable.
+            let (market, gamma_error) = match gamma() {
+                Ok(client) => match gamma_market(&client, condition).await {
+                    Ok(market) => (market, None),
+                    Err(e) => (None, Some(format!("{e:#}"))),
+                },
+                Err(e) => (None, Some(format!("{e:#}"))),
+            };
+            ctf_output::print_condition(&resolution, market.as_ref(), gamma_error, &output)
+        }
+        CtfCommand::Transfer {
+            position,
+            amount,
+            to,
+            tx: options,
+        } => {
+            tx::set_options(options)?;
+            tx::check_recipient(to)?;
+            let amount = parse_usdc_amount(&amount)?;
+            let sender = sender().await?;
+            let changes = vec![BalanceChange {
+                asset: format!("Token {}", truncate(&position.to_string(), 12)),
+                amount: -raw_to_usdc(amount)?,
+            }];
+            let call = IConditionalTokens::safeTransferFromCall {
+                from: sender.address(),
+                to,
+                id: position,
+                value: amount,
+                data: Bytes::new(),
+            };
+            execute_call(
+                "transfer",
+                "Transfer failed",
+                private_key,
+                sender,
+                Call::new(conditional_tokens(), &call),
+                async { Ok(changes) },
+                &output,
+            )
+            .await
         }
         CtfCommand::ConditionId {
             oracle,
@@ -295,7 +1074,7 @@ pub async fn execute(args: CtfArgs, output: OutputFormat, private_key: Option<&s
             outcomes,
         } => {
             let provider = auth::create_readonly_provider().await?;
-            let client = ctf::Client::new(provider, POLYGON)?;
+            let client = ctf::Client::new(provider, config::chain().id())?;
 
             let req = ConditionIdRequest::builder()
                 .oracle(oracle)
@@ -314,7 +1093,7 @@ pub async fn execute(args: CtfArgs, output: OutputFormat, private_key: Option<&s
             let parent = parent_collection.unwrap_or_default();
 
             let provider = auth::create_readonly_provider().await?;
-            let client = ctf::Client::new(provider, POLYGON)?;
+            let client = ctf::Client::new(provider, config::chain().id())?;
 
             let req = CollectionIdRequest::builder()
                 .parent_collection_id(parent)
@@ -330,10 +1109,10 @@ pub async fn execute(args: CtfArgs, output: OutputFormat, private_key: Option<&s
             collection,
         } => {
             let provider = auth::create_readonly_provider().await?;
-            let client = ctf::Client::new(provider, POLYGON)?;
+            let client = ctf::Client::new(provider, config::chain().id())?;
 
             let req = PositionIdRequest::builder()
-                .collateral_token(collateral)
+                .collateral_token(collateral.unwrap_or_else(|| config::chain().usdc()))
                 .collection_id(collection)
                 .build();
 
@@ -476,6 +1255,34 @@ mod tests {
         assert!(parse_u256_csv("1,abc,3").is_err());
     }
 
+    #[test]
+    fn raw_to_usdc_scales_by_decimals() {
+        assert_eq!(
+            raw_to_usdc(U256::from(12_500_000u64)).unwrap().to_string(),
+            "12.5"
+        );
+        assert_eq!(
+            raw_to_usdc(U256::from(1u64)).unwrap().to_string(),
+            "0.000001"
+        );
+        assert_eq!(raw_to_usdc(U256::ZERO).unwrap().to_string(), "0");
+    }
+
+    #[test]
+    fn raw_to_usdc_rejects_overflow() {
+        assert!(raw_to_usdc(U256::MAX).is_err());
+        assert!(raw_to_usdc(U256::from(u64::MAX)).is_err());
+    }
+
+    #[test]
+    fn full_partition_covers_every_outcome() {
+        assert_eq!(full_partition(2), binary_u256_vec());
+        assert_eq!(
+            full_partition(3),
+            vec![U256::from(1u64), U256::from(2u64), U256::from(4u64)]
+        );
+    }
+
     #[test]
     fn binary_u256_vec_is_binary() {
         let p = binary_u256_vec();
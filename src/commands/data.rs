@@ -1,3 +1,5 @@
+use std::str::FromStr;
+
 use anyhow::Result;
 use clap::{Args, Subcommand};
 use polymarket_client_sdk::data::{
@@ -10,6 +12,8 @@ use polymarket_client_sdk::data::{
 };
 use polymarket_client_sdk::types::{Address, B256};
 
+use crate::config::{self, SignatureType};
+use crate::error::CliError;
 use crate::output::OutputFormat;
 use crate::output::data::{
     print_activity, print_builder_leaderboard, print_builder_volume, print_closed_positions,
@@ -27,12 +31,12 @@ pub struct DataArgs {
 pub enum DataCommand {
     /// Get open positions for a wallet address
     Positions {
-        /// Wallet address (0x...)
-        address: Address,
+        #[command(flatten)]
+        address: AddressArgs,
 
-        /// Max results
-        #[arg(long, default_value = "25")]
-        limit: i32,
+        /// Max results [default: the `limit` setting, or 25]
+        #[arg(long)]
+        limit: Option<i32>,
 
         /// Pagination offset
         #[arg(long)]
@@ -41,12 +45,12 @@ pub enum DataCommand {
 
     /// Get closed positions for a wallet address
     ClosedPositions {
-        /// Wallet address (0x...)
-        address: Address,
+        #[command(flatten)]
+        address: AddressArgs,
 
-        /// Max results
-        #[arg(long, default_value = "25")]
-        limit: i32,
+        /// Max results [default: the `limit` setting, or 25]
+        #[arg(long)]
+        limit: Option<i32>,
 
         /// Pagination offset
         #[arg(long)]
@@ -55,24 +59,24 @@ pub enum DataCommand {
 
     /// Get total position value for a wallet address
     Value {
-        /// Wallet address (0x...)
-        address: Address,
+        #[command(flatten)]
+        address: AddressArgs,
     },
 
     /// Get count of unique markets traded by a wallet
     Traded {
-        /// Wallet address (0x...)
-        address: Address,
+        #[command(flatten)]
+        address: AddressArgs,
     },
 
     /// Get trade history
     Trades {
-        /// Wallet address (0x...)
-        address: Address,
+        #[command(flatten)]
+        address: AddressArgs,
 
-        /// Max results
-        #[arg(long, default_value = "25")]
-        limit: i32,
+        /// Max results [default: the `limit` setting, or 25]
+        #[arg(long)]
+        limit: Option<i32>,
 
         /// Pagination offset
         #[arg(long)]
@@ -81,12 +85,12 @@ pub enum DataCommand {
 
     /// Get on-chain activity for a wallet address
     Activity {
-        /// Wallet address (0x...)
-        address: Address,
+        #[command(flatten)]
+        address: AddressArgs,
 
-        /// Max results
-        #[arg(long, default_value = "25")]
-        limit: i32,
+        /// Max results [default: the `limit` setting, or 25]
+        #[arg(long)]
+        limit: Option<i32>,
 
         /// Pagination offset
         #[arg(long)]
@@ -125,9 +129,9 @@ pub enum DataCommand {
         #[arg(long)]
         order_by: Option<OrderBy>,
 
-        /// Max results
-        #[arg(long, default_value = "25")]
-        limit: i32,
+        /// Max results [default: the `limit` setting, or 25]
+        #[arg(long)]
+        limit: Option<i32>,
 
         /// Pagination offset
         #[arg(long)]
@@ -140,9 +144,9 @@ pub enum DataCommand {
         #[arg(long)]
         period: Option<TimePeriod>,
 
-        /// Max results
-        #[arg(long, default_value = "25")]
-        limit: i32,
+        /// Max results [default: the `limit` setting, or 25]
+        #[arg(long)]
+        limit: Option<i32>,
 
         /// Pagination offset
         #[arg(long)]
@@ -157,6 +161,92 @@ pub enum DataCommand {
     },
 }
 
+#[derive(Args)]
+pub struct AddressArgs {
+    /// Wallet address (0x...), or `self`, `eoa` or `proxy` [default: self]
+    address: Option<WalletAddress>,
+
+    /// Same as the positional address
+    #[arg(long = "address", value_name = "ADDRESS", conflicts_with = "address")]
+    address_flag: Option<WalletAddress>,
+}
+
+impl AddressArgs {
+    fn resolve(
+        self,
+        private_key: Option<&str>,
+        signature_type: Option<SignatureType>,
+    ) -> Result<Address> {
+        self.address
+            .or(self.address_flag)
+            .unwrap_or(WalletAddress::Funder)
+            .resolve(private_key, signature_type)
+    }
+}
+
+/// An address, or one of the configured wallet's.
+#[derive(Clone, Copy, Debug)]
+pub enum WalletAddress {
+    Address(Address),
+    /// `self`: where the wallet's funds are, per its signature type.
+    Funder,
+    /// `eoa`: the signing key's own address.
+    Eoa,
+    /// `proxy`: the proxy or Safe wallet, even with `--signature-type eoa`.
+    Proxy,
+}
+
+impl FromStr for WalletAddress {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "self" => Ok(Self::Funder),
+            "eoa" => Ok(Self::Eoa),
+            "proxy" => Ok(Self::Proxy),
+            _ => Address::from_str(s)
+                .map(Self::Address)
+                .map_err(|_| format!("expected a 0x address, self, eoa or proxy, got `{s}`")),
+        }
+    }
+}
+
+impl WalletAddress {
+    pub fn resolve(
+        self,
+        private_key: Option<&str>,
+        signature_type: Option<SignatureType>,
+    ) -> Result<Address> {
+        let eoa = || -> Result<Address> {
+            let (address, _) = config::resolve_address(private_key)?;
+            Ok(address.ok_or_else(|| {
+                CliError::auth(format!("{}, or pass an address", config::NO_WALLET_MSG))
+            })?)
+        };
+        match self {
+            Self::Address(address) => Ok(address),
+            Self::Funder => {
+                eoa()?;
+                config::resolve_funder(private_key, signature_type)
+            }
+            Self::Eoa => eoa(),
+            Self::Proxy => {
+                let signature_type = match config::resolve_signature_type(signature_type)? {
+                    SignatureType::GnosisSafe => SignatureType::GnosisSafe,
+                    _ => SignatureType::Proxy,
+                };
+                config::derived_wallet(eoa()?, signature_type).ok_or_else(|| {
+                    CliError::invalid_argument(format!(
+                        "No {signature_type} wallet on {}",
+                        config::chain().name()
+                    ))
+                    .into()
+                })
+            }
+        }
+    }
+}
+
 #[derive(Clone, Debug, clap::ValueEnum)]
 pub enum TimePeriod {
     Day,
@@ -191,7 +281,15 @@ impl From<OrderBy> for polymarket_client_sdk::data::types::LeaderboardOrderBy {
     }
 }
 
-pub async fn execute(client: &data::Client, args: DataArgs, output: OutputFormat) -> Result<()> {
+pub async fn execute(
+    client: &data::Client,
+    args: DataArgs,
+    output: OutputFormat,
+    private_key: Option<&str>,
+    signature_type: Option<SignatureType>,
+) -> Result<()> {
+    let resolve = |address: AddressArgs| address.resolve(private_key, signature_type);
+
     match args.command {
         DataCommand::Positions {
             address,
@@ -199,8 +297,8 @@ pub async fn execute(client: &data::Client, args: DataArgs, output: OutputFormat
             offset,
         } => {
             let request = PositionsRequest::builder()
-                .user(address)
-                .limit(limit)?
+                .user(resolve(address)?)
+                .limit(limit.unwrap_or_else(config::page_size))?
                 .maybe_offset(offset)?
                 .build();
 
@@ -214,8 +312,8 @@ pub async fn execute(client: &data::Client, args: DataArgs, output: OutputFormat
             offset,
         } => {
             let request = ClosedPositionsRequest::builder()
-                .user(address)
-                .limit(limit)?
+                .user(resolve(address)?)
+                .limit(limit.unwrap_or_else(config::page_size))?
                 .maybe_offset(offset)?
                 .build();
 
@@ -224,14 +322,14 @@ pub async fn execute(client: &data::Client, args: DataArgs, output: OutputFormat
         }
 
         DataCommand::Value { address } => {
-            let request = ValueRequest::builder().user(address).build();
+            let request = ValueRequest::builder().user(resolve(address)?).build();
 
             let values = client.value(&request).await?;
             print_value(&values, &output)?;
         }
 
         DataCommand::Traded { address } => {
-            let request = TradedRequest::builder().user(address).build();
+            let request = TradedRequest::builder().user(resolve(address)?).build();
 
             let traded = client.traded(&request).await?;
             print_traded(&traded, &output)?;
@@ -243,8 +341,8 @@ pub async fn execute(client: &data::Client, args: DataArgs, output: OutputFormat
             offset,
         } => {
             let request = TradesRequest::builder()
-                .user(address)
-                .limit(limit)?
+                .user(resolve(address)?)
+                .limit(limit.unwrap_or_else(config::page_size))?
                 .maybe_offset(offset)?
                 .build();
 
@@ -258,8 +356,8 @@ pub async fn execute(client: &data::Client, args: DataArgs, output: OutputFormat
             offset,
         } => {
             let request = ActivityRequest::builder()
-                .user(address)
-                .limit(limit)?
+                .user(resolve(address)?)
+                .limit(limit.unwrap_or_else(config::page_size))?
                 .maybe_offset(offset)?
                 .build();
 
@@ -299,7 +397,7 @@ pub async fn execute(client: &data::Client, args: DataArgs, output: OutputFormat
             let request = TraderLeaderboardRequest::builder()
                 .maybe_time_period(period.map(Into::into))
                 .maybe_order_by(order_by.map(Into::into))
-                .limit(limit)?
+                .limit(limit.unwrap_or_else(config::page_size))?
                 .maybe_offset(offset)?
                 .build();
 
@@ -314,7 +412,7 @@ pub async fn execute(client: &data::Client, args: DataArgs, output: OutputFormat
         } => {
             let request = BuilderLeaderboardRequest::builder()
                 .maybe_time_period(period.map(Into::into))
-                .limit(limit)?
+                .limit(limit.unwrap_or_else(config::page_size))?
                 .maybe_offset(offset)?
                 .build();
 
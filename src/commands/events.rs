@@ -6,6 +6,7 @@ use polymarket_client_sdk::gamma::{
 };
 
 use super::is_numeric_id;
+use crate::config;
 use crate::output::OutputFormat;
 use crate::output::events::{print_event, print_events};
 use crate::output::tags::print_tags;
@@ -28,9 +29,9 @@ pub enum EventsCommand {
         #[arg(long)]
         closed: Option<bool>,
 
-        /// Max results
-        #[arg(long, default_value = "25")]
-        limit: i32,
+        /// Max results [default: the `limit` setting, or 25]
+        #[arg(long)]
+        limit: Option<i32>,
 
         /// Pagination offset
         #[arg(long)]
@@ -76,7 +77,7 @@ pub async fn execute(client: &gamma::Client, args: EventsArgs, output: OutputFor
             let resolved_closed = closed.or_else(|| active.map(|a| !a));
 
             let request = EventsRequest::builder()
-                .limit(limit)
+                .limit(limit.unwrap_or_else(config::page_size))
                 .maybe_closed(resolved_closed)
                 .maybe_offset(offset)
                 .ascending(ascending)
@@ -12,6 +12,7 @@ use polymarket_client_sdk::gamma::{
 };
 
 use super::is_numeric_id;
+use crate::config;
 use crate::output::OutputFormat;
 use crate::output::markets::{print_market, print_markets};
 use crate::output::tags::print_tags;
@@ -34,9 +35,9 @@ pub enum MarketsCommand {
         #[arg(long)]
         closed: Option<bool>,
 
-        /// Max results
-        #[arg(long, default_value = "25")]
-        limit: i32,
+        /// Max results [default: the `limit` setting, or 25]
+        #[arg(long)]
+        limit: Option<i32>,
 
         /// Pagination offset
         #[arg(long)]
@@ -91,7 +92,7 @@ pub async fn execute(
             let resolved_closed = closed.or_else(|| active.map(|a| !a));
 
             let request = MarketsRequest::builder()
-                .limit(limit)
+                .limit(limit.unwrap_or_else(config::page_size))
                 .maybe_closed(resolved_closed)
                 .maybe_offset(offset)
                 .maybe_order(order)
@@ -1,19 +1,21 @@
-pub(crate) const USDC_ADDRESS_STR: &str = "0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174";
 pub(crate) const USDC_DECIMALS: u32 = 6;
 
 pub(crate) mod approve;
 pub(crate) mod bridge;
 pub(crate) mod clob;
 pub(crate) mod comments;
+pub(crate) mod config;
 pub(crate) mod ctf;
 pub(crate) mod data;
 pub(crate) mod events;
 pub(crate) mod markets;
 pub(crate) mod profiles;
+pub(crate) mod schema;
 pub(crate) mod series;
 pub(crate) mod setup;
 pub(crate) mod sports;
 pub(crate) mod tags;
+pub(crate) mod tx;
 pub(crate) mod upgrade;
 pub(crate) mod wallet;
 
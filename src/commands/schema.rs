@@ -0,0 +1,44 @@
+use anyhow::Result;
+use clap::Args;
+
+use crate::error::CliError;
+use crate::output::schema::{self, SCHEMA_VERSION};
+use crate::output::{OutputFormat, print_json};
+
+#[derive(Args)]
+pub struct SchemaArgs {
+    /// Command to describe, e.g. `markets list` (omit to list all commands)
+    pub command: Vec<String>,
+}
+
+pub fn execute(args: &SchemaArgs, output: OutputFormat, envelope: bool) -> Result<()> {
+    if args.command.is_empty() {
+        let commands: Vec<_> = schema::registry().into_iter().map(|c| c.command).collect();
+        match output {
+            OutputFormat::Table => {
+                println!("Schema version {SCHEMA_VERSION}. Commands with JSON output:");
+                for command in commands {
+                    println!("  {command}");
+                }
+            }
+            OutputFormat::Json => print_json(&serde_json::json!({
+                "schema_version": SCHEMA_VERSION,
+                "commands": commands,
+            }))?,
+        }
+        return Ok(());
+    }
+
+    let entry = schema::find(&args.command).ok_or_else(|| {
+        CliError::invalid_argument(format!(
+            "No schema for `{}`. Run `polymarket schema` to list commands.",
+            args.command.join(" ")
+        ))
+    })?;
+    // The schema itself is JSON whatever the output format, and is never enveloped.
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&entry.document(envelope))?
+    );
+    Ok(())
+}
@@ -5,6 +5,7 @@ use polymarket_client_sdk::gamma::{
     types::request::{SeriesByIdRequest, SeriesListRequest},
 };
 
+use crate::config;
 use crate::output::OutputFormat;
 use crate::output::series::{print_series, print_series_item};
 
@@ -18,9 +19,9 @@ pub struct SeriesArgs {
 pub enum SeriesCommand {
     /// List series
     List {
-        /// Max results
-        #[arg(long, default_value = "25")]
-        limit: i32,
+        /// Max results [default: the `limit` setting, or 25]
+        #[arg(long)]
+        limit: Option<i32>,
 
         /// Pagination offset
         #[arg(long)]
@@ -56,7 +57,7 @@ pub async fn execute(client: &gamma::Client, args: SeriesArgs, output: OutputFor
             closed,
         } => {
             let request = SeriesListRequest::builder()
-                .limit(limit)
+                .limit(limit.unwrap_or_else(config::page_size))
                 .maybe_offset(offset)
                 .maybe_order(order)
                 .ascending(ascending)
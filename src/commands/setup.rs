@@ -3,10 +3,10 @@ use std::str::FromStr;
 
 use anyhow::{Context, Result};
 use polymarket_client_sdk::auth::{LocalSigner, Signer as _};
+use polymarket_client_sdk::derive_proxy_wallet;
 use polymarket_client_sdk::types::Address;
-use polymarket_client_sdk::{POLYGON, derive_proxy_wallet};
 
-use crate::config;
+use crate::{config, keystore};
 
 fn print_banner() {
     // #2E5CFF → RGB(46, 92, 255)
@@ -83,11 +83,7 @@ pub fn execute() -> Result<()> {
     step_header(1, total, "Wallet");
 
     let address = if config::config_exists() {
-        let (key, source) = config::resolve_key(None)?;
-        if let Some(k) = &key
-            && let Ok(signer) = LocalSigner::from_str(k)
-        {
-            let addr = signer.address();
+        if let Ok((Some(addr), source)) = config::resolve_address(None) {
             println!("  ✓ Wallet already configured ({})", source.label());
             println!("    Address: {addr}");
             println!();
@@ -111,21 +107,21 @@ pub fn execute() -> Result<()> {
 fn setup_wallet() -> Result<Address> {
     let has_key = prompt_yn("  Do you have an existing private key?", false)?;
 
-    let (address, key_hex) = if has_key {
+    let signer = if has_key {
         let key = prompt("  Enter private key: ")?;
-        let signer = LocalSigner::from_str(&key)
+        LocalSigner::from_str(&key)
             .context("Invalid private key")?
-            .with_chain_id(Some(POLYGON));
-        let hex = format!("{:#x}", signer.to_bytes());
-        (signer.address(), hex)
+            .with_chain_id(Some(config::chain().id()))
     } else {
-        let signer = LocalSigner::random().with_chain_id(Some(POLYGON));
-        let address = signer.address();
-        let hex = format!("{:#x}", signer.to_bytes());
-        (address, hex)
+        LocalSigner::random().with_chain_id(Some(config::chain().id()))
     };
+    let address = signer.address();
 
-    config::save_wallet(&key_hex, POLYGON, config::DEFAULT_SIGNATURE_TYPE)?;
+    println!("  The key is stored encrypted. Choose a password to unlock it.");
+    let name = config::profile_name()?;
+    config::validate_profile_name(&name)?;
+    let profile = keystore::key_profile(&signer, config::SignatureType::default(), false, None)?;
+    config::save_profile(&name, profile)?;
 
     if has_key {
         println!("  ✓ Wallet imported");
@@ -137,7 +133,7 @@ fn setup_wallet() -> Result<Address> {
 
     if !has_key {
         println!();
-        println!("  ⚠ Back up your private key from the config file.");
+        println!("  ⚠ Back up your keystore file and its password.");
         println!("    If lost, your funds cannot be recovered.");
     }
 
@@ -149,7 +145,7 @@ fn finish_setup(address: Address) -> Result<()> {
 
     step_header(2, total, "Proxy Wallet");
 
-    let proxy = derive_proxy_wallet(address, POLYGON);
+    let proxy = derive_proxy_wallet(address, config::chain().id());
     match proxy {
         Some(proxy) => {
             println!("  ✓ Proxy wallet derived");
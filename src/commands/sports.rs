@@ -2,6 +2,7 @@ use anyhow::Result;
 use clap::{Args, Subcommand};
 use polymarket_client_sdk::gamma::{self, types::request::TeamsRequest};
 
+use crate::config;
 use crate::output::OutputFormat;
 use crate::output::sports::{print_sport_types, print_sports, print_teams};
 
@@ -21,9 +22,9 @@ pub enum SportsCommand {
 
     /// List sports teams
     Teams {
-        /// Max results
-        #[arg(long, default_value = "25")]
-        limit: i32,
+        /// Max results [default: the `limit` setting, or 25]
+        #[arg(long)]
+        limit: Option<i32>,
 
         /// Pagination offset
         #[arg(long)]
@@ -63,7 +64,7 @@ pub async fn execute(client: &gamma::Client, args: SportsArgs, output: OutputFor
             league,
         } => {
             let request = TeamsRequest::builder()
-                .limit(limit)
+                .limit(limit.unwrap_or_else(config::page_size))
                 .maybe_offset(offset)
                 .maybe_order(order)
                 .ascending(ascending)
@@ -9,6 +9,7 @@ use polymarket_client_sdk::gamma::{
 };
 
 use super::is_numeric_id;
+use crate::config;
 use crate::output::OutputFormat;
 use crate::output::tags::{print_related_tags, print_tag, print_tags};
 
@@ -22,9 +23,9 @@ pub struct TagsArgs {
 pub enum TagsCommand {
     /// List tags
     List {
-        /// Max results
-        #[arg(long, default_value = "25")]
-        limit: i32,
+        /// Max results [default: the `limit` setting, or 25]
+        #[arg(long)]
+        limit: Option<i32>,
 
         /// Pagination offset
         #[arg(long)]
@@ -70,7 +71,7 @@ pub async fn execute(client: &gamma::Client, args: TagsArgs, output: OutputForma
             ascending,
         } => {
             let request = TagsRequest::builder()
-                .limit(limit)
+                .limit(limit.unwrap_or_else(config::page_size))
                 .maybe_offset(offset)
                 .ascending(ascending)
                 .build();
@@ -0,0 +1,216 @@
+use alloy::consensus::Transaction;
+use alloy::network::{ReceiptResponse, TransactionResponse};
+use alloy::primitives::B256;
+use alloy::providers::Provider;
+use anyhow::{Context, Result};
+use clap::{Args, Subcommand};
+
+use crate::error::{CliError, ErrorCode};
+use crate::journal::{self, Entry, Status};
+use crate::output::OutputFormat;
+use crate::output::tx::{
+    print_entries, print_replacement, print_replacement_simulation, print_status,
+};
+use crate::tx::Replacement;
+use crate::{auth, config, tx};
+
+#[derive(Args)]
+pub struct TxArgs {
+    #[command(subcommand)]
+    pub command: TxCommand,
+}
+
+#[derive(Subcommand)]
+pub enum TxCommand {
+    /// List the transactions `approve`, `ctf` and `wallet send` sent, newest first
+    List {
+        /// Only transactions that haven't been mined
+        #[arg(long)]
+        pending: bool,
+    },
+    /// Check whether a transaction is pending, confirmed, reverted or replaced
+    Status {
+        /// Transaction hash
+        hash: B256,
+    },
+    /// Resend a pending transaction with higher fees at the same nonce
+    Speedup {
+        /// Transaction hash
+        hash: B256,
+        #[command(flatten)]
+        tx: tx::TxArgs,
+    },
+    /// Replace a pending transaction with an empty transfer to yourself
+    Cancel {
+        /// Transaction hash
+        hash: B256,
+        #[command(flatten)]
+        tx: tx::TxArgs,
+    },
+}
+
+/// The transaction `hash` from the journal, or else from the node.
+async fn lookup(provider: &impl Provider, hash: B256) -> Result<Entry> {
+    let chain = config::chain();
+    if let Some(entry) = journal::find(hash)?
+        && entry.chain_id == chain.id()
+    {
+        return Ok(entry);
+    }
+    let tx = provider
+        .get_transaction_by_hash(hash)
+        .await
+        .context("Failed to look up the transaction")?
+        .ok_or_else(|| {
+            CliError::new(
+                ErrorCode::NotFound,
+                format!(
+                    "Transaction {hash} is neither in the journal nor known to the {} node",
+                    chain.name()
+                ),
+            )
+        })?;
+    let max_fee_per_gas = Transaction::max_fee_per_gas(&tx);
+    Ok(Entry {
+        hash,
+        chain_id: chain.id(),
+        from: tx.from(),
+        nonce: tx.nonce(),
+        to: tx.to().unwrap_or_default(),
+        value: tx.value(),
+        input: tx.input().clone(),
+        gas_limit: tx.gas_limit(),
+        max_fee_per_gas,
+        max_priority_fee_per_gas: tx.max_priority_fee_per_gas().unwrap_or(max_fee_per_gas),
+        sent_at: None,
+        status: Status::Pending,
+        replaces: None,
+    })
+}
+
+/// Where `entry` stands on the chain, with the block it was mined in.
+async fn chain_status(provider: &impl Provider, entry: &Entry) -> Result<(Status, Option<u64>)> {
+    let receipt = provider
+        .get_transaction_receipt(entry.hash)
+        .await
+        .context("Failed to get transaction receipt")?;
+    if let Some(receipt) = receipt {
+        let status = if receipt.status() {
+            Status::Confirmed
+        } else {
+            Status::Reverted
+        };
+        return Ok((status, receipt.block_number()));
+    }
+    // Not mined itself, but its nonce is used up: something else took it.
+    let mined = provider
+        .get_transaction_count(entry.from)
+        .latest()
+        .await
+        .context("Failed to get the nonce")?;
+    let status = if mined > entry.nonce {
+        Status::Replaced
+    } else {
+        Status::Pending
+    };
+    Ok((status, None))
+}
+
+async fn list(pending: bool, output: OutputFormat) -> Result<()> {
+    let chain_id = config::chain().id();
+    let mut entries = journal::load()?;
+    let unmined: Vec<Entry> = entries
+        .iter()
+        .filter(|e| e.chain_id == chain_id && e.status == Status::Pending)
+        .cloned()
+        .collect();
+    if !unmined.is_empty() {
+        let provider = auth::create_readonly_provider().await?;
+        let mut changed = false;
+        for entry in &unmined {
+            // One unreachable lookup shouldn't hide the rest of the journal:
+            // keep the stored status and move on.
+            match chain_status(&provider, entry).await {
+                Ok((status, _)) => {
+                    changed |= journal::update_status(&mut entries, entry.hash, status);
+                }
+                Err(e) => eprintln!("Warning: couldn't refresh {}: {e:#}", entry.hash),
+            }
+        }
+        if changed {
+            journal::save(&entries)?;
+        }
+    }
+
+    let entries: Vec<Entry> = entries
+        .into_iter()
+        .rev()
+        .filter(|e| e.chain_id == chain_id && (!pending || e.status == Status::Pending))
+        .collect();
+    print_entries(&entries, &output)
+}
+
+async fn status(hash: B256, output: OutputFormat) -> Result<()> {
+    let provider = auth::create_readonly_provider().await?;
+    let entry = lookup(&provider, hash).await?;
+    let (status, block_number) = chain_status(&provider, &entry).await?;
+    let mut entries = journal::load()?;
+    if journal::update_status(&mut entries, hash, status) {
+        journal::save(&entries)?;
+    }
+    print_status(&entry, status, block_number, &output)
+}
+
+async fn replace(
+    hash: B256,
+    replacement: Replacement,
+    private_key: Option<&str>,
+    output: OutputFormat,
+) -> Result<()> {
+    let (key, _) = config::resolve_address(private_key)?;
+    let key = key.ok_or_else(|| CliError::auth(config::NO_WALLET_MSG))?;
+    let reader = auth::create_readonly_provider().await?;
+    let original = lookup(&reader, hash).await?;
+    anyhow::ensure!(
+        original.from == key,
+        CliError::invalid_argument(format!(
+            "Transaction {hash} was sent by {}, not by your wallet {key}",
+            original.from
+        ))
+    );
+    let (status, _) = chain_status(&reader, &original).await?;
+    anyhow::ensure!(
+        status == Status::Pending,
+        CliError::invalid_argument(format!(
+            "Transaction {hash} is already {status}; there's nothing to replace"
+        ))
+    );
+
+    if tx::simulating() {
+        let simulated = tx::simulate_replacement(&reader, &original, replacement).await?;
+        print_replacement_simulation(replacement, hash, &simulated, &output)?;
+        if let Some(reason) = simulated.simulation.revert_reason {
+            anyhow::bail!("The replacement would revert: {reason}");
+        }
+        return Ok(());
+    }
+
+    let provider = auth::create_provider(private_key).await?;
+    let sent = tx::replace(&provider, &original, replacement).await?;
+    print_replacement(replacement, hash, &sent, &output)
+}
+
+pub async fn execute(args: TxArgs, output: OutputFormat, private_key: Option<&str>) -> Result<()> {
+    match args.command {
+        TxCommand::List { pending } => list(pending, output).await,
+        TxCommand::Status { hash } => status(hash, output).await,
+        TxCommand::Speedup { hash, tx: options } => {
+            tx::set_options(options)?;
+            replace(hash, Replacement::SpeedUp, private_key, output).await
+        }
+        TxCommand::Cancel { hash, tx: options } => {
+            tx::set_options(options)?;
+            replace(hash, Replacement::Cancel, private_key, output).await
+        }
+    }
+}
@@ -1,13 +1,60 @@
+use std::io::{IsTerminal as _, Read as _};
 use std::str::FromStr;
 
+use alloy::primitives::U256;
+use alloy::primitives::utils::format_units;
+use alloy::providers::Provider;
+use alloy::signers::local::coins_bip39::{English, Mnemonic};
+use alloy::signers::local::{MnemonicBuilder, PrivateKeySigner};
+use alloy::sol;
 use anyhow::{Context, Result, bail};
 use clap::{Args, Subcommand};
 use polymarket_client_sdk::auth::LocalSigner;
 use polymarket_client_sdk::auth::Signer as _;
-use polymarket_client_sdk::{POLYGON, derive_proxy_wallet};
+use polymarket_client_sdk::clob::types::AssetType;
+use polymarket_client_sdk::clob::types::request::BalanceAllowanceRequest;
+use polymarket_client_sdk::types::{Address, Decimal};
+use polymarket_client_sdk::{data, derive_proxy_wallet};
+use tabled::settings::Style;
+use tabled::{Table, Tabled};
 
-use crate::config;
-use crate::output::OutputFormat;
+use super::ctf::{execute_call, parse_usdc_amount};
+use crate::auth;
+use crate::commands::USDC_DECIMALS;
+use crate::config::{self, Profile, SignatureType};
+use crate::error::CliError;
+use crate::keystore;
+use crate::output::ctf::BalanceChange;
+use crate::output::{DASH, OutputFormat, print_json, truncate};
+use crate::tx::{self, Call, Sender, TxArgs};
+
+sol! {
+    #[sol(rpc)]
+    interface IERC20 {
+        function balanceOf(address account) external view returns (uint256);
+        function transfer(address to, uint256 value) external returns (bool);
+    }
+
+    #[sol(rpc)]
+    interface IERC1155 {
+        function balanceOf(address account, uint256 id) external view returns (uint256);
+    }
+}
+
+/// What `wallet send` sends.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum SendToken {
+    /// Native USDC
+    Usdc,
+    /// Bridged USDC.e, the collateral Polymarket trades with
+    #[value(name = "usdc.e")]
+    UsdcE,
+    /// POL, the gas token
+    Pol,
+}
+
+/// BIP-44 path of Ethereum accounts, followed by the account index.
+const DERIVATION_PATH_PREFIX: &str = "m/44'/60'/0'/0/";
 
 #[derive(Args)]
 pub struct WalletArgs {
@@ -15,6 +62,17 @@ pub struct WalletArgs {
     pub command: WalletCommand,
 }
 
+/// How a new key is stored.
+#[derive(Args)]
+pub struct KeyStorageArgs {
+    /// Store the key unencrypted in config.json instead of a keystore
+    #[arg(long, conflicts_with = "password_command")]
+    plaintext: bool,
+    /// Command printing the keystore password, e.g. `pass show polymarket`
+    #[arg(long)]
+    password_command: Option<String>,
+}
+
 #[derive(Subcommand)]
 pub enum WalletCommand {
     /// Generate a new random wallet and save to config
@@ -23,24 +81,136 @@ pub enum WalletCommand {
         #[arg(long)]
         force: bool,
         /// Signature type: eoa, proxy (default), or gnosis-safe
-        #[arg(long, default_value = "proxy")]
-        signature_type: String,
+        #[arg(long, default_value_t)]
+        signature_type: SignatureType,
+        /// Profile to save the wallet as (default: the profile in use)
+        #[arg(long)]
+        name: Option<String>,
+        /// Generate a 24-word recovery phrase and use its first account
+        #[arg(long)]
+        mnemonic: bool,
+        #[command(flatten)]
+        storage: KeyStorageArgs,
     },
-    /// Import an existing private key
+    /// Import an existing private key, or an address to watch
     Import {
         /// Private key (hex, with or without 0x prefix)
-        key: String,
+        #[arg(required_unless_present_any = ["address", "mnemonic"])]
+        key: Option<String>,
+        /// Derive the key from a BIP-39 recovery phrase instead, read from
+        /// stdin or prompted for
+        #[arg(long, conflicts_with_all = ["key", "address"])]
+        mnemonic: bool,
+        /// Derivation path of the key (default: m/44'/60'/0'/0/<index>)
+        #[arg(long, requires = "mnemonic", conflicts_with_all = ["index", "key", "address"])]
+        derivation_path: Option<String>,
+        /// Account index on the default derivation path
+        #[arg(long, requires = "mnemonic", conflicts_with_all = ["key", "address"])]
+        index: Option<u32>,
+        /// Add a watch-only profile for this address instead of a key
+        #[arg(long, conflicts_with = "key")]
+        address: Option<Address>,
+        /// Sign for --address through this remote signer's JSON-RPC endpoint
+        #[arg(long, requires = "address", conflicts_with_all = ["key", "mnemonic"])]
+        signer_url: Option<String>,
         /// Overwrite existing wallet
         #[arg(long)]
         force: bool,
         /// Signature type: eoa, proxy (default), or gnosis-safe
-        #[arg(long, default_value = "proxy")]
-        signature_type: String,
+        #[arg(long, default_value_t)]
+        signature_type: SignatureType,
+        /// Profile to save the wallet as (default: the profile in use)
+        #[arg(long)]
+        name: Option<String>,
+        #[command(flatten)]
+        storage: KeyStorageArgs,
+    },
+    /// Move the plaintext key of the profile in use into an encrypted keystore
+    Encrypt {
+        /// Command printing the keystore password, e.g. `pass show polymarket`
+        #[arg(long)]
+        password_command: Option<String>,
+    },
+    /// Print the private key of the profile in use, decrypting it if needed
+    Export,
+    /// List the accounts of a recovery phrase, read from stdin or prompted
+    /// for, with their proxy wallets
+    Derive {
+        /// Number of accounts to list
+        #[arg(long, default_value_t = 10)]
+        count: u32,
+        /// First account index
+        #[arg(long, default_value_t = 0)]
+        start: u32,
+    },
+    /// Set the address holding the funds of the profile in use, for a proxy
+    /// or Safe not derived from its key
+    SetFunder {
+        /// Proxy or Safe wallet address
+        #[arg(required_unless_present = "clear")]
+        address: Option<Address>,
+        /// Remove the funder, deriving it from the signature type again
+        #[arg(long, conflicts_with = "address")]
+        clear: bool,
+        /// Save without checking on chain that the key controls the funder
+        #[arg(long)]
+        no_verify: bool,
     },
     /// Show the address of the configured wallet
     Address,
     /// Show wallet info (address, config path, key source)
     Show,
+    /// Check on chain which wallets of the key exist and hold funds, and
+    /// recommend a signature type
+    Detect {
+        /// Save the recommended signature type to the profile in use
+        #[arg(long)]
+        save: bool,
+    },
+    /// Read balances on chain (POL, USDC.e, native USDC and outcome tokens)
+    /// next to the balances the CLOB reports
+    Balances {
+        /// Outcome token IDs, comma-separated (default: the tokens of your positions)
+        #[arg(long, value_delimiter = ',')]
+        token: Vec<U256>,
+    },
+    /// Send USDC.e, native USDC or POL to another address (sends an
+    /// on-chain transaction)
+    Send {
+        /// Recipient address
+        #[arg(long)]
+        to: Address,
+        /// Amount to send, e.g. 25
+        #[arg(long)]
+        amount: String,
+        /// Token to send
+        #[arg(long, value_enum, default_value_t = SendToken::UsdcE)]
+        token: SendToken,
+        #[command(flatten)]
+        tx: TxArgs,
+    },
+    /// List wallet profiles
+    List,
+    /// Make a profile the default for future commands
+    Use {
+        /// Profile name
+        name: String,
+    },
+    /// Rename a profile
+    Rename {
+        /// Current profile name
+        from: String,
+        /// New profile name
+        to: String,
+    },
+    /// Remove a profile and its key
+    Remove {
+        /// Profile name
+        name: String,
+        /// Skip confirmation prompt
+        #[arg(long)]
+        force: bool,
+    },
     /// Delete all config and keys (fresh install)
     Reset {
         /// Skip confirmation prompt
@@ -49,124 +219,463 @@ pub enum WalletCommand {
     },
 }
 
-pub fn execute(
+pub async fn execute(
     args: WalletArgs,
     output: OutputFormat,
     private_key_flag: Option<&str>,
+    signature_type_flag: Option<SignatureType>,
+    data: impl FnOnce() -> Result<data::Client>,
 ) -> Result<()> {
     match args.command {
         WalletCommand::Create {
             force,
             signature_type,
-        } => cmd_create(output, force, &signature_type),
+            name,
+            mnemonic,
+            storage,
+        } => cmd_create(output, force, signature_type, name, mnemonic, storage),
+        WalletCommand::Import {
+            mnemonic: true,
+            derivation_path,
+            index,
+            force,
+            signature_type,
+            name,
+            storage,
+            ..
+        } => {
+            let path = derivation_path.unwrap_or_else(|| derivation_path_at(index.unwrap_or(0)));
+            let signer = mnemonic_signer(&read_mnemonic()?, &path)?;
+            let key = format!("{:#x}", signer.to_bytes());
+            let derived = Derived { path, phrase: None };
+            cmd_import(
+                &key,
+                Some(derived),
+                output,
+                force,
+                signature_type,
+                name,
+                storage,
+            )
+        }
         WalletCommand::Import {
             key,
+            address,
+            signer_url,
             force,
             signature_type,
-        } => cmd_import(&key, output, force, &signature_type),
+            name,
+            storage,
+            ..
+        } => match (key, address) {
+            (Some(key), _) => cmd_import(&key, None, output, force, signature_type, name, storage),
+            (None, Some(address)) => {
+                cmd_watch(address, signer_url, output, force, signature_type, name)
+            }
+            (None, None) => unreachable!("clap requires a key or --address"),
+        },
+        WalletCommand::Encrypt { password_command } => cmd_encrypt(output, password_command),
+        WalletCommand::Export => cmd_export(output, private_key_flag),
+        WalletCommand::Derive { count, start } => {
+            cmd_derive(output, &read_mnemonic()?, start, count)
+        }
+        WalletCommand::SetFunder {
+            address, no_verify, ..
+        } => {
+            cmd_set_funder(
+                output,
+                private_key_flag,
+                signature_type_flag,
+                address,
+                !no_verify,
+            )
+            .await
+        }
         WalletCommand::Address => cmd_address(output, private_key_flag),
-        WalletCommand::Show => cmd_show(output, private_key_flag),
+        WalletCommand::Show => cmd_show(output, private_key_flag, signature_type_flag),
+        WalletCommand::Detect { save } => cmd_detect(output, private_key_flag, save).await,
+        WalletCommand::Balances { token } => {
+            cmd_balances(output, private_key_flag, signature_type_flag, data, token).await
+        }
+        WalletCommand::Send {
+            to,
+            amount,
+            token,
+            tx: options,
+        } => {
+            tx::set_options(options)?;
+            cmd_send(
+                output,
+                private_key_flag,
+                signature_type_flag,
+                to,
+                &amount,
+                token,
+            )
+            .await
+        }
+        WalletCommand::List => cmd_list(output),
+        WalletCommand::Use { name } => cmd_use(output, &name),
+        WalletCommand::Rename { from, to } => cmd_rename(output, &from, &to),
+        WalletCommand::Remove { name, force } => cmd_remove(output, &name, force),
         WalletCommand::Reset { force } => cmd_reset(output, force),
     }
 }
 
-fn guard_overwrite(force: bool) -> Result<()> {
-    if !force && config::config_exists() {
+/// The profile to save to: `--name`, or the profile in use.
+fn target_profile(name: Option<String>, force: bool) -> Result<String> {
+    let name = match name {
+        Some(name) => name,
+        None => config::profile_name()?,
+    };
+    config::validate_profile_name(&name)?;
+    let exists = config::load_config()?.is_some_and(|c| c.profiles.contains_key(&name));
+    if exists && !force {
         bail!(
-            "A wallet already exists at {}. Use --force to overwrite.",
+            "A wallet already exists in profile `{name}` at {}. Use --force to overwrite, \
+             or --name to add another profile.",
             config::config_path()?.display()
         );
     }
-    Ok(())
+    Ok(name)
 }
 
-fn cmd_create(output: OutputFormat, force: bool, signature_type: &str) -> Result<()> {
-    guard_overwrite(force)?;
+/// Where a key derived from a recovery phrase came from.
+struct Derived {
+    path: String,
+    /// Set only when the phrase was just generated, so it's shown once.
+    phrase: Option<String>,
+}
+
+fn derivation_path_at(index: u32) -> String {
+    format!("{DERIVATION_PATH_PREFIX}{index}")
+}
+
+/// The recovery phrase, piped on stdin or typed at a hidden prompt, so it
+/// stays out of shell history and the process list.
+fn read_mnemonic() -> Result<String> {
+    let phrase = if std::io::stdin().is_terminal() {
+        rpassword::prompt_password("Recovery phrase: ")
+            .context(CliError::invalid_argument("No recovery phrase"))?
+    } else {
+        let mut phrase = String::new();
+        std::io::stdin()
+            .read_to_string(&mut phrase)
+            .context("Failed to read the recovery phrase from stdin")?;
+        phrase
+    };
+    if phrase.trim().is_empty() {
+        bail!(CliError::invalid_argument("No recovery phrase"));
+    }
+    Ok(phrase)
+}
 
-    let signer = LocalSigner::random().with_chain_id(Some(POLYGON));
-    let address = signer.address();
-    let key_hex = format!("{:#x}", signer.to_bytes());
+fn mnemonic_signer(phrase: &str, path: &str) -> Result<PrivateKeySigner> {
+    let phrase = phrase.split_whitespace().collect::<Vec<_>>().join(" ");
+    MnemonicBuilder::<English>::default()
+        .phrase(phrase)
+        .derivation_path(path)
+        .context(CliError::invalid_argument(format!(
+            "Invalid derivation path: {path}"
+        )))?
+        .build()
+        .context(CliError::invalid_argument("Invalid recovery phrase"))
+}
 
-    config::save_wallet(&key_hex, POLYGON, signature_type)?;
+fn print_saved(
+    output: OutputFormat,
+    verb: &str,
+    profile: &str,
+    address: Address,
+    signature_type: SignatureType,
+    derived: Option<&Derived>,
+) -> Result<()> {
     let config_path = config::config_path()?;
-    let proxy_addr = derive_proxy_wallet(address, POLYGON);
+    let proxy_addr = derive_proxy_wallet(address, config::chain().id());
 
     match output {
         OutputFormat::Json => {
-            println!(
-                "{}",
-                serde_json::json!({
-                    "address": address.to_string(),
-                    "proxy_address": proxy_addr.map(|a| a.to_string()),
-                    "signature_type": signature_type,
-                    "config_path": config_path.display().to_string(),
-                })
-            );
+            let mut saved = serde_json::json!({
+                "profile": profile,
+                "address": address.to_string(),
+                "proxy_address": proxy_addr.map(|a| a.to_string()),
+                "signature_type": signature_type,
+                "config_path": config_path.display().to_string(),
+            });
+            if let Some(derived) = derived {
+                saved["derivation_path"] = derived.path.clone().into();
+                if let Some(phrase) = &derived.phrase {
+                    saved["mnemonic"] = phrase.clone().into();
+                }
+            }
+            print_json(&saved)?;
         }
         OutputFormat::Table => {
-            println!("Wallet created successfully!");
+            println!("Wallet {verb} successfully!");
+            println!("Profile:        {profile}");
             println!("Address:        {address}");
             if let Some(proxy) = proxy_addr {
                 println!("Proxy wallet:   {proxy}");
             }
             println!("Signature type: {signature_type}");
+            if let Some(derived) = derived {
+                println!("Derivation:     {}", derived.path);
+            }
             println!("Config:         {}", config_path.display());
+        }
+    }
+    Ok(())
+}
+
+fn cmd_create(
+    output: OutputFormat,
+    force: bool,
+    signature_type: SignatureType,
+    name: Option<String>,
+    mnemonic: bool,
+    storage: KeyStorageArgs,
+) -> Result<()> {
+    let profile = target_profile(name, force)?;
+
+    let (signer, derived) = if mnemonic {
+        let phrase = Mnemonic::<English>::new_with_count(&mut rand::thread_rng(), 24)
+            .context("Failed to generate recovery phrase")?
+            .to_phrase();
+        let path = derivation_path_at(0);
+        let signer = mnemonic_signer(&phrase, &path)?;
+        let derived = Derived {
+            path,
+            phrase: Some(phrase),
+        };
+        (signer, Some(derived))
+    } else {
+        (LocalSigner::random(), None)
+    };
+    let signer = signer.with_chain_id(Some(config::chain().id()));
+    let saved = keystore::key_profile(
+        &signer,
+        signature_type,
+        storage.plaintext,
+        storage.password_command,
+    )?;
+    config::save_profile(&profile, saved)?;
+    print_saved(
+        output,
+        "created",
+        &profile,
+        signer.address(),
+        signature_type,
+        derived.as_ref(),
+    )?;
+    if matches!(output, OutputFormat::Table) {
+        println!();
+        if let Some(phrase) = derived.and_then(|d| d.phrase) {
+            println!("Recovery phrase (shown only this once):");
+            println!("  {phrase}");
             println!();
+            println!("IMPORTANT: Write down the recovery phrase and keep it offline.");
+        } else if storage.plaintext {
             println!("IMPORTANT: Back up your private key from the config file.");
-            println!("           If lost, your funds cannot be recovered.");
+        } else {
+            println!("IMPORTANT: Back up your keystore file and its password.");
         }
+        println!("           If lost, your funds cannot be recovered.");
     }
     Ok(())
 }
 
-fn cmd_import(key: &str, output: OutputFormat, force: bool, signature_type: &str) -> Result<()> {
-    guard_overwrite(force)?;
+fn cmd_import(
+    key: &str,
+    derived: Option<Derived>,
+    output: OutputFormat,
+    force: bool,
+    signature_type: SignatureType,
+    name: Option<String>,
+    storage: KeyStorageArgs,
+) -> Result<()> {
+    let profile = target_profile(name, force)?;
 
     let signer = LocalSigner::from_str(key)
-        .context("Invalid private key")?
-        .with_chain_id(Some(POLYGON));
-    let address = signer.address();
-    let key_hex = format!("{:#x}", signer.to_bytes());
+        .context(CliError::invalid_argument("Invalid private key"))?
+        .with_chain_id(Some(config::chain().id()));
+    let saved = keystore::key_profile(
+        &signer,
+        signature_type,
+        storage.plaintext,
+        storage.password_command,
+    )?;
+    config::save_profile(&profile, saved)?;
+    print_saved(
+        output,
+        "imported",
+        &profile,
+        signer.address(),
+        signature_type,
+        derived.as_ref(),
+    )
+}
 
-    config::save_wallet(&key_hex, POLYGON, signature_type)?;
-    let config_path = config::config_path()?;
-    let proxy_addr = derive_proxy_wallet(address, POLYGON);
+fn cmd_encrypt(output: OutputFormat, password_command: Option<String>) -> Result<()> {
+    let name = config::profile_name()?;
+    let profile = config_with_profile(&name)?
+        .profiles
+        .remove(&name)
+        .expect("checked above");
+    let Some(key) = profile.private_key.as_deref() else {
+        return Err(CliError::invalid_argument(format!(
+            "Profile `{name}` has no plaintext key to encrypt"
+        ))
+        .into());
+    };
+    let signer =
+        LocalSigner::from_str(key).context(CliError::invalid_argument("Invalid private key"))?;
+    let encrypted =
+        keystore::key_profile(&signer, profile.signature_type, false, password_command)?;
+    let keystore_path = encrypted.keystore.clone().expect("just encrypted");
+    // Only the key moves; the rest of the profile stays as it was.
+    config::save_profile(
+        &name,
+        Profile {
+            chain_id: profile.chain_id,
+            funder: profile.funder,
+            display: profile.display,
+            settings: profile.settings,
+            ..encrypted
+        },
+    )?;
+
+    match output {
+        OutputFormat::Table => {
+            println!("Encrypted the key of profile `{name}`.");
+            println!("Keystore: {}", keystore_path.display());
+        }
+        OutputFormat::Json => print_json(&serde_json::json!({
+            "profile": name,
+            "keystore": keystore_path.display().to_string(),
+        }))?,
+    }
+    Ok(())
+}
+
+fn cmd_export(output: OutputFormat, private_key_flag: Option<&str>) -> Result<()> {
+    let key = config::require_key(private_key_flag)?;
+    let address = LocalSigner::from_str(&key)
+        .context(CliError::invalid_argument("Invalid private key"))?
+        .address();
+
+    match output {
+        OutputFormat::Table => {
+            eprintln!("WARNING: Anyone with this key controls the wallet's funds.");
+            println!("Address:     {address}");
+            println!("Private key: {key}");
+        }
+        OutputFormat::Json => print_json(&serde_json::json!({
+            "address": address.to_string(),
+            "private_key": key,
+        }))?,
+    }
+    Ok(())
+}
+
+/// List the accounts of `phrase` from `start`, with their proxy wallets.
+fn cmd_derive(output: OutputFormat, phrase: &str, start: u32, count: u32) -> Result<()> {
+    let accounts = (start..start.saturating_add(count))
+        .map(|index| {
+            let path = derivation_path_at(index);
+            let address = mnemonic_signer(phrase, &path)?.address();
+            Ok((
+                index,
+                path,
+                address,
+                derive_proxy_wallet(address, config::chain().id()),
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
 
     match output {
         OutputFormat::Json => {
-            println!(
-                "{}",
-                serde_json::json!({
-                    "address": address.to_string(),
-                    "proxy_address": proxy_addr.map(|a| a.to_string()),
-                    "signature_type": signature_type,
-                    "config_path": config_path.display().to_string(),
+            let accounts: Vec<_> = accounts
+                .iter()
+                .map(|(index, path, address, proxy)| {
+                    serde_json::json!({
+                        "index": index,
+                        "derivation_path": path,
+                        "address": address.to_string(),
+                        "proxy_address": proxy.map(|a| a.to_string()),
+                    })
                 })
-            );
+                .collect();
+            print_json(&accounts)?;
         }
         OutputFormat::Table => {
-            println!("Wallet imported successfully!");
-            println!("Address:        {address}");
-            if let Some(proxy) = proxy_addr {
-                println!("Proxy wallet:   {proxy}");
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "Index")]
+                index: u32,
+                #[tabled(rename = "Path")]
+                path: String,
+                #[tabled(rename = "Address")]
+                address: String,
+                #[tabled(rename = "Proxy Wallet")]
+                proxy: String,
             }
-            println!("Signature type: {signature_type}");
-            println!("Config:         {}", config_path.display());
+            let rows = accounts
+                .into_iter()
+                .map(|(index, path, address, proxy)| Row {
+                    index,
+                    path,
+                    address: address.to_string(),
+                    proxy: proxy.map_or_else(|| DASH.into(), |a| a.to_string()),
+                });
+            let table = Table::new(rows).with(Style::rounded()).to_string();
+            println!("{table}");
         }
     }
     Ok(())
 }
 
-fn cmd_address(output: OutputFormat, private_key_flag: Option<&str>) -> Result<()> {
-    let (key, _) = config::resolve_key(private_key_flag)?;
-    let key = key.ok_or_else(|| anyhow::anyhow!("{}", config::NO_WALLET_MSG))?;
+/// Save a profile for `address`: watch-only, or signing through `signer_url`.
+fn cmd_watch(
+    address: Address,
+    signer_url: Option<String>,
+    output: OutputFormat,
+    force: bool,
+    signature_type: SignatureType,
+    name: Option<String>,
+) -> Result<()> {
+    let profile = target_profile(name, force)?;
+    let (saved, verb) = match signer_url {
+        Some(url) => (
+            Profile::remote(address, url, signature_type),
+            "imported (remote signer)",
+        ),
+        None => (
+            Profile::watch_only(address, signature_type),
+            "imported (watch-only)",
+        ),
+    };
+    config::save_profile(&profile, saved)?;
+    print_saved(output, verb, &profile, address, signature_type, None)
+}
 
-    let signer = LocalSigner::from_str(&key).context("Invalid private key")?;
-    let address = signer.address();
+/// Address of a profile: its watch address, or the one derived from its key.
+fn profile_address(profile: &Profile) -> Option<Address> {
+    profile.address.or_else(|| {
+        profile
+            .private_key
+            .as_deref()
+            .and_then(|k| LocalSigner::from_str(k).ok())
+            .map(|s| s.address())
+    })
+}
+
+fn cmd_address(output: OutputFormat, private_key_flag: Option<&str>) -> Result<()> {
+    let (address, _) = config::resolve_address(private_key_flag)?;
+    let address = address.ok_or_else(|| CliError::auth(config::NO_WALLET_MSG))?;
 
     match output {
         OutputFormat::Json => {
-            println!("{}", serde_json::json!({"address": address.to_string()}));
+            print_json(&serde_json::json!({"address": address.to_string()}))?;
         }
         OutputFormat::Table => {
             println!("{address}");
@@ -175,72 +684,733 @@ fn cmd_address(output: OutputFormat, private_key_flag: Option<&str>) -> Result<(
     Ok(())
 }
 
-fn cmd_show(output: OutputFormat, private_key_flag: Option<&str>) -> Result<()> {
-    let (key, source) = config::resolve_key(private_key_flag)?;
-    let signer = key.as_deref().and_then(|k| LocalSigner::from_str(k).ok());
-    let address = signer.as_ref().map(|s| s.address().to_string());
-    let proxy_addr = signer
-        .as_ref()
-        .and_then(|s| derive_proxy_wallet(s.address(), POLYGON))
+fn cmd_show(
+    output: OutputFormat,
+    private_key_flag: Option<&str>,
+    signature_type_flag: Option<SignatureType>,
+) -> Result<()> {
+    // An invalid key is reported as "not configured" rather than failing.
+    let (address, source) = config::resolve_address(private_key_flag)
+        .or_else(|_| config::resolve_key(private_key_flag).map(|(_, source)| (None, source)))?;
+    let profile = config::load_profile()?;
+    let sig_type = config::resolve_signature_type(signature_type_flag)?;
+    let funder = config::funder()?;
+    // The wallet holding the funds, as `resolve_funder` picks it; `None`
+    // when the signature type has no wallet on this chain.
+    let trading_wallet = address
+        .and_then(|a| {
+            funder.or(match sig_type {
+                SignatureType::Eoa => Some(a),
+                _ => config::derived_wallet(a, sig_type),
+            })
+        })
         .map(|a| a.to_string());
+    let funder = funder.map(|a| a.to_string());
+    let signer_url = match source {
+        config::KeySource::RemoteSigner => profile.and_then(|p| p.signer_url),
+        _ => None,
+    };
+    let address = address.map(|a| a.to_string());
 
-    let sig_type = config::resolve_signature_type(None)?;
+    let profile_name = config::profile_name()?;
     let config_path = config::config_path()?;
 
     match output {
         OutputFormat::Json => {
-            println!(
-                "{}",
-                serde_json::json!({
-                    "address": address,
-                    "proxy_address": proxy_addr,
-                    "signature_type": sig_type,
-                    "config_path": config_path.display().to_string(),
-                    "source": source.label(),
-                    "configured": address.is_some(),
-                })
-            );
+            print_json(&serde_json::json!({
+                "profile": profile_name,
+                "address": address,
+                "trading_wallet": trading_wallet,
+                "funder": funder,
+                "signature_type": sig_type,
+                "chain": config::chain().name(),
+                "config_path": config_path.display().to_string(),
+                "source": source.label(),
+                "signer_url": signer_url,
+                "configured": address.is_some(),
+            }))?;
         }
         OutputFormat::Table => {
+            println!("Profile:        {profile_name}");
             match &address {
                 Some(addr) => println!("Address:        {addr}"),
                 None => println!("Address:        (not configured)"),
             }
-            if let Some(proxy) = &proxy_addr {
-                println!("Proxy wallet:   {proxy}");
+            if let Some(wallet) = &trading_wallet {
+                println!("Trading wallet: {wallet}");
+            }
+            if let Some(funder) = &funder {
+                println!("Funder:         {funder}");
             }
             println!("Signature type: {sig_type}");
+            println!("Chain:          {}", config::chain().name());
             println!("Config path:    {}", config_path.display());
             println!("Key source:     {}", source.label());
+            if let Some(url) = &signer_url {
+                println!("Signer:         {url}");
+            }
+        }
+    }
+    Ok(())
+}
+
+/// One of the wallets a key can trade from.
+struct Candidate {
+    signature_type: SignatureType,
+    address: Address,
+    /// Whether a contract is deployed there; `None` for the EOA.
+    deployed: Option<bool>,
+    usdc: Decimal,
+    pol: Decimal,
+}
+
+fn units(value: U256, decimals: u8) -> Result<Decimal> {
+    Ok(Decimal::from_str(&format_units(value, decimals)?)?.normalize())
+}
+
+/// The signature type to use and why: a deployed proxy or Safe, the one
+/// holding the most USDC first, else the EOA if it holds USDC.
+fn recommend(candidates: &[Candidate]) -> (SignatureType, &'static str) {
+    let contract = candidates
+        .iter()
+        .filter(|c| c.deployed == Some(true))
+        .reduce(|best, c| if c.usdc > best.usdc { c } else { best });
+    if let Some(contract) = contract {
+        let reason = if contract.usdc.is_zero() {
+            "its wallet contract is deployed"
+        } else {
+            "its wallet contract is deployed and holds USDC"
+        };
+        return (contract.signature_type, reason);
+    }
+    let eoa = candidates
+        .iter()
+        .find(|c| c.signature_type == SignatureType::Eoa);
+    if eoa.is_some_and(|c| !c.usdc.is_zero()) {
+        return (SignatureType::Eoa, "the key itself holds USDC");
+    }
+    if candidates
+        .iter()
+        .any(|c| c.signature_type == SignatureType::Proxy)
+    {
+        (
+            SignatureType::Proxy,
+            "no wallet is funded yet; proxy is the default",
+        )
+    } else {
+        (
+            SignatureType::Eoa,
+            "no wallet is funded yet and this chain has no proxy wallets",
+        )
+    }
+}
+
+async fn cmd_detect(
+    output: OutputFormat,
+    private_key_flag: Option<&str>,
+    save: bool,
+) -> Result<()> {
+    let (eoa, _) = config::resolve_address(private_key_flag)?;
+    let eoa = eoa.ok_or_else(|| CliError::auth(config::NO_WALLET_MSG))?;
+    let current = config::resolve_signature_type(None)?;
+    let provider = auth::create_readonly_provider().await?;
+    let usdc = IERC20::new(config::chain().usdc(), &provider);
+
+    let mut candidates = Vec::new();
+    for signature_type in [
+        SignatureType::Eoa,
+        SignatureType::Proxy,
+        SignatureType::GnosisSafe,
+    ] {
+        let address = match signature_type {
+            SignatureType::Eoa => eoa,
+            _ => match config::derived_wallet(eoa, signature_type) {
+                Some(address) => address,
+                None => continue,
+            },
+        };
+        let deployed = match signature_type {
+            SignatureType::Eoa => None,
+            _ => Some(!provider.get_code_at(address).await?.is_empty()),
+        };
+        candidates.push(Candidate {
+            signature_type,
+            address,
+            deployed,
+            usdc: units(usdc.balanceOf(address).call().await?, USDC_DECIMALS as u8)?,
+            pol: units(provider.get_balance(address).await?, 18)?,
+        });
+    }
+    let (recommended, reason) = recommend(&candidates);
+
+    if save {
+        let name = config::profile_name()?;
+        let mut config = config_with_profile(&name)?;
+        config
+            .profiles
+            .get_mut(&name)
+            .expect("checked by config_with_profile")
+            .signature_type = recommended;
+        config::save_config(&config)?;
+    }
+
+    match output {
+        OutputFormat::Json => {
+            let wallets: Vec<_> = candidates
+                .iter()
+                .map(|c| {
+                    serde_json::json!({
+                        "signature_type": c.signature_type,
+                        "address": c.address.to_string(),
+                        "deployed": c.deployed,
+                        "usdc": c.usdc.to_string(),
+                        "pol": c.pol.to_string(),
+                    })
+                })
+                .collect();
+            print_json(&serde_json::json!({
+                "address": eoa.to_string(),
+                "current": current,
+                "recommended": recommended,
+                "reason": reason,
+                "saved": save,
+                "wallets": wallets,
+            }))?;
+        }
+        OutputFormat::Table => {
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "Signature type")]
+                signature_type: SignatureType,
+                #[tabled(rename = "Address")]
+                address: String,
+                #[tabled(rename = "Deployed")]
+                deployed: &'static str,
+                #[tabled(rename = "USDC")]
+                usdc: String,
+                #[tabled(rename = "POL")]
+                pol: String,
+            }
+            let rows = candidates.iter().map(|c| Row {
+                signature_type: c.signature_type,
+                address: c.address.to_string(),
+                deployed: match c.deployed {
+                    None => DASH,
+                    Some(true) => "yes",
+                    Some(false) => "no",
+                },
+                usdc: c.usdc.to_string(),
+                pol: c.pol.to_string(),
+            });
+            println!("{}", Table::new(rows).with(Style::rounded()));
+            println!("Current signature type: {current}");
+            println!("Recommended:            {recommended} ({reason})");
+            if save {
+                println!("Saved to profile `{}`.", config::profile_name()?);
+            } else if recommended != current {
+                println!("Run `polymarket wallet detect --save` to use it.");
+            }
         }
     }
     Ok(())
 }
 
+/// One balance read on chain, with the CLOB's figure for it.
+struct Holding {
+    asset: String,
+    token_id: Option<U256>,
+    balance: Decimal,
+    clob: Option<Decimal>,
+}
+
+/// A wallet of the key and its balances.
+struct Holder {
+    role: SignatureType,
+    address: Address,
+    /// Whether it's the address that trades, which the CLOB knows.
+    trading: bool,
+    holdings: Vec<Holding>,
+}
+
+/// What the CLOB reports for `address`'s collateral and `tokens`, in
+/// whole units.
+async fn clob_balances(
+    private_key_flag: Option<&str>,
+    signature_type_flag: Option<SignatureType>,
+    tokens: &[U256],
+) -> Result<(Decimal, Vec<Decimal>)> {
+    let client = auth::authenticated_clob_client(private_key_flag, signature_type_flag).await?;
+    let divisor = Decimal::from(10u64.pow(USDC_DECIMALS));
+    let request = BalanceAllowanceRequest::builder()
+        .asset_type(AssetType::Collateral)
+        .build();
+    let collateral = client.balance_allowance(request).await?.balance / divisor;
+    let mut balances = Vec::with_capacity(tokens.len());
+    for &token in tokens {
+        let request = BalanceAllowanceRequest::builder()
+            .asset_type(AssetType::Conditional)
+            .token_id(token)
+            .build();
+        balances.push(client.balance_allowance(request).await?.balance / divisor);
+    }
+    Ok((collateral.normalize(), balances))
+}
+
+async fn cmd_balances(
+    output: OutputFormat,
+    private_key_flag: Option<&str>,
+    signature_type_flag: Option<SignatureType>,
+    data: impl FnOnce() -> Result<data::Client>,
+    tokens: Vec<U256>,
+) -> Result<()> {
+    let (eoa, _) = config::resolve_address(private_key_flag)?;
+    let eoa = eoa.ok_or_else(|| CliError::auth(config::NO_WALLET_MSG))?;
+    let signature_type = config::resolve_signature_type(signature_type_flag)?;
+    let trading = config::resolve_funder(private_key_flag, signature_type_flag)?;
+
+    // Without token IDs, check every token the data API has positions in.
+    let tokens: Vec<(U256, String)> = if tokens.is_empty() {
+        super::ctf::positions_by_condition(&data()?, trading, false)
+            .await?
+            .into_values()
+            .flatten()
+            .map(|p| {
+                (
+                    p.asset,
+                    format!("{} \u{b7} {}", p.outcome, truncate(&p.title, 40)),
+                )
+            })
+            .collect()
+    } else {
+        tokens
+            .into_iter()
+            .map(|t| (t, format!("Token {}", truncate(&t.to_string(), 12))))
+            .collect()
+    };
+    let token_ids: Vec<U256> = tokens.iter().map(|(id, _)| *id).collect();
+
+    let clob = clob_balances(private_key_flag, signature_type_flag, &token_ids).await;
+    let (clob_collateral, clob_tokens, clob_error) = match clob {
+        Ok((collateral, tokens)) => (
+            Some(collateral),
+            tokens.into_iter().map(Some).collect(),
+            None,
+        ),
+        Err(e) => (None, vec![None; token_ids.len()], Some(format!("{e:#}"))),
+    };
+
+    let provider = auth::create_readonly_provider().await?;
+    let chain = config::chain();
+    let usdc = IERC20::new(chain.usdc(), &provider);
+    let native_usdc = IERC20::new(chain.native_usdc(), &provider);
+    let ctf = IERC1155::new(chain.contracts(false).conditional_tokens, &provider);
+    let decimals = USDC_DECIMALS as u8;
+
+    let mut holders = vec![(SignatureType::Eoa, eoa)];
+    if trading != eoa {
+        holders.push((signature_type, trading));
+    }
+    let mut wallets = Vec::new();
+    for (role, address) in holders {
+        let is_trading = address == trading;
+        let clob_for = |balance: Option<Decimal>| balance.filter(|_| is_trading);
+        let mut holdings = vec![
+            Holding {
+                asset: "POL".into(),
+                token_id: None,
+                balance: units(provider.get_balance(address).await?, 18)?,
+                clob: None,
+            },
+            Holding {
+                asset: "USDC.e".into(),
+                token_id: None,
+                balance: units(usdc.balanceOf(address).call().await?, decimals)?,
+                clob: clob_for(clob_collateral),
+            },
+            Holding {
+                asset: "USDC".into(),
+                token_id: None,
+                balance: units(native_usdc.balanceOf(address).call().await?, decimals)?,
+                clob: None,
+            },
+        ];
+        for ((token_id, label), clob) in tokens.iter().zip(&clob_tokens) {
+            holdings.push(Holding {
+                asset: label.clone(),
+                token_id: Some(*token_id),
+                balance: units(ctf.balanceOf(address, *token_id).call().await?, decimals)?,
+                clob: clob_for(*clob),
+            });
+        }
+        wallets.push(Holder {
+            role,
+            address,
+            trading: is_trading,
+            holdings,
+        });
+    }
+
+    match output {
+        OutputFormat::Json => {
+            let wallets: Vec<_> = wallets
+                .iter()
+                .map(|w| {
+                    let balances: Vec<_> = w
+                        .holdings
+                        .iter()
+                        .map(|h| {
+                            serde_json::json!({
+                                "asset": h.asset,
+                                "token_id": h.token_id.map(|t| t.to_string()),
+                                "balance": h.balance.to_string(),
+                                "clob_balance": h.clob.map(|c| c.to_string()),
+                            })
+                        })
+                        .collect();
+                    serde_json::json!({
+                        "signature_type": w.role,
+                        "address": w.address.to_string(),
+                        "trading": w.trading,
+                        "balances": balances,
+                    })
+                })
+                .collect();
+            print_json(&serde_json::json!({
+                "wallets": wallets,
+                "clob_error": clob_error,
+            }))?;
+        }
+        OutputFormat::Table => {
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "Wallet")]
+                wallet: String,
+                #[tabled(rename = "Asset")]
+                asset: String,
+                #[tabled(rename = "On chain")]
+                balance: String,
+                #[tabled(rename = "CLOB")]
+                clob: String,
+            }
+            let mut mismatch = false;
+            let mut rows = Vec::new();
+            for wallet in &wallets {
+                for holding in &wallet.holdings {
+                    let clob = match holding.clob {
+                        None => DASH.to_string(),
+                        Some(clob) if clob == holding.balance => clob.to_string(),
+                        Some(clob) => {
+                            mismatch = true;
+                            format!("{clob} \u{2717}")
+                        }
+                    };
+                    rows.push(Row {
+                        wallet: wallet.role.to_string(),
+                        asset: holding.asset.clone(),
+                        balance: holding.balance.to_string(),
+                        clob,
+                    });
+                }
+            }
+            for wallet in &wallets {
+                let note = if wallet.trading { " (trades)" } else { "" };
+                println!("{:<12} {}{note}", wallet.role.as_str(), wallet.address);
+            }
+            println!("{}", Table::new(rows).with(Style::rounded()));
+            if mismatch {
+                println!(
+                    "\u{2717} The CLOB's balance differs from the chain. It lags behind transfers; \
+                     `polymarket clob update-balance` refreshes it."
+                );
+            }
+            if let Some(error) = clob_error {
+                println!("CLOB balances unavailable: {error}");
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Parse a positive amount of POL into wei.
+fn parse_pol_amount(s: &str) -> Result<U256> {
+    let val: Decimal = s
+        .trim()
+        .parse()
+        .context(CliError::invalid_argument(format!("Invalid amount: {s}")))?;
+    anyhow::ensure!(
+        val > Decimal::ZERO,
+        CliError::invalid_argument("Amount must be positive")
+    );
+    let raw = val
+        .checked_mul(Decimal::from(10u64.pow(18)))
+        .ok_or_else(|| CliError::invalid_argument(format!("Amount too large: {val}")))?;
+    anyhow::ensure!(
+        raw.fract().is_zero(),
+        CliError::invalid_argument(format!(
+            "Amount {val} exceeds POL precision (max 18 decimal places)"
+        ))
+    );
+    let raw: u128 = raw
+        .try_into()
+        .map_err(|_| CliError::invalid_argument(format!("Amount too large: {val}")))?;
+    Ok(U256::from(raw))
+}
+
+async fn cmd_send(
+    output: OutputFormat,
+    private_key_flag: Option<&str>,
+    signature_type_flag: Option<SignatureType>,
+    to: Address,
+    amount: &str,
+    token: SendToken,
+) -> Result<()> {
+    tx::check_recipient(to)?;
+    let chain = config::chain();
+    let (call, asset, raw, decimals) = match token {
+        SendToken::Pol => {
+            let raw = parse_pol_amount(amount)?;
+            (Call::native(to, raw), "POL", raw, 18)
+        }
+        SendToken::UsdcE | SendToken::Usdc => {
+            let raw = parse_usdc_amount(amount)?;
+            let (contract, asset) = match token {
+                SendToken::Usdc => (chain.native_usdc(), "USDC"),
+                _ => (chain.usdc(), "USDC.e"),
+            };
+            let call = Call::new(contract, &IERC20::transferCall { to, value: raw });
+            (call, asset, raw, USDC_DECIMALS as u8)
+        }
+    };
+    let changes = vec![BalanceChange {
+        asset: asset.to_string(),
+        amount: -units(raw, decimals)?,
+    }];
+    execute_call(
+        "send",
+        "Transfer failed",
+        private_key_flag,
+        Sender::resolve(private_key_flag, signature_type_flag).await?,
+        call,
+        async { Ok(changes) },
+        &output,
+    )
+    .await
+}
+
+fn cmd_list(output: OutputFormat) -> Result<()> {
+    let config = config::load_config()?.unwrap_or_default();
+    let active = config.profile_name();
+    let profiles: Vec<_> = config
+        .profiles
+        .iter()
+        .map(|(name, p)| {
+            serde_json::json!({
+                "name": name,
+                "address": profile_address(p).map(|a| a.to_string()),
+                "signature_type": p.signature_type,
+                "funder": p.funder.map(|a| a.to_string()),
+                "encrypted": p.keystore.is_some(),
+                "signer_url": p.signer_url,
+                "watch_only": !p.can_sign(),
+                "active": *name == active,
+            })
+        })
+        .collect();
+
+    match output {
+        OutputFormat::Json => print_json(&profiles)?,
+        OutputFormat::Table => {
+            if config.profiles.is_empty() {
+                println!(
+                    "No profiles. Run `polymarket wallet create` or `polymarket wallet import <key>`."
+                );
+                return Ok(());
+            }
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "")]
+                active: &'static str,
+                #[tabled(rename = "Profile")]
+                name: String,
+                #[tabled(rename = "Address")]
+                address: String,
+                #[tabled(rename = "Signature Type")]
+                signature_type: String,
+                #[tabled(rename = "Key")]
+                key: &'static str,
+            }
+            let rows = config.profiles.iter().map(|(name, p)| Row {
+                active: if *name == active { "*" } else { "" },
+                name: name.clone(),
+                address: profile_address(p).map_or_else(|| DASH.into(), |a| a.to_string()),
+                signature_type: p.signature_type.to_string(),
+                key: if p.keystore.is_some() {
+                    "encrypted"
+                } else if p.signer_url.is_some() {
+                    "remote"
+                } else if p.private_key.is_some() {
+                    "plaintext"
+                } else {
+                    "watch-only"
+                },
+            });
+            let table = Table::new(rows).with(Style::rounded()).to_string();
+            println!("{table}");
+        }
+    }
+    Ok(())
+}
+
+/// Load the config and check that profile `name` exists.
+fn config_with_profile(name: &str) -> Result<config::Config> {
+    config::load_config()?
+        .filter(|c| c.profiles.contains_key(name))
+        .ok_or_else(|| config::profile_not_found(name))
+}
+
+async fn cmd_set_funder(
+    output: OutputFormat,
+    private_key_flag: Option<&str>,
+    signature_type_flag: Option<SignatureType>,
+    funder: Option<Address>,
+    verify: bool,
+) -> Result<()> {
+    let name = config::profile_name()?;
+    let mut config = config_with_profile(&name)?;
+    let verified = match funder {
+        Some(funder) if verify => {
+            let (eoa, _) = config::resolve_address(private_key_flag)?;
+            let eoa = eoa.ok_or_else(|| CliError::auth(config::NO_WALLET_MSG))?;
+            let signature_type = config::resolve_signature_type(signature_type_flag)?;
+            auth::verify_funder(eoa, funder, signature_type).await?
+        }
+        _ => false,
+    };
+    config
+        .profiles
+        .get_mut(&name)
+        .expect("checked by config_with_profile")
+        .funder = funder;
+    config::save_config(&config)?;
+
+    match output {
+        OutputFormat::Table => match funder {
+            Some(funder) => {
+                println!("Funder of profile `{name}` set to {funder}.");
+                if verified {
+                    println!("Verified on chain: the key controls it.");
+                } else if verify {
+                    println!("Proxy wallets have no on-chain owner, so this couldn't be verified.");
+                }
+            }
+            None => println!("Removed the funder of profile `{name}`."),
+        },
+        OutputFormat::Json => print_json(&serde_json::json!({
+            "profile": name,
+            "funder": funder.map(|a| a.to_string()),
+            "verified": verified,
+        }))?,
+    }
+    Ok(())
+}
+
+fn cmd_use(output: OutputFormat, name: &str) -> Result<()> {
+    let mut config = config_with_profile(name)?;
+    config.active_profile = name.to_string();
+    config::save_config(&config)?;
+
+    match output {
+        OutputFormat::Table => println!("Now using profile `{name}`."),
+        OutputFormat::Json => print_json(&serde_json::json!({"active_profile": name}))?,
+    }
+    Ok(())
+}
+
+fn cmd_rename(output: OutputFormat, from: &str, to: &str) -> Result<()> {
+    config::validate_profile_name(to)?;
+    let mut config = config_with_profile(from)?;
+    if config.profiles.contains_key(to) {
+        return Err(CliError::invalid_argument(format!("Profile `{to}` already exists")).into());
+    }
+    let profile = config.profiles.remove(from).expect("checked above");
+    config.profiles.insert(to.to_string(), profile);
+    if config.active_profile == from {
+        config.active_profile = to.to_string();
+    }
+    config::save_config(&config)?;
+
+    match output {
+        OutputFormat::Table => println!("Renamed profile `{from}` to `{to}`."),
+        OutputFormat::Json => print_json(&serde_json::json!({"from": from, "to": to}))?,
+    }
+    Ok(())
+}
+
+fn cmd_remove(output: OutputFormat, name: &str, force: bool) -> Result<()> {
+    let mut config = config_with_profile(name)?;
+    let has_key = config.profiles[name].can_sign();
+    if has_key && !force && !confirm(&format!("Delete profile `{name}` and its private key?"))? {
+        println!("Aborted.");
+        return Ok(());
+    }
+
+    let removed = config.profiles.remove(name).expect("checked above");
+    if let Some(path) = removed.keystore {
+        std::fs::remove_file(&path)
+            .or_else(|e| match e.kind() {
+                std::io::ErrorKind::NotFound => Ok(()),
+                _ => Err(e),
+            })
+            .context(format!("Failed to delete keystore {}", path.display()))?;
+    }
+    if config.active_profile == name {
+        config.active_profile = config
+            .profiles
+            .keys()
+            .next()
+            .cloned()
+            .unwrap_or_else(|| config::DEFAULT_PROFILE.to_string());
+    }
+    config::save_config(&config)?;
+
+    match output {
+        OutputFormat::Table => {
+            println!("Removed profile `{name}`.");
+            if !config.profiles.is_empty() {
+                println!("Active profile: {}", config.active_profile);
+            }
+        }
+        OutputFormat::Json => print_json(&serde_json::json!({
+            "removed": name,
+            "active_profile": config.active_profile,
+        }))?,
+    }
+    Ok(())
+}
+
+fn confirm(question: &str) -> Result<bool> {
+    use std::io::{self, BufRead, Write};
+    print!("{question} [y/N] ");
+    io::stdout().flush()?;
+    let mut input = String::new();
+    io::stdin().lock().read_line(&mut input)?;
+    Ok(matches!(input.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
 fn cmd_reset(output: OutputFormat, force: bool) -> Result<()> {
     if !config::config_exists() {
         match output {
             OutputFormat::Table => println!("Nothing to reset. No config found."),
             OutputFormat::Json => {
-                println!(
-                    "{}",
-                    serde_json::json!({"reset": false, "reason": "no config found"})
-                );
+                print_json(&serde_json::json!({"reset": false, "reason": "no config found"}))?;
             }
         }
         return Ok(());
     }
 
-    if !force {
-        use std::io::{self, BufRead, Write};
-        print!("This will delete all keys and config. Are you sure? [y/N] ");
-        io::stdout().flush()?;
-        let mut input = String::new();
-        io::stdin().lock().read_line(&mut input)?;
-        if !matches!(input.trim().to_lowercase().as_str(), "y" | "yes") {
-            println!("Aborted.");
-            return Ok(());
-        }
+    if !force && !confirm("This will delete all keys and config. Are you sure?")? {
+        println!("Aborted.");
+        return Ok(());
     }
 
     let path = config::config_path()?;
@@ -252,14 +1422,100 @@ fn cmd_reset(output: OutputFormat, force: bool) -> Result<()> {
             println!("All keys and settings have been removed.");
         }
         OutputFormat::Json => {
-            println!(
-                "{}",
-                serde_json::json!({
-                    "reset": true,
-                    "deleted": path.display().to_string(),
-                })
-            );
+            print_json(&serde_json::json!({
+                "reset": true,
+                "deleted": path.display().to_string(),
+            }))?;
         }
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(signature_type: SignatureType, deployed: Option<bool>, usdc: i64) -> Candidate {
+        Candidate {
+            signature_type,
+            address: Address::ZERO,
+            deployed,
+            usdc: Decimal::from(usdc),
+            pol: Decimal::ZERO,
+        }
+    }
+
+    #[test]
+    fn detect_prefers_funded_contracts() {
+        let eoa = candidate(SignatureType::Eoa, None, 10);
+        let proxy = candidate(SignatureType::Proxy, Some(true), 5);
+        let safe = candidate(SignatureType::GnosisSafe, Some(true), 20);
+        let (recommended, _) = recommend(&[eoa, proxy, safe]);
+        assert_eq!(recommended, SignatureType::GnosisSafe);
+
+        let eoa = candidate(SignatureType::Eoa, None, 10);
+        let proxy = candidate(SignatureType::Proxy, Some(false), 0);
+        assert_eq!(recommend(&[eoa, proxy]).0, SignatureType::Eoa);
+
+        let eoa = candidate(SignatureType::Eoa, None, 0);
+        let proxy = candidate(SignatureType::Proxy, Some(false), 0);
+        assert_eq!(recommend(&[eoa, proxy]).0, SignatureType::Proxy);
+        let eoa = candidate(SignatureType::Eoa, None, 0);
+        assert_eq!(recommend(&[eoa]).0, SignatureType::Eoa);
+    }
+
+    const PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn mnemonic_signer_follows_bip44() {
+        let first = mnemonic_signer(PHRASE, &derivation_path_at(0)).unwrap();
+        assert_eq!(
+            first.address(),
+            "0x9858EfFD232B4033E47d90003D41EC34EcaEda94"
+                .parse::<Address>()
+                .unwrap()
+        );
+        let custom = mnemonic_signer(PHRASE, "m/44'/60'/0'/0/1").unwrap();
+        assert_eq!(
+            custom.address(),
+            mnemonic_signer(PHRASE, &derivation_path_at(1))
+                .unwrap()
+                .address()
+        );
+    }
+
+    #[test]
+    fn mnemonic_signer_ignores_extra_whitespace() {
+        let spaced = format!("  {}\n", PHRASE.replace(' ', "   "));
+        assert_eq!(
+            mnemonic_signer(&spaced, &derivation_path_at(0))
+                .unwrap()
+                .address(),
+            mnemonic_signer(PHRASE, &derivation_path_at(0))
+                .unwrap()
+                .address()
+        );
+    }
+
+    #[test]
+    fn mnemonic_signer_rejects_bad_input() {
+        assert!(mnemonic_signer("not a phrase", &derivation_path_at(0)).is_err());
+        assert!(mnemonic_signer(PHRASE, "m/not/a/path").is_err());
+    }
+
+    #[test]
+    fn pol_amounts_are_parsed_to_wei() {
+        assert_eq!(
+            parse_pol_amount("1.5").unwrap(),
+            U256::from(1_500_000_000_000_000_000u128)
+        );
+        assert_eq!(
+            parse_pol_amount("0.000000000000000001").unwrap(),
+            U256::from(1)
+        );
+        assert!(parse_pol_amount("0.0000000000000000001").is_err());
+        assert!(parse_pol_amount("0").is_err());
+        assert!(parse_pol_amount("-1").is_err());
+        assert!(parse_pol_amount("lots").is_err());
+    }
+}
@@ -1,32 +1,415 @@
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Mutex;
 
+use alloy::primitives::address;
 use anyhow::{Context, Result};
+use polymarket_client_sdk::auth::LocalSigner;
+use polymarket_client_sdk::types::{Address, ChainId};
+use polymarket_client_sdk::{
+    AMOY, ContractConfig, POLYGON, contract_config, derive_proxy_wallet, derive_safe_wallet,
+};
 use serde::{Deserialize, Serialize};
 
+use crate::error::CliError;
+use crate::output::OutputFlag;
+use crate::output::display::{Numbers, PriceStyle, Timezone};
+
 const ENV_VAR: &str = "POLYMARKET_PRIVATE_KEY";
 const SIG_TYPE_ENV_VAR: &str = "POLYMARKET_SIGNATURE_TYPE";
-pub(crate) const DEFAULT_SIGNATURE_TYPE: &str = "proxy";
+const PROFILE_ENV_VAR: &str = "POLYMARKET_PROFILE";
+const CHAIN_ENV_VAR: &str = "POLYMARKET_CHAIN";
+const FUNDER_ENV_VAR: &str = "POLYMARKET_FUNDER";
+pub(crate) const RPC_URL_ENV_VAR: &str = "POLYMARKET_RPC_URL";
+pub(crate) const GAMMA_URL_ENV_VAR: &str = "POLYMARKET_GAMMA_URL";
+pub(crate) const CLOB_URL_ENV_VAR: &str = "POLYMARKET_CLOB_URL";
+pub(crate) const DATA_URL_ENV_VAR: &str = "POLYMARKET_DATA_URL";
+pub(crate) const BRIDGE_URL_ENV_VAR: &str = "POLYMARKET_BRIDGE_URL";
+pub(crate) const DEFAULT_PROFILE: &str = "default";
+pub(crate) const DEFAULT_LIMIT: i32 = 25;
+pub(crate) const DEFAULT_RPC_URL: &str = "https://polygon.drpc.org";
+pub(crate) const DEFAULT_AMOY_RPC_URL: &str = "https://rpc-amoy.polygon.technology";
+pub(crate) const DEFAULT_GAMMA_URL: &str = "https://gamma-api.polymarket.com";
+pub(crate) const DEFAULT_CLOB_URL: &str = "https://clob.polymarket.com";
+pub(crate) const DEFAULT_AMOY_CLOB_URL: &str = "https://clob-staging.polymarket.com";
+pub(crate) const DEFAULT_DATA_URL: &str = "https://data-api.polymarket.com";
+pub(crate) const DEFAULT_BRIDGE_URL: &str = "https://bridge.polymarket.com";
 
 pub(crate) const NO_WALLET_MSG: &str =
     "No wallet configured. Run `polymarket wallet create` or `polymarket wallet import <key>`";
 
-#[derive(Serialize, Deserialize)]
+/// `config.json`: named wallet profiles plus settings shared by all of them.
+#[derive(Default, Serialize, Deserialize)]
 pub(crate) struct Config {
-    pub private_key: String,
+    /// Profile used when neither `--profile` nor `POLYMARKET_PROFILE` is set.
+    #[serde(default = "default_profile_name")]
+    pub active_profile: String,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+    #[serde(default, skip_serializing_if = "DisplayConfig::is_empty")]
+    pub display: DisplayConfig,
+    #[serde(default, skip_serializing_if = "Settings::is_empty")]
+    pub settings: Settings,
+}
+
+/// One wallet: a private key, or just an address for watch-only profiles.
+#[derive(Clone, Serialize, Deserialize)]
+pub(crate) struct Profile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private_key: Option<String>,
+    /// Encrypted keystore holding the key, instead of `private_key`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keystore: Option<PathBuf>,
+    /// Command printing the keystore password, e.g. `pass show polymarket`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password_command: Option<String>,
+    /// JSON-RPC endpoint of a remote signer holding the key, e.g. Web3Signer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signer_url: Option<String>,
+    /// The wallet's address: the only field of watch-only profiles.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+    #[serde(default = "default_chain_id")]
     pub chain_id: u64,
-    #[serde(default = "default_signature_type")]
-    pub signature_type: String,
+    #[serde(default)]
+    pub signature_type: SignatureType,
+    /// Address holding the funds, when it isn't the one derived from the key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub funder: Option<Address>,
+    /// Display defaults for this profile, over the top-level `display`.
+    #[serde(default, skip_serializing_if = "DisplayConfig::is_empty")]
+    pub display: DisplayConfig,
+    /// Settings for this profile, over the top-level `settings`.
+    #[serde(default, skip_serializing_if = "Settings::is_empty")]
+    pub settings: Settings,
+}
+
+impl Profile {
+    pub fn with_key(private_key: String, signature_type: SignatureType) -> Self {
+        Self {
+            private_key: Some(private_key),
+            keystore: None,
+            password_command: None,
+            signer_url: None,
+            address: None,
+            chain_id: chain().id(),
+            signature_type,
+            funder: None,
+            display: DisplayConfig::default(),
+            settings: Settings::default(),
+        }
+    }
+
+    pub fn watch_only(address: Address, signature_type: SignatureType) -> Self {
+        Self {
+            private_key: None,
+            address: Some(address),
+            ..Self::with_key(String::new(), signature_type)
+        }
+    }
+
+    pub fn in_keystore(
+        keystore: PathBuf,
+        address: Address,
+        password_command: Option<String>,
+        signature_type: SignatureType,
+    ) -> Self {
+        Self {
+            private_key: None,
+            keystore: Some(keystore),
+            password_command,
+            address: Some(address),
+            ..Self::with_key(String::new(), signature_type)
+        }
+    }
+
+    pub fn remote(address: Address, signer_url: String, signature_type: SignatureType) -> Self {
+        Self {
+            signer_url: Some(signer_url),
+            ..Self::watch_only(address, signature_type)
+        }
+    }
+
+    /// Whether the profile can sign: with a key, plaintext or encrypted, or
+    /// through a remote signer.
+    pub fn can_sign(&self) -> bool {
+        self.private_key.is_some() || self.keystore.is_some() || self.signer_url.is_some()
+    }
+}
+
+/// Table display preferences; each is overridden by its global flag.
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub(crate) struct DisplayConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<Timezone>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub numbers: Option<Numbers>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price_style: Option<PriceStyle>,
+}
+
+impl DisplayConfig {
+    fn is_empty(&self) -> bool {
+        self.timezone.is_none() && self.numbers.is_none() && self.price_style.is_none()
+    }
+
+    /// `self`, with unset fields taken from `fallback`.
+    fn or(self, fallback: Self) -> Self {
+        Self {
+            timezone: self.timezone.or(fallback.timezone),
+            numbers: self.numbers.or(fallback.numbers),
+            price_style: self.price_style.or(fallback.price_style),
+        }
+    }
+}
+
+/// How orders are signed, which decides the address holding the funds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum SignatureType {
+    /// The key signs for itself and holds the funds
+    Eoa,
+    /// A Polymarket proxy wallet derived from the key holds the funds
+    #[default]
+    Proxy,
+    /// A Gnosis Safe owned by the key holds the funds
+    GnosisSafe,
+}
+
+impl SignatureType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Eoa => "eoa",
+            Self::Proxy => "proxy",
+            Self::GnosisSafe => "gnosis-safe",
+        }
+    }
+}
+
+impl std::fmt::Display for SignatureType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Network to talk to: Polygon mainnet or the Amoy testnet.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum Chain {
+    #[default]
+    Polygon,
+    Amoy,
+}
+
+impl Chain {
+    pub fn id(self) -> ChainId {
+        match self {
+            Self::Polygon => POLYGON,
+            Self::Amoy => AMOY,
+        }
+    }
+
+    pub fn from_id(id: ChainId) -> Option<Self> {
+        match id {
+            POLYGON => Some(Self::Polygon),
+            AMOY => Some(Self::Amoy),
+            _ => None,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Polygon => "polygon",
+            Self::Amoy => "amoy",
+        }
+    }
+
+    /// Exchange, collateral and conditional token contracts; `neg_risk`
+    /// selects the neg-risk exchange.
+    pub fn contracts(self, neg_risk: bool) -> &'static ContractConfig {
+        contract_config(self.id(), neg_risk).expect("the SDK configures every supported chain")
+    }
+
+    /// The USDC token used as collateral.
+    pub fn usdc(self) -> Address {
+        self.contracts(false).collateral
+    }
+
+    /// Circle's native USDC, which Polymarket doesn't take as collateral.
+    pub fn native_usdc(self) -> Address {
+        match self {
+            Self::Polygon => address!("0x3c499c542cEF5E3811e1192ce70d8cC03d5c3359"),
+            Self::Amoy => address!("0x41E94Eb019C0762f9Bfcf9Fb1E58725BfB0e7582"),
+        }
+    }
+
+    pub fn default_rpc_url(self) -> &'static str {
+        match self {
+            Self::Polygon => DEFAULT_RPC_URL,
+            Self::Amoy => DEFAULT_AMOY_RPC_URL,
+        }
+    }
+
+    pub fn default_clob_url(self) -> &'static str {
+        match self {
+            Self::Polygon => DEFAULT_CLOB_URL,
+            Self::Amoy => DEFAULT_AMOY_CLOB_URL,
+        }
+    }
+
+    /// Block explorer link to transaction `tx_hash`.
+    pub fn tx_url(self, tx_hash: impl std::fmt::Display) -> String {
+        match self {
+            Self::Polygon => format!("https://polygonscan.com/tx/{tx_hash}"),
+            Self::Amoy => format!("https://amoy.polygonscan.com/tx/{tx_hash}"),
+        }
+    }
 }
 
-fn default_signature_type() -> String {
-    DEFAULT_SIGNATURE_TYPE.to_string()
+/// Endpoints and command defaults, set with `polymarket config set`.
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub(crate) struct Settings {
+    /// Output format when `--output` isn't given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<OutputFlag>,
+    /// Page size of list commands when `--limit` isn't given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+    /// Seconds before a network command gives up; commands sending
+    /// transactions are exempt.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rpc_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gamma_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clob_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bridge_url: Option<String>,
+}
+
+impl Settings {
+    fn is_empty(&self) -> bool {
+        self.output.is_none()
+            && self.limit.is_none()
+            && self.timeout.is_none()
+            && self.rpc_url.is_none()
+            && self.gamma_url.is_none()
+            && self.clob_url.is_none()
+            && self.data_url.is_none()
+            && self.bridge_url.is_none()
+    }
+
+    /// Endpoints set through `POLYMARKET_*_URL` environment variables.
+    pub fn from_env() -> Self {
+        let var = |name| std::env::var(name).ok().filter(|v: &String| !v.is_empty());
+        Self {
+            rpc_url: var(RPC_URL_ENV_VAR),
+            gamma_url: var(GAMMA_URL_ENV_VAR),
+            clob_url: var(CLOB_URL_ENV_VAR),
+            data_url: var(DATA_URL_ENV_VAR),
+            bridge_url: var(BRIDGE_URL_ENV_VAR),
+            ..Self::default()
+        }
+    }
+
+    /// `self`, with unset fields taken from `fallback`.
+    pub fn or(self, fallback: Self) -> Self {
+        Self {
+            output: self.output.or(fallback.output),
+            limit: self.limit.or(fallback.limit),
+            timeout: self.timeout.or(fallback.timeout),
+            rpc_url: self.rpc_url.or(fallback.rpc_url),
+            gamma_url: self.gamma_url.or(fallback.gamma_url),
+            clob_url: self.clob_url.or(fallback.clob_url),
+            data_url: self.data_url.or(fallback.data_url),
+            bridge_url: self.bridge_url.or(fallback.bridge_url),
+        }
+    }
+
+    pub fn rpc_url(&self) -> &str {
+        self.rpc_url
+            .as_deref()
+            .unwrap_or_else(|| chain().default_rpc_url())
+    }
+
+    pub fn gamma_url(&self) -> String {
+        base_url(self.gamma_url.as_deref().unwrap_or(DEFAULT_GAMMA_URL))
+    }
+
+    pub fn clob_url(&self) -> String {
+        base_url(
+            self.clob_url
+                .as_deref()
+                .unwrap_or_else(|| chain().default_clob_url()),
+        )
+    }
+
+    pub fn data_url(&self) -> String {
+        base_url(self.data_url.as_deref().unwrap_or(DEFAULT_DATA_URL))
+    }
+
+    pub fn bridge_url(&self) -> String {
+        base_url(self.bridge_url.as_deref().unwrap_or(DEFAULT_BRIDGE_URL))
+    }
+}
+
+/// `url` ending in a slash, since the SDK clients append paths to it verbatim.
+fn base_url(url: &str) -> String {
+    if url.ends_with('/') {
+        url.to_string()
+    } else {
+        format!("{url}/")
+    }
+}
+
+/// Single-wallet config written before profiles existed.
+#[derive(Deserialize)]
+struct LegacyConfig {
+    private_key: String,
+    #[serde(default = "default_chain_id")]
+    chain_id: u64,
+    #[serde(default)]
+    signature_type: SignatureType,
+    #[serde(default)]
+    display: DisplayConfig,
+}
+
+impl From<LegacyConfig> for Config {
+    fn from(legacy: LegacyConfig) -> Self {
+        let profile = Profile {
+            chain_id: legacy.chain_id,
+            ..Profile::with_key(legacy.private_key, legacy.signature_type)
+        };
+        Self {
+            active_profile: DEFAULT_PROFILE.to_string(),
+            profiles: BTreeMap::from([(DEFAULT_PROFILE.to_string(), profile)]),
+            display: legacy.display,
+            settings: Settings::default(),
+        }
+    }
+}
+
+fn default_profile_name() -> String {
+    DEFAULT_PROFILE.to_string()
+}
+
+fn default_chain_id() -> u64 {
+    POLYGON
 }
 
 pub(crate) enum KeySource {
     Flag,
     EnvVar,
     ConfigFile,
+    Keystore,
+    RemoteSigner,
+    WatchOnly,
     None,
 }
 
@@ -36,12 +419,37 @@ impl KeySource {
             Self::Flag => "--private-key flag",
             Self::EnvVar => "POLYMARKET_PRIVATE_KEY env var",
             Self::ConfigFile => "config file",
+            Self::Keystore => "encrypted keystore",
+            Self::RemoteSigner => "remote signer",
+            Self::WatchOnly => "watch-only profile (no key)",
             Self::None => "not configured",
         }
     }
 }
 
-fn config_dir() -> Result<PathBuf> {
+/// Set by `--profile` for the command currently running.
+static PROFILE_FLAG: Mutex<Option<String>> = Mutex::new(None);
+
+pub(crate) fn set_profile_flag(profile: Option<String>) {
+    *PROFILE_FLAG
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = profile;
+}
+
+/// Profile requested with `--profile` or `POLYMARKET_PROFILE`, if any.
+pub(crate) fn requested_profile() -> Option<String> {
+    let flag = PROFILE_FLAG
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone();
+    flag.or_else(|| {
+        std::env::var(PROFILE_ENV_VAR)
+            .ok()
+            .filter(|p| !p.is_empty())
+    })
+}
+
+pub(crate) fn config_dir() -> Result<PathBuf> {
     let home = dirs::home_dir().context("Could not determine home directory")?;
     Ok(home.join(".config").join("polymarket"))
 }
@@ -62,6 +470,15 @@ pub fn delete_config() -> Result<()> {
     Ok(())
 }
 
+/// Parse `config.json`, migrating a single-wallet config to a `default` profile.
+fn parse_config(data: &str) -> serde_json::Result<Config> {
+    let value: serde_json::Value = serde_json::from_str(data)?;
+    if value.get("profiles").is_none() && value.get("private_key").is_some() {
+        return serde_json::from_value::<LegacyConfig>(value).map(Config::from);
+    }
+    serde_json::from_value(value)
+}
+
 /// Load config from disk. Returns `Ok(None)` if no config file exists,
 /// or `Err` if the file exists but can't be read or parsed.
 pub fn load_config() -> Result<Option<Config>> {
@@ -73,28 +490,191 @@ pub fn load_config() -> Result<Option<Config>> {
             return Err(anyhow::anyhow!(e).context(format!("Failed to read {}", path.display())));
         }
     };
-    let config = serde_json::from_str(&data)
-        .context(format!("Invalid JSON in config file {}", path.display()))?;
+    let config =
+        parse_config(&data).context(format!("Invalid JSON in config file {}", path.display()))?;
     Ok(Some(config))
 }
 
-/// Priority: CLI flag > env var > config file > default ("proxy").
-pub fn resolve_signature_type(cli_flag: Option<&str>) -> Result<String> {
+impl Config {
+    /// Name of the profile in use: `--profile`, then `POLYMARKET_PROFILE`,
+    /// then `active_profile`.
+    pub fn profile_name(&self) -> String {
+        requested_profile().unwrap_or_else(|| self.active_profile.clone())
+    }
+
+    /// The profile in use. Only an explicitly requested profile must exist.
+    pub fn profile(&self) -> Result<Option<&Profile>> {
+        let name = self.profile_name();
+        match self.profiles.get(&name) {
+            Some(profile) => Ok(Some(profile)),
+            None if requested_profile().is_some() => Err(profile_not_found(&name)),
+            None => Ok(None),
+        }
+    }
+}
+
+pub(crate) fn profile_not_found(name: &str) -> anyhow::Error {
+    CliError::invalid_argument(format!(
+        "Profile `{name}` not found. Run `polymarket wallet list` to see profiles."
+    ))
+    .into()
+}
+
+/// Reject profile names that aren't plain words: letters, digits, `-`, `_`
+/// and `.`, not starting with `.`.
+pub(crate) fn validate_profile_name(name: &str) -> Result<()> {
+    let valid = !name.is_empty()
+        && name.len() <= 64
+        && !name.starts_with('.')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    anyhow::ensure!(
+        valid,
+        CliError::invalid_argument(format!(
+            "Invalid profile name `{name}`: use letters, digits, `-`, `_` and `.`"
+        ))
+    );
+    Ok(())
+}
+
+/// Name of the profile in use, even when there is no config file yet.
+pub fn profile_name() -> Result<String> {
+    Ok(match load_config()? {
+        Some(config) => config.profile_name(),
+        None => requested_profile().unwrap_or_else(default_profile_name),
+    })
+}
+
+/// The profile in use, if the config file has one.
+pub fn load_profile() -> Result<Option<Profile>> {
+    match load_config()? {
+        Some(config) => Ok(config.profile()?.cloned()),
+        None => match requested_profile() {
+            Some(name) => Err(profile_not_found(&name)),
+            None => Ok(None),
+        },
+    }
+}
+
+/// Top-level `display` settings, overridden by those of the profile in use.
+pub fn load_display_config() -> Result<DisplayConfig> {
+    let Some(config) = load_config()? else {
+        return Ok(DisplayConfig::default());
+    };
+    let profile = config
+        .profiles
+        .get(&config.profile_name())
+        .map(|p| p.display.clone())
+        .unwrap_or_default();
+    Ok(profile.or(config.display))
+}
+
+/// Top-level `settings`, overridden by those of the profile in use.
+pub fn load_settings() -> Result<Settings> {
+    let Some(config) = load_config()? else {
+        return Ok(Settings::default());
+    };
+    let profile = config
+        .profiles
+        .get(&config.profile_name())
+        .map(|p| p.settings.clone())
+        .unwrap_or_default();
+    Ok(profile.or(config.settings))
+}
+
+/// Settings of the command currently running, set once by `main::run`.
+static SETTINGS: Mutex<Option<Settings>> = Mutex::new(None);
+
+pub(crate) fn set_settings(settings: Settings) {
+    *SETTINGS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(settings);
+}
+
+pub(crate) fn settings() -> Settings {
+    SETTINGS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone()
+        .unwrap_or_default()
+}
+
+/// Priority: `--chain` > `POLYMARKET_CHAIN` > the profile's `chain_id` > Polygon.
+pub(crate) fn resolve_chain(flag: Option<Chain>) -> Result<Chain> {
+    if let Some(chain) = flag {
+        return Ok(chain);
+    }
+    if let Ok(name) = std::env::var(CHAIN_ENV_VAR)
+        && !name.is_empty()
+    {
+        return <Chain as clap::ValueEnum>::from_str(&name, true).map_err(|_| {
+            CliError::invalid_argument(format!(
+                "Invalid {CHAIN_ENV_VAR} `{name}`: expected polygon or amoy"
+            ))
+            .into()
+        });
+    }
+    let Some(config) = load_config()? else {
+        return Ok(Chain::default());
+    };
+    let name = config.profile_name();
+    match config.profiles.get(&name) {
+        Some(profile) => Chain::from_id(profile.chain_id).ok_or_else(|| {
+            CliError::invalid_argument(format!(
+                "Profile `{name}` has unsupported chain_id {}; use {POLYGON} (polygon) or {AMOY} (amoy)",
+                profile.chain_id
+            ))
+            .into()
+        }),
+        None => Ok(Chain::default()),
+    }
+}
+
+/// Chain of the command currently running, set once by `main::run`.
+static CHAIN: Mutex<Option<Chain>> = Mutex::new(None);
+
+pub(crate) fn set_chain(chain: Chain) {
+    *CHAIN
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(chain);
+}
+
+pub(crate) fn chain() -> Chain {
+    CHAIN
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .unwrap_or_default()
+}
+
+/// Page size of list commands run without `--limit`.
+pub(crate) fn page_size() -> i32 {
+    settings().limit.unwrap_or(DEFAULT_LIMIT)
+}
+
+/// Priority: CLI flag > env var > profile > default ("proxy").
+pub fn resolve_signature_type(cli_flag: Option<SignatureType>) -> Result<SignatureType> {
     if let Some(st) = cli_flag {
-        return Ok(st.to_string());
+        return Ok(st);
     }
     if let Ok(st) = std::env::var(SIG_TYPE_ENV_VAR)
         && !st.is_empty()
     {
-        return Ok(st);
+        return <SignatureType as clap::ValueEnum>::from_str(&st, true).map_err(|_| {
+            CliError::invalid_argument(format!(
+                "Invalid {SIG_TYPE_ENV_VAR} `{st}`: expected eoa, proxy or gnosis-safe"
+            ))
+            .into()
+        });
     }
-    if let Some(config) = load_config()? {
-        return Ok(config.signature_type);
+    if let Some(profile) = load_profile()? {
+        return Ok(profile.signature_type);
     }
-    Ok(DEFAULT_SIGNATURE_TYPE.to_string())
+    Ok(SignatureType::default())
 }
 
-pub fn save_wallet(key: &str, chain_id: u64, signature_type: &str) -> Result<()> {
+/// Write `config` to disk, readable only by the current user.
+pub fn save_config(config: &Config) -> Result<()> {
     let dir = config_dir()?;
     fs::create_dir_all(&dir).context("Failed to create config directory")?;
 
@@ -104,12 +684,7 @@ pub fn save_wallet(key: &str, chain_id: u64, signature_type: &str) -> Result<()>
         fs::set_permissions(&dir, fs::Permissions::from_mode(0o700))?;
     }
 
-    let config = Config {
-        private_key: key.to_string(),
-        chain_id,
-        signature_type: signature_type.to_string(),
-    };
-    let json = serde_json::to_string_pretty(&config)?;
+    let json = serde_json::to_string_pretty(config)?;
     let path = config_path()?;
 
     #[cfg(unix)]
@@ -135,7 +710,20 @@ pub fn save_wallet(key: &str, chain_id: u64, signature_type: &str) -> Result<()>
     Ok(())
 }
 
-/// Priority: CLI flag > env var > config file.
+/// Save `profile` under `name`, replacing any profile of that name. The
+/// first profile saved becomes the active one.
+pub fn save_profile(name: &str, profile: Profile) -> Result<()> {
+    validate_profile_name(name)?;
+    let mut config = load_config()?.unwrap_or_default();
+    if config.profiles.is_empty() {
+        config.active_profile = name.to_string();
+    }
+    config.profiles.insert(name.to_string(), profile);
+    save_config(&config)
+}
+
+/// Priority: CLI flag > env var > profile. Keystores are not decrypted here,
+/// so their key is `None`; see [`require_key`].
 pub fn resolve_key(cli_flag: Option<&str>) -> Result<(Option<String>, KeySource)> {
     if let Some(key) = cli_flag {
         return Ok((Some(key.to_string()), KeySource::Flag));
@@ -145,10 +733,123 @@ pub fn resolve_key(cli_flag: Option<&str>) -> Result<(Option<String>, KeySource)
     {
         return Ok((Some(key), KeySource::EnvVar));
     }
-    if let Some(config) = load_config()? {
-        return Ok((Some(config.private_key), KeySource::ConfigFile));
+    match load_profile()? {
+        Some(Profile {
+            private_key: Some(key),
+            ..
+        }) => Ok((Some(key), KeySource::ConfigFile)),
+        Some(Profile {
+            keystore: Some(_), ..
+        }) => Ok((None, KeySource::Keystore)),
+        Some(Profile {
+            signer_url: Some(_),
+            ..
+        }) => Ok((None, KeySource::RemoteSigner)),
+        Some(Profile {
+            address: Some(_), ..
+        }) => Ok((None, KeySource::WatchOnly)),
+        _ => Ok((None, KeySource::None)),
+    }
+}
+
+/// The private key to sign with, or an auth error saying why there is none.
+pub fn require_key(cli_flag: Option<&str>) -> Result<String> {
+    match resolve_key(cli_flag)? {
+        (Some(key), _) => Ok(key),
+        (None, KeySource::Keystore) => {
+            let profile = load_profile()?.expect("keystore source implies a profile");
+            crate::keystore::unlock(&profile)
+        }
+        (None, KeySource::RemoteSigner) => Err(CliError::auth(format!(
+            "Profile `{}` signs with a remote signer; its key isn't available here.",
+            profile_name()?
+        ))
+        .into()),
+        (None, KeySource::WatchOnly) => Err(CliError::auth(format!(
+            "Profile `{}` is watch-only and can't sign. Use --profile to pick a profile with a key.",
+            profile_name()?
+        ))
+        .into()),
+        (None, _) => Err(CliError::auth(NO_WALLET_MSG).into()),
+    }
+}
+
+/// Address of the wallet in use, without decrypting a keystore.
+pub fn resolve_address(cli_flag: Option<&str>) -> Result<(Option<Address>, KeySource)> {
+    let (key, source) = resolve_key(cli_flag)?;
+    if let Some(key) = key {
+        let signer = LocalSigner::from_str(&key)
+            .context(CliError::invalid_argument("Invalid private key"))?;
+        return Ok((Some(signer.address()), source));
+    }
+    let address = match source {
+        KeySource::Keystore | KeySource::RemoteSigner | KeySource::WatchOnly => {
+            load_profile()?.and_then(|p| p.address)
+        }
+        _ => None,
+    };
+    Ok((address, source))
+}
+
+/// The proxy or Safe wallet of `eoa` for `signature_type`, if the chain has
+/// a factory for it; `None` for `eoa`.
+pub fn derived_wallet(eoa: Address, signature_type: SignatureType) -> Option<Address> {
+    match signature_type {
+        SignatureType::Proxy => derive_proxy_wallet(eoa, chain().id()),
+        SignatureType::GnosisSafe => derive_safe_wallet(eoa, chain().id()),
+        SignatureType::Eoa => None,
     }
-    Ok((None, KeySource::None))
+}
+
+static FUNDER_FLAG: Mutex<Option<Address>> = Mutex::new(None);
+
+pub(crate) fn set_funder_flag(funder: Option<Address>) {
+    *FUNDER_FLAG
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = funder;
+}
+
+/// Priority: `--funder` > `POLYMARKET_FUNDER` > the profile's `funder`.
+/// `None` means the funder is derived from the signature type.
+pub fn funder() -> Result<Option<Address>> {
+    let flag = *FUNDER_FLAG
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if flag.is_some() {
+        return Ok(flag);
+    }
+    if let Ok(funder) = std::env::var(FUNDER_ENV_VAR)
+        && !funder.is_empty()
+    {
+        return Address::from_str(&funder).map(Some).map_err(|_| {
+            CliError::invalid_argument(format!("Invalid {FUNDER_ENV_VAR} `{funder}`")).into()
+        });
+    }
+    Ok(load_profile()?.and_then(|p| p.funder))
+}
+
+/// Address holding the funds of the wallet in use: the funder, else the
+/// proxy or Safe wallet of the signature type, else the EOA.
+pub fn resolve_funder(
+    cli_flag: Option<&str>,
+    signature_type_flag: Option<SignatureType>,
+) -> Result<Address> {
+    let (eoa, _) = resolve_address(cli_flag)?;
+    let eoa = eoa.ok_or_else(|| CliError::auth(NO_WALLET_MSG))?;
+    if let Some(funder) = funder()? {
+        return Ok(funder);
+    }
+    let signature_type = resolve_signature_type(signature_type_flag)?;
+    if signature_type == SignatureType::Eoa {
+        return Ok(eoa);
+    }
+    derived_wallet(eoa, signature_type).ok_or_else(|| {
+        CliError::invalid_argument(format!(
+            "No {signature_type} wallet on {}; use --signature-type eoa",
+            chain().name()
+        ))
+        .into()
+    })
 }
 
 #[cfg(test)]
@@ -201,8 +902,8 @@ mod tests {
         let _lock = ENV_LOCK.lock().unwrap();
         unsafe { set(SIG_TYPE_ENV_VAR, "eoa") };
         assert_eq!(
-            resolve_signature_type(Some("gnosis-safe")).unwrap(),
-            "gnosis-safe"
+            resolve_signature_type(Some(SignatureType::GnosisSafe)).unwrap(),
+            SignatureType::GnosisSafe
         );
         unsafe { unset(SIG_TYPE_ENV_VAR) };
     }
@@ -211,15 +912,132 @@ mod tests {
     fn resolve_sig_type_env_var_returns_env_value() {
         let _lock = ENV_LOCK.lock().unwrap();
         unsafe { set(SIG_TYPE_ENV_VAR, "eoa") };
-        assert_eq!(resolve_signature_type(None).unwrap(), "eoa");
+        assert_eq!(resolve_signature_type(None).unwrap(), SignatureType::Eoa);
         unsafe { unset(SIG_TYPE_ENV_VAR) };
     }
 
     #[test]
-    fn resolve_sig_type_without_env_returns_nonempty() {
+    fn resolve_sig_type_rejects_unknown_env_value() {
         let _lock = ENV_LOCK.lock().unwrap();
+        unsafe { set(SIG_TYPE_ENV_VAR, "gnosis_safe") };
+        assert!(resolve_signature_type(None).is_err());
         unsafe { unset(SIG_TYPE_ENV_VAR) };
-        let result = resolve_signature_type(None).unwrap();
-        assert!(!result.is_empty());
+    }
+
+    #[test]
+    fn unknown_signature_type_in_config_is_an_error() {
+        assert!(parse_config(r#"{"private_key": "0xabc", "signature_type": "safe"}"#).is_err());
+    }
+
+    #[test]
+    fn legacy_config_migrates_to_default_profile() {
+        let config =
+            parse_config(r#"{"private_key": "0xabc", "chain_id": 137, "signature_type": "eoa"}"#)
+                .unwrap();
+        assert_eq!(config.active_profile, DEFAULT_PROFILE);
+        let profile = &config.profiles[DEFAULT_PROFILE];
+        assert_eq!(profile.private_key.as_deref(), Some("0xabc"));
+        assert_eq!(profile.signature_type, SignatureType::Eoa);
+        assert_eq!(profile.chain_id, 137);
+    }
+
+    #[test]
+    fn legacy_config_keeps_display_settings() {
+        let config = parse_config(
+            r#"{"private_key": "0xabc", "chain_id": 137, "display": {"numbers": "full"}}"#,
+        )
+        .unwrap();
+        assert_eq!(config.display.numbers, Some(Numbers::Full));
+        assert_eq!(
+            config.profiles[DEFAULT_PROFILE].signature_type,
+            SignatureType::Proxy
+        );
+    }
+
+    #[test]
+    fn profiles_round_trip() {
+        let watched: Address = "0x56687bf447db6ffa42ffe2204a05edaa20f55839"
+            .parse()
+            .unwrap();
+        let config = Config {
+            active_profile: "watch".into(),
+            profiles: BTreeMap::from([
+                (
+                    "main".into(),
+                    Profile::with_key("0xabc".into(), SignatureType::Proxy),
+                ),
+                (
+                    "watch".into(),
+                    Profile::watch_only(watched, SignatureType::Eoa),
+                ),
+            ]),
+            display: DisplayConfig::default(),
+            settings: Settings::default(),
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        assert!(!json.contains("\"display\""));
+        assert!(!json.contains("\"settings\""));
+        let parsed = parse_config(&json).unwrap();
+        assert_eq!(parsed.active_profile, "watch");
+        assert_eq!(parsed.profiles["watch"].address, Some(watched));
+        assert!(parsed.profiles["watch"].private_key.is_none());
+        assert_eq!(
+            parsed.profiles["main"].private_key.as_deref(),
+            Some("0xabc")
+        );
+    }
+
+    #[test]
+    fn profile_flag_overrides_env_and_active_profile() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        let config = Config {
+            active_profile: "main".into(),
+            ..Config::default()
+        };
+        unsafe { unset(PROFILE_ENV_VAR) };
+        assert_eq!(config.profile_name(), "main");
+        unsafe { set(PROFILE_ENV_VAR, "from-env") };
+        assert_eq!(config.profile_name(), "from-env");
+        set_profile_flag(Some("from-flag".into()));
+        assert_eq!(config.profile_name(), "from-flag");
+        set_profile_flag(None);
+        unsafe { unset(PROFILE_ENV_VAR) };
+    }
+
+    #[test]
+    fn missing_profile_is_an_error_only_when_requested() {
+        let _lock = ENV_LOCK.lock().unwrap();
+        unsafe { unset(PROFILE_ENV_VAR) };
+        let config = Config::default();
+        assert!(config.profile().unwrap().is_none());
+        set_profile_flag(Some("nope".into()));
+        assert!(config.profile().is_err());
+        set_profile_flag(None);
+    }
+
+    #[test]
+    fn profile_display_overrides_top_level() {
+        let profile = DisplayConfig {
+            numbers: Some(Numbers::Full),
+            ..DisplayConfig::default()
+        };
+        let top = DisplayConfig {
+            numbers: Some(Numbers::Abbreviated),
+            price_style: Some(PriceStyle::American),
+            ..DisplayConfig::default()
+        };
+        let merged = profile.or(top);
+        assert_eq!(merged.numbers, Some(Numbers::Full));
+        assert_eq!(merged.price_style, Some(PriceStyle::American));
+    }
+
+    #[test]
+    fn chains_map_to_sdk_contracts() {
+        for chain in [Chain::Polygon, Chain::Amoy] {
+            assert_eq!(Chain::from_id(chain.id()), Some(chain));
+            assert_eq!(chain.usdc(), chain.contracts(true).collateral);
+        }
+        assert_ne!(Chain::Polygon.usdc(), Chain::Amoy.usdc());
+        assert_eq!(Chain::from_id(1), None);
     }
 }
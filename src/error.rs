@@ -0,0 +1,329 @@
+use std::fmt;
+
+use polymarket_client_sdk::error::{Error as SdkError, Geoblock, Kind, Status};
+
+/// Failure categories reported to scripts. Each maps to a stable exit code and
+/// to the `code` field of the JSON error object. Don't renumber existing codes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ErrorCode {
+    General,
+    InvalidArgument,
+    Auth,
+    Geoblocked,
+    InsufficientBalance,
+    OrderRejected,
+    Network,
+    ChainRevert,
+    NotFound,
+    RateLimited,
+    Upstream,
+}
+
+impl ErrorCode {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::General => "general",
+            Self::InvalidArgument => "invalid_argument",
+            Self::Auth => "auth",
+            Self::Geoblocked => "geoblocked",
+            Self::InsufficientBalance => "insufficient_balance",
+            Self::OrderRejected => "order_rejected",
+            Self::Network => "network",
+            Self::ChainRevert => "chain_revert",
+            Self::NotFound => "not_found",
+            Self::RateLimited => "rate_limited",
+            Self::Upstream => "upstream",
+        }
+    }
+
+    /// Process exit code. 2 matches clap's exit code for usage errors.
+    pub(crate) fn exit_code(self) -> u8 {
+        match self {
+            Self::General => 1,
+            Self::InvalidArgument => 2,
+            Self::Auth => 3,
+            Self::Geoblocked => 4,
+            Self::InsufficientBalance => 5,
+            Self::OrderRejected => 6,
+            Self::Network => 7,
+            Self::ChainRevert => 8,
+            Self::NotFound => 9,
+            Self::RateLimited => 10,
+            Self::Upstream => 11,
+        }
+    }
+
+    pub(crate) fn retryable(self) -> bool {
+        matches!(self, Self::Network | Self::RateLimited | Self::Upstream)
+    }
+}
+
+/// An error the CLI raises itself with a known category. Works as a root error
+/// (`?`, `ensure!`) or as `.context(...)` on an underlying error.
+#[derive(Debug)]
+pub(crate) struct CliError {
+    code: ErrorCode,
+    message: String,
+}
+
+impl CliError {
+    pub(crate) fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+
+    pub(crate) fn invalid_argument(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::InvalidArgument, message)
+    }
+
+    pub(crate) fn auth(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::Auth, message)
+    }
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for CliError {}
+
+/// A classified error, as printed by `output::print_error`.
+pub(crate) struct ErrorReport {
+    pub code: ErrorCode,
+    pub message: String,
+    pub status: Option<u16>,
+    pub details: Option<serde_json::Value>,
+}
+
+impl ErrorReport {
+    pub(crate) fn new(error: &anyhow::Error) -> Self {
+        let mut report = Self {
+            code: ErrorCode::General,
+            message: error.to_string(),
+            status: None,
+            details: None,
+        };
+
+        if let Some(e) = error.downcast_ref::<CliError>() {
+            report.code = e.code;
+            return report;
+        }
+
+        for cause in error.chain() {
+            if let Some(code) = report.classify(cause) {
+                report.code = code;
+                return report;
+            }
+        }
+
+        let full = format!("{error:#}").to_lowercase();
+        if is_balance_message(&full) {
+            report.code = ErrorCode::InsufficientBalance;
+        }
+        report
+    }
+
+    fn classify(&mut self, cause: &(dyn std::error::Error + 'static)) -> Option<ErrorCode> {
+        if let Some(status) = cause.downcast_ref::<Status>() {
+            self.status = Some(status.status_code.as_u16());
+            self.details = Some(serde_json::json!({
+                "method": status.method.as_str(),
+                "path": status.path,
+                "body": status.message,
+            }));
+            return Some(status_code(status));
+        }
+        if let Some(geo) = cause.downcast_ref::<Geoblock>() {
+            self.details = Some(serde_json::json!({
+                "country": geo.country,
+                "region": geo.region,
+            }));
+            return Some(ErrorCode::Geoblocked);
+        }
+        if let Some(e) = cause.downcast_ref::<SdkError>() {
+            match e.kind() {
+                Kind::Geoblock => return Some(ErrorCode::Geoblocked),
+                Kind::Validation => return Some(ErrorCode::InvalidArgument),
+                _ => {}
+            }
+        }
+        if let Some(e) = cause.downcast_ref::<reqwest::Error>() {
+            if e.is_timeout() || e.is_connect() || e.is_request() {
+                return Some(ErrorCode::Network);
+            }
+            if let Some(status) = e.status() {
+                self.status = Some(status.as_u16());
+            }
+        }
+        if let Some(e) = cause.downcast_ref::<alloy::contract::Error>() {
+            return match e {
+                alloy::contract::Error::TransportError(e) => Some(self.classify_rpc(e)),
+                alloy::contract::Error::PendingTransactionError(_) => Some(ErrorCode::Network),
+                _ => None,
+            };
+        }
+        if let Some(e) = cause.downcast_ref::<alloy::transports::TransportError>() {
+            return Some(self.classify_rpc(e));
+        }
+        if cause
+            .downcast_ref::<alloy::providers::PendingTransactionError>()
+            .is_some()
+        {
+            return Some(ErrorCode::Network);
+        }
+        None
+    }
+
+    fn classify_rpc(&mut self, error: &alloy::transports::TransportError) -> ErrorCode {
+        use alloy::transports::RpcError;
+
+        match error {
+            RpcError::ErrorResp(payload) => {
+                self.details = Some(serde_json::json!({
+                    "rpc_code": payload.code,
+                    "rpc_message": payload.message,
+                    "data": payload.data.as_ref().map(|d| d.get()),
+                }));
+                let message = payload.message.to_lowercase();
+                if message.contains("insufficient funds") || is_balance_message(&message) {
+                    ErrorCode::InsufficientBalance
+                } else if payload.code == 3 || message.contains("revert") {
+                    ErrorCode::ChainRevert
+                } else {
+                    ErrorCode::Upstream
+                }
+            }
+            RpcError::Transport(_) | RpcError::NullResp => ErrorCode::Network,
+            _ => ErrorCode::General,
+        }
+    }
+
+    pub(crate) fn to_json(&self) -> serde_json::Value {
+        let mut obj = serde_json::json!({
+            "code": self.code.as_str(),
+            "exit_code": self.code.exit_code(),
+            "message": self.message,
+            "retryable": self.code.retryable(),
+        });
+        if let Some(status) = self.status {
+            obj["status"] = status.into();
+        }
+        if let Some(details) = &self.details {
+            obj["details"] = details.clone();
+        }
+        serde_json::json!({ "error": obj })
+    }
+}
+
+fn status_code(status: &Status) -> ErrorCode {
+    let code = status.status_code.as_u16();
+    match code {
+        401 | 403 => ErrorCode::Auth,
+        404 => ErrorCode::NotFound,
+        429 => ErrorCode::RateLimited,
+        500.. => ErrorCode::Upstream,
+        _ if is_balance_message(&status.message.to_lowercase()) => ErrorCode::InsufficientBalance,
+        _ if status.path.contains("order") => ErrorCode::OrderRejected,
+        _ => ErrorCode::InvalidArgument,
+    }
+}
+
+fn is_balance_message(message: &str) -> bool {
+    message.contains("not enough balance") || message.contains("insufficient balance")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context as _;
+    use polymarket_client_sdk::error::{Method, StatusCode};
+
+    fn status_error(code: u16, path: &str, body: &str) -> anyhow::Error {
+        SdkError::status(
+            StatusCode::from_u16(code).unwrap(),
+            Method::POST,
+            path.to_string(),
+            body,
+        )
+        .into()
+    }
+
+    #[test]
+    fn untagged_error_is_general() {
+        let report = ErrorReport::new(&anyhow::anyhow!("boom"));
+        assert_eq!(report.code, ErrorCode::General);
+        assert_eq!(report.code.exit_code(), 1);
+    }
+
+    #[test]
+    fn cli_error_as_context_sets_code() {
+        let err = Err::<(), _>(anyhow::anyhow!("bad hex"))
+            .context(CliError::auth("Invalid private key"))
+            .unwrap_err();
+        let report = ErrorReport::new(&err);
+        assert_eq!(report.code, ErrorCode::Auth);
+        assert_eq!(report.message, "Invalid private key");
+    }
+
+    #[test]
+    fn status_codes_map_to_categories() {
+        let cases = [
+            (401, "/auth/api-key", "", ErrorCode::Auth),
+            (404, "/markets/slug/x", "", ErrorCode::NotFound),
+            (429, "/book", "", ErrorCode::RateLimited),
+            (502, "/book", "", ErrorCode::Upstream),
+            (400, "/order", "invalid tick size", ErrorCode::OrderRejected),
+            (
+                400,
+                "/order",
+                "not enough balance / allowance",
+                ErrorCode::InsufficientBalance,
+            ),
+            (400, "/markets", "bad limit", ErrorCode::InvalidArgument),
+        ];
+        for (status, path, body, expected) in cases {
+            let report = ErrorReport::new(&status_error(status, path, body));
+            assert_eq!(report.code, expected, "{status} {path}");
+            assert_eq!(report.status, Some(status));
+        }
+    }
+
+    #[test]
+    fn status_error_survives_context() {
+        let err = Err::<(), _>(status_error(404, "/markets", ""))
+            .context("Failed to fetch market")
+            .unwrap_err();
+        assert_eq!(ErrorReport::new(&err).code, ErrorCode::NotFound);
+    }
+
+    #[test]
+    fn geoblock_is_classified() {
+        let err: anyhow::Error =
+            SdkError::with_source(Kind::Geoblock, std::io::Error::other("blocked")).into();
+        assert_eq!(ErrorReport::new(&err).code, ErrorCode::Geoblocked);
+    }
+
+    #[test]
+    fn json_envelope_has_stable_fields() {
+        let report = ErrorReport::new(&status_error(503, "/book", "down"));
+        let json = report.to_json();
+        let err = &json["error"];
+        assert_eq!(err["code"], "upstream");
+        assert_eq!(err["exit_code"], 11);
+        assert_eq!(err["retryable"], true);
+        assert_eq!(err["status"], 503);
+        assert_eq!(err["details"]["path"], "/book");
+        assert!(err["message"].is_string());
+    }
+
+    #[test]
+    fn retryable_only_for_transient_failures() {
+        assert!(ErrorCode::Network.retryable());
+        assert!(!ErrorCode::OrderRejected.retryable());
+        assert!(!ErrorCode::InvalidArgument.retryable());
+    }
+}
@@ -0,0 +1,225 @@
+//! Local record of the transactions sent by `approve`, `ctf` and `wallet send`.
+//!
+//! The journal lives in `~/.config/polymarket/transactions.json`, so a
+//! transaction that never confirms can be found again with `tx list` and
+//! replaced with `tx speedup` or `tx cancel`.
+
+use std::fs;
+use std::path::PathBuf;
+
+use alloy::primitives::{Address, B256, Bytes, U256};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::config;
+
+/// Older entries are dropped once the journal holds this many.
+const MAX_ENTRIES: usize = 500;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Status {
+    /// Sent and not yet mined.
+    Pending,
+    Confirmed,
+    Reverted,
+    /// Another transaction with the same nonce was mined instead.
+    Replaced,
+}
+
+impl Status {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Confirmed => "confirmed",
+            Self::Reverted => "reverted",
+            Self::Replaced => "replaced",
+        }
+    }
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A transaction as it was sent.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct Entry {
+    pub(crate) hash: B256,
+    pub(crate) chain_id: u64,
+    pub(crate) from: Address,
+    pub(crate) nonce: u64,
+    pub(crate) to: Address,
+    #[serde(default)]
+    pub(crate) value: U256,
+    pub(crate) input: Bytes,
+    pub(crate) gas_limit: u64,
+    pub(crate) max_fee_per_gas: u128,
+    pub(crate) max_priority_fee_per_gas: u128,
+    /// When it was sent; unknown for transactions only the node knows.
+    #[serde(default)]
+    pub(crate) sent_at: Option<DateTime<Utc>>,
+    pub(crate) status: Status,
+    /// The transaction this one was sent to speed up or cancel.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) replaces: Option<B256>,
+}
+
+fn journal_path() -> Result<PathBuf> {
+    Ok(config::config_dir()?.join("transactions.json"))
+}
+
+/// Every recorded transaction, oldest first.
+pub(crate) fn load() -> Result<Vec<Entry>> {
+    let path = journal_path()?;
+    let data = match fs::read_to_string(&path) {
+        Ok(d) => d,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => {
+            return Err(anyhow::anyhow!(e).context(format!("Failed to read {}", path.display())));
+        }
+    };
+    serde_json::from_str(&data).context(format!("Failed to parse {}", path.display()))
+}
+
+/// Overwrite the journal with `entries`, keeping the latest [`MAX_ENTRIES`].
+pub(crate) fn save(entries: &[Entry]) -> Result<()> {
+    let dir = config::config_dir()?;
+    fs::create_dir_all(&dir).context("Failed to create config directory")?;
+    let skip = entries.len().saturating_sub(MAX_ENTRIES);
+    let json = serde_json::to_string_pretty(&entries[skip..])?;
+    let path = journal_path()?;
+    // Written beside the journal and renamed over it, so a crash never
+    // leaves it truncated.
+    let tmp = path.with_extension("json.tmp");
+
+    #[cfg(unix)]
+    {
+        use std::io::Write as _;
+        use std::os::unix::fs::OpenOptionsExt;
+        let mut file = fs::OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .mode(0o600)
+            .open(&tmp)
+            .context(format!("Failed to create {}", tmp.display()))?;
+        file.write_all(json.as_bytes())
+            .context(format!("Failed to write {}", tmp.display()))?;
+    }
+
+    #[cfg(not(unix))]
+    {
+        fs::write(&tmp, &json).context(format!("Failed to write {}", tmp.display()))?;
+    }
+
+    fs::rename(&tmp, &path).context(format!("Failed to write {}", path.display()))
+}
+
+/// Add `entry`, replacing an earlier entry of the same hash.
+pub(crate) fn record(entry: Entry) -> Result<()> {
+    let mut entries = load()?;
+    entries.retain(|e| e.hash != entry.hash);
+    entries.push(entry);
+    save(&entries)
+}
+
+/// Set the status of the transaction `hash`, if it's recorded.
+pub(crate) fn set_status(hash: B256, status: Status) -> Result<()> {
+    let mut entries = load()?;
+    if !update_status(&mut entries, hash, status) {
+        return Ok(());
+    }
+    save(&entries)
+}
+
+/// Apply `status` to `hash` and, if it was mined, mark the other
+/// transactions with its nonce as replaced. Returns whether anything changed.
+pub(crate) fn update_status(entries: &mut [Entry], hash: B256, status: Status) -> bool {
+    let Some(mined) = entries.iter().find(|e| e.hash == hash).cloned() else {
+        return false;
+    };
+    let mut changed = false;
+    for entry in entries.iter_mut() {
+        let new = if entry.hash == hash {
+            status
+        } else if matches!(status, Status::Confirmed | Status::Reverted)
+            && entry.status == Status::Pending
+            && entry.chain_id == mined.chain_id
+            && entry.from == mined.from
+            && entry.nonce == mined.nonce
+        {
+            Status::Replaced
+        } else {
+            continue;
+        };
+        changed |= entry.status != new;
+        entry.status = new;
+    }
+    changed
+}
+
+/// Find the recorded transaction `hash`.
+pub(crate) fn find(hash: B256) -> Result<Option<Entry>> {
+    Ok(load()?.into_iter().find(|e| e.hash == hash))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(hash: u8, nonce: u64) -> Entry {
+        Entry {
+            hash: B256::repeat_byte(hash),
+            chain_id: 137,
+            from: Address::repeat_byte(0x11),
+            nonce,
+            to: Address::repeat_byte(0x22),
+            value: U256::ZERO,
+            input: Bytes::from_static(&[1, 2, 3]),
+            gas_limit: 100_000,
+            max_fee_per_gas: 50_000_000_000,
+            max_priority_fee_per_gas: 30_000_000_000,
+            sent_at: DateTime::from_timestamp(1_700_000_000, 0),
+            status: Status::Pending,
+            replaces: None,
+        }
+    }
+
+    #[test]
+    fn entries_round_trip_through_json() {
+        let mut original = entry(0xaa, 7);
+        original.replaces = Some(B256::repeat_byte(0xbb));
+        let json = serde_json::to_string(&original).unwrap();
+        assert!(json.contains("\"status\":\"pending\""));
+        assert_eq!(serde_json::from_str::<Entry>(&json).unwrap(), original);
+    }
+
+    #[test]
+    fn mined_transaction_replaces_others_with_its_nonce() {
+        let mut entries = vec![entry(0xaa, 7), entry(0xbb, 7), entry(0xcc, 8)];
+        assert!(update_status(
+            &mut entries,
+            B256::repeat_byte(0xbb),
+            Status::Confirmed
+        ));
+        let statuses: Vec<_> = entries.iter().map(|e| e.status).collect();
+        assert_eq!(
+            statuses,
+            [Status::Replaced, Status::Confirmed, Status::Pending]
+        );
+        assert!(!update_status(
+            &mut entries,
+            B256::repeat_byte(0xbb),
+            Status::Confirmed
+        ));
+        assert!(!update_status(
+            &mut entries,
+            B256::repeat_byte(0xdd),
+            Status::Confirmed
+        ));
+    }
+}
@@ -0,0 +1,233 @@
+//! Private keys encrypted in Ethereum keystore v3 files (scrypt + AES-128-CTR).
+//!
+//! Keystores live in `~/.config/polymarket/keystores/`, named after the
+//! address of their key and never overwritten. The
+//! password comes from `POLYMARKET_PASSWORD`, the profile's `password_command`,
+//! or a prompt on the terminal.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use alloy::primitives::Address;
+use alloy::signers::local::{LocalSignerError, PrivateKeySigner};
+use anyhow::{Context, Result, bail};
+use eth_keystore::KeystoreError;
+
+use crate::config::{self, Profile, SignatureType};
+use crate::error::CliError;
+
+const PASSWORD_ENV_VAR: &str = "POLYMARKET_PASSWORD";
+
+/// Keys unlocked by this process, so the shell only asks once per keystore.
+static UNLOCKED: Mutex<Option<HashMap<PathBuf, String>>> = Mutex::new(None);
+
+pub fn keystore_dir() -> Result<PathBuf> {
+    Ok(config::config_dir()?.join("keystores"))
+}
+
+/// Run `command` through the shell and return its first line of output.
+fn run_password_command(command: &str) -> Result<String> {
+    #[cfg(unix)]
+    let output = std::process::Command::new("sh")
+        .args(["-c", command])
+        .output();
+    #[cfg(not(unix))]
+    let output = std::process::Command::new("cmd")
+        .args(["/C", command])
+        .output();
+    let output = output.context(format!("Failed to run password command `{command}`"))?;
+    if !output.status.success() {
+        bail!(CliError::auth(format!(
+            "Password command `{command}` failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    let stdout =
+        String::from_utf8(output.stdout).context("Password command printed invalid UTF-8")?;
+    Ok(stdout.lines().next().unwrap_or_default().to_string())
+}
+
+fn env_password() -> Option<String> {
+    std::env::var(PASSWORD_ENV_VAR)
+        .ok()
+        .filter(|p| !p.is_empty())
+}
+
+/// Password to unlock an existing keystore.
+fn unlock_password(password_command: Option<&str>) -> Result<String> {
+    if let Some(password) = env_password() {
+        return Ok(password);
+    }
+    if let Some(command) = password_command {
+        return run_password_command(command);
+    }
+    rpassword::prompt_password("Keystore password: ").context(CliError::auth(format!(
+        "No keystore password: set {PASSWORD_ENV_VAR} or a password_command"
+    )))
+}
+
+/// Password for a new keystore, asked twice when prompting.
+pub fn new_password(password_command: Option<&str>) -> Result<String> {
+    if let Some(password) = env_password() {
+        return Ok(password);
+    }
+    if let Some(command) = password_command {
+        return run_password_command(command);
+    }
+    let no_password = || {
+        CliError::auth(format!(
+            "No keystore password: set {PASSWORD_ENV_VAR}, use --password-command, or --plaintext"
+        ))
+    };
+    let password = rpassword::prompt_password("New keystore password: ").context(no_password())?;
+    if password.is_empty() {
+        bail!(CliError::invalid_argument("Password must not be empty"));
+    }
+    let confirm = rpassword::prompt_password("Repeat password: ").context(no_password())?;
+    if password != confirm {
+        bail!(CliError::invalid_argument("Passwords don't match"));
+    }
+    Ok(password)
+}
+
+/// The first free file name for a keystore of `address` in `dir`:
+/// `<address>.json`, then `<address>-2.json` and so on, so another
+/// profile's keystore is never overwritten.
+fn free_name(dir: &Path, address: Address) -> String {
+    let base = format!("{address:#x}");
+    std::iter::once(format!("{base}.json"))
+        .chain((2..).map(|n| format!("{base}-{n}.json")))
+        .find(|name| !dir.join(name).exists())
+        .expect("unbounded")
+}
+
+/// Encrypt `signer`'s key into a new file in `keystores/` and return its path.
+fn encrypt(signer: &PrivateKeySigner, password: &str) -> Result<PathBuf> {
+    let dir = keystore_dir()?;
+    std::fs::create_dir_all(&dir).context("Failed to create keystore directory")?;
+    let name = free_name(&dir, signer.address());
+    PrivateKeySigner::encrypt_keystore(
+        &dir,
+        &mut rand::thread_rng(),
+        signer.to_bytes(),
+        password,
+        Some(&name),
+    )
+    .context("Failed to encrypt keystore")?;
+    let path = dir.join(name);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600))?;
+    }
+
+    Ok(path)
+}
+
+fn decrypt(path: &Path, password: &str) -> Result<String> {
+    let signer = PrivateKeySigner::decrypt_keystore(path, password).map_err(|e| match e {
+        LocalSignerError::EthKeystoreError(KeystoreError::MacMismatch) => {
+            anyhow::Error::from(CliError::auth("Wrong keystore password"))
+        }
+        e => anyhow::Error::new(e).context(format!("Failed to read keystore {}", path.display())),
+    })?;
+    Ok(format!("{:#x}", signer.to_bytes()))
+}
+
+/// A profile for `signer`'s key: encrypted into a new keystore, or kept in
+/// `config.json` with `plaintext`.
+pub fn key_profile(
+    signer: &PrivateKeySigner,
+    signature_type: SignatureType,
+    plaintext: bool,
+    password_command: Option<String>,
+) -> Result<Profile> {
+    if plaintext {
+        return Ok(Profile::with_key(
+            format!("{:#x}", signer.to_bytes()),
+            signature_type,
+        ));
+    }
+    let password = new_password(password_command.as_deref())?;
+    let path = encrypt(signer, &password)?;
+    Ok(Profile::in_keystore(
+        path,
+        signer.address(),
+        password_command,
+        signature_type,
+    ))
+}
+
+/// Decrypt the key of a keystore profile, asking for the password at most
+/// once per process.
+pub fn unlock(profile: &Profile) -> Result<String> {
+    let path = profile
+        .keystore
+        .as_deref()
+        .context("Profile has no keystore")?;
+    let mut unlocked = UNLOCKED
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let unlocked = unlocked.get_or_insert_with(HashMap::new);
+    if let Some(key) = unlocked.get(path) {
+        return Ok(key.clone());
+    }
+    let key = decrypt(path, &unlock_password(profile.password_command.as_deref())?)?;
+    unlocked.insert(path.to_path_buf(), key.clone());
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keystore_round_trips_key() {
+        let dir = std::env::temp_dir().join("polymarket-cli-keystore-test");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let signer = PrivateKeySigner::random();
+        PrivateKeySigner::encrypt_keystore(
+            &dir,
+            &mut rand::thread_rng(),
+            signer.to_bytes(),
+            "hunter2",
+            Some("test.json"),
+        )
+        .unwrap();
+        let path = dir.join("test.json");
+
+        let key = decrypt(&path, "hunter2").unwrap();
+        assert_eq!(key, format!("{:#x}", signer.to_bytes()));
+
+        let err = decrypt(&path, "wrong").unwrap_err();
+        assert_eq!(
+            crate::error::ErrorReport::new(&err).code,
+            crate::error::ErrorCode::Auth
+        );
+    }
+
+    #[test]
+    fn keystore_names_skip_existing_files() {
+        let dir = std::env::temp_dir().join("polymarket-cli-keystore-names");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let address = Address::repeat_byte(0xab);
+        let first = free_name(&dir, address);
+        assert_eq!(first, format!("{address:#x}.json"));
+        std::fs::write(dir.join(&first), "{}").unwrap();
+        assert_eq!(free_name(&dir, address), format!("{address:#x}-2.json"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn password_command_uses_first_line() {
+        assert_eq!(
+            run_password_command("printf 'secret\\nignored'").unwrap(),
+            "secret"
+        );
+        assert!(run_password_command("exit 1").is_err());
+    }
+}
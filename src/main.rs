@@ -1,13 +1,22 @@
 mod auth;
 mod commands;
 mod config;
+mod error;
+mod journal;
+mod keystore;
 mod output;
+mod remote_signer;
 mod shell;
+mod tx;
 
+use std::path::PathBuf;
 use std::process::ExitCode;
+use std::time::Duration;
 
+use anyhow::Context;
 use clap::{Parser, Subcommand};
-use output::OutputFormat;
+use error::{CliError, ErrorCode};
+use output::{OutputFlag, OutputFormat};
 
 #[derive(Parser)]
 #[command(name = "polymarket", about = "Polymarket CLI", version)]
@@ -15,9 +24,46 @@ pub(crate) struct Cli {
     #[command(subcommand)]
     command: Commands,
 
-    /// Output format: table or json
-    #[arg(short, long, global = true, default_value = "table")]
-    pub(crate) output: OutputFormat,
+    /// Output format: table, json, or template [default: the `output` setting, or table]
+    #[arg(short, long, global = true)]
+    pub(crate) output: Option<OutputFlag>,
+
+    /// Template rendered per record with `-o template` (e.g. '{{question}} {{best_bid}}')
+    #[arg(long, global = true, conflicts_with = "template_file")]
+    template: Option<String>,
+
+    /// Read the `-o template` template from a file
+    #[arg(long, global = true)]
+    template_file: Option<PathBuf>,
+
+    /// Wrap JSON output as {"schema_version", "data", "next_cursor"}
+    #[arg(long, global = true)]
+    envelope: bool,
+
+    /// Timezone for dates in tables: utc, local, or an offset like +02:00
+    #[arg(long, global = true)]
+    timezone: Option<output::display::Timezone>,
+
+    /// Dollar amounts in tables: abbreviated ($1.5M) or full ($1,500,000.00)
+    #[arg(long, global = true)]
+    numbers: Option<output::display::Numbers>,
+
+    /// Prices in tables: probability, cents, or decimal, american or fractional odds
+    #[arg(long, global = true)]
+    price_style: Option<output::display::PriceStyle>,
+
+    /// Wallet profile to use (overrides POLYMARKET_PROFILE and `wallet use`)
+    #[arg(long, global = true)]
+    profile: Option<String>,
+
+    /// Network: polygon or amoy (overrides POLYMARKET_CHAIN and the profile's chain)
+    #[arg(long, global = true)]
+    chain: Option<config::Chain>,
+
+    /// Address holding the funds, for a proxy or Safe not derived from the key
+    /// (overrides POLYMARKET_FUNDER and the profile's funder)
+    #[arg(long, global = true)]
+    funder: Option<polymarket_client_sdk::types::Address>,
 
     /// Private key (overrides env var and config file)
     #[arg(long, global = true)]
@@ -25,7 +71,27 @@ pub(crate) struct Cli {
 
     /// Signature type: eoa, proxy, or gnosis-safe
     #[arg(long, global = true)]
-    signature_type: Option<String>,
+    signature_type: Option<config::SignatureType>,
+
+    /// Polygon JSON-RPC endpoint (overrides POLYMARKET_RPC_URL and `rpc_url`)
+    #[arg(long, global = true)]
+    rpc_url: Option<String>,
+
+    /// Gamma API base URL (overrides POLYMARKET_GAMMA_URL and `gamma_url`)
+    #[arg(long, global = true)]
+    gamma_url: Option<String>,
+
+    /// CLOB API base URL (overrides POLYMARKET_CLOB_URL and `clob_url`)
+    #[arg(long, global = true)]
+    clob_url: Option<String>,
+
+    /// Data API base URL (overrides POLYMARKET_DATA_URL and `data_url`)
+    #[arg(long, global = true)]
+    data_url: Option<String>,
+
+    /// Bridge API base URL (overrides POLYMARKET_BRIDGE_URL and `bridge_url`)
+    #[arg(long, global = true)]
+    bridge_url: Option<String>,
 }
 
 #[derive(Subcommand)]
@@ -54,26 +120,59 @@ enum Commands {
     Clob(commands::clob::ClobArgs),
     /// CTF operations: split, merge, redeem positions
     Ctf(commands::ctf::CtfArgs),
+    /// Check on, speed up or cancel transactions sent by approve and ctf
+    Tx(commands::tx::TxArgs),
     /// Query on-chain data (positions, trades, leaderboards)
     Data(commands::data::DataArgs),
     /// Bridge assets from other chains to Polymarket
     Bridge(commands::bridge::BridgeArgs),
     /// Manage wallet and authentication
     Wallet(commands::wallet::WalletArgs),
+    /// Print the JSON Schema of a command's JSON output
+    Schema(commands::schema::SchemaArgs),
+    /// View and change settings (endpoints, defaults)
+    Config(commands::config::ConfigArgs),
     /// Check API health status
     Status,
     /// Update to the latest version
     Upgrade,
 }
 
+impl Cli {
+    /// `--output`, else the `output` setting, else table.
+    pub(crate) fn output_flag(&self) -> OutputFlag {
+        self.output
+            .or_else(|| config::load_settings().ok()?.output)
+            .unwrap_or(OutputFlag::Table)
+    }
+
+    /// Settings in effect: endpoint flags, then `POLYMARKET_*_URL`, then
+    /// the config file.
+    fn settings(&self) -> anyhow::Result<config::Settings> {
+        let flags = config::Settings {
+            rpc_url: self.rpc_url.clone(),
+            gamma_url: self.gamma_url.clone(),
+            clob_url: self.clob_url.clone(),
+            data_url: self.data_url.clone(),
+            bridge_url: self.bridge_url.clone(),
+            ..config::Settings::default()
+        };
+        Ok(flags
+            .or(config::Settings::from_env())
+            .or(config::load_settings()?))
+    }
+}
+
 #[tokio::main]
 async fn main() -> ExitCode {
     let cli = Cli::parse();
-    let output = cli.output;
+    config::set_profile_flag(cli.profile.clone());
+    let output = cli.output_flag();
 
     if let Err(e) = run(cli).await {
-        output::print_error(&e, output);
-        return ExitCode::FAILURE;
+        let report = error::ErrorReport::new(&e);
+        output::print_error(&report, output);
+        return ExitCode::from(report.code.exit_code());
     }
 
     ExitCode::SUCCESS
@@ -81,53 +180,149 @@ async fn main() -> ExitCode {
 
 #[allow(clippy::too_many_lines)]
 pub(crate) async fn run(cli: Cli) -> anyhow::Result<()> {
-    // Lazy-init so we only pay for the client we actually use.
-    let gamma = std::cell::LazyCell::new(polymarket_client_sdk::gamma::Client::default);
-    let data = std::cell::LazyCell::new(polymarket_client_sdk::data::Client::default);
-    let bridge = std::cell::LazyCell::new(polymarket_client_sdk::bridge::Client::default);
-
-    match cli.command {
-        Commands::Setup => commands::setup::execute(),
-        Commands::Shell => Box::pin(shell::run_shell()).await,
-        Commands::Markets(args) => commands::markets::execute(&gamma, args, cli.output).await,
-        Commands::Events(args) => commands::events::execute(&gamma, args, cli.output).await,
-        Commands::Tags(args) => commands::tags::execute(&gamma, args, cli.output).await,
-        Commands::Series(args) => commands::series::execute(&gamma, args, cli.output).await,
-        Commands::Comments(args) => commands::comments::execute(&gamma, args, cli.output).await,
-        Commands::Profiles(args) => commands::profiles::execute(&gamma, args, cli.output).await,
-        Commands::Sports(args) => commands::sports::execute(&gamma, args, cli.output).await,
-        Commands::Approve(args) => {
-            commands::approve::execute(args, cli.output, cli.private_key.as_deref()).await
-        }
-        Commands::Clob(args) => {
-            commands::clob::execute(
-                args,
-                cli.output,
-                cli.private_key.as_deref(),
-                cli.signature_type.as_deref(),
-            )
-            .await
-        }
-        Commands::Ctf(args) => {
-            commands::ctf::execute(args, cli.output, cli.private_key.as_deref()).await
-        }
-        Commands::Data(args) => commands::data::execute(&data, args, cli.output).await,
-        Commands::Bridge(args) => commands::bridge::execute(&bridge, args, cli.output).await,
-        Commands::Wallet(args) => {
-            commands::wallet::execute(args, cli.output, cli.private_key.as_deref())
-        }
-        Commands::Upgrade => commands::upgrade::execute(),
-        Commands::Status => {
-            let status = gamma.status().await?;
-            match cli.output {
-                OutputFormat::Json => {
-                    println!("{}", serde_json::json!({"status": status}));
-                }
-                OutputFormat::Table => {
-                    println!("API Status: {status}");
+    config::set_profile_flag(cli.profile.clone());
+    let settings = cli.settings()?;
+    let output_flag = cli.output.or(settings.output).unwrap_or(OutputFlag::Table);
+    if matches!(output_flag, OutputFlag::Template) {
+        anyhow::ensure!(
+            cli.template.is_some() || cli.template_file.is_some(),
+            CliError::invalid_argument("-o template requires --template or --template-file")
+        );
+        output::template::activate(cli.template.as_deref(), cli.template_file.as_deref())?;
+    } else {
+        output::template::activate(None, None)?;
+    }
+    output::set_envelope(cli.envelope);
+    output::display::set(output::display::resolve(
+        cli.timezone,
+        cli.numbers,
+        cli.price_style,
+    )?);
+    config::set_settings(settings.clone());
+    config::set_chain(config::resolve_chain(cli.chain)?);
+    config::set_funder_flag(cli.funder);
+    let output = output_flag.format();
+
+    // Built on use so we only pay for the client we actually need.
+    let gamma = || {
+        let url = settings.gamma_url();
+        polymarket_client_sdk::gamma::Client::new(&url).context(CliError::invalid_argument(
+            format!("Invalid gamma_url `{url}`"),
+        ))
+    };
+    let data = || {
+        let url = settings.data_url();
+        polymarket_client_sdk::data::Client::new(&url).context(CliError::invalid_argument(format!(
+            "Invalid data_url `{url}`"
+        )))
+    };
+    let bridge = || {
+        let url = settings.bridge_url();
+        polymarket_client_sdk::bridge::Client::new(&url).context(CliError::invalid_argument(
+            format!("Invalid bridge_url `{url}`"),
+        ))
+    };
+
+    // Commands that may wait on the user aren't subject to `timeout`, nor are
+    // those sending transactions: `--tx-timeout` bounds their wait, and
+    // timing out after a broadcast would invite a retry that sends it twice.
+    let exempt = matches!(
+        cli.command,
+        Commands::Setup
+            | Commands::Shell
+            | Commands::Wallet(_)
+            | Commands::Config(_)
+            | Commands::Upgrade
+            | Commands::Approve(_)
+            | Commands::Ctf(_)
+            | Commands::Tx(_)
+    );
+    let command = async {
+        match cli.command {
+            Commands::Setup => commands::setup::execute(),
+            Commands::Shell => Box::pin(shell::run_shell()).await,
+            Commands::Markets(args) => commands::markets::execute(&gamma()?, args, output).await,
+            Commands::Events(args) => commands::events::execute(&gamma()?, args, output).await,
+            Commands::Tags(args) => commands::tags::execute(&gamma()?, args, output).await,
+            Commands::Series(args) => commands::series::execute(&gamma()?, args, output).await,
+            Commands::Comments(args) => commands::comments::execute(&gamma()?, args, output).await,
+            Commands::Profiles(args) => commands::profiles::execute(&gamma()?, args, output).await,
+            Commands::Sports(args) => commands::sports::execute(&gamma()?, args, output).await,
+            Commands::Approve(args) => {
+                commands::approve::execute(
+                    args,
+                    output,
+                    cli.private_key.as_deref(),
+                    cli.signature_type,
+                )
+                .await
+            }
+            Commands::Clob(args) => {
+                commands::clob::execute(
+                    args,
+                    output,
+                    cli.private_key.as_deref(),
+                    cli.signature_type,
+                )
+                .await
+            }
+            Commands::Ctf(args) => {
+                commands::ctf::execute(
+                    args,
+                    output,
+                    cli.private_key.as_deref(),
+                    cli.signature_type,
+                    gamma,
+                    data,
+                )
+                .await
+            }
+            Commands::Tx(args) => {
+                commands::tx::execute(args, output, cli.private_key.as_deref()).await
+            }
+            Commands::Data(args) => {
+                commands::data::execute(
+                    &data()?,
+                    args,
+                    output,
+                    cli.private_key.as_deref(),
+                    cli.signature_type,
+                )
+                .await
+            }
+            Commands::Bridge(args) => commands::bridge::execute(&bridge()?, args, output).await,
+            Commands::Wallet(args) => {
+                commands::wallet::execute(
+                    args,
+                    output,
+                    cli.private_key.as_deref(),
+                    cli.signature_type,
+                    data,
+                )
+                .await
+            }
+            Commands::Upgrade => commands::upgrade::execute(),
+            Commands::Schema(args) => commands::schema::execute(&args, output, cli.envelope),
+            Commands::Config(args) => commands::config::execute(args, output),
+            Commands::Status => {
+                let status = gamma()?.status().await?;
+                match output {
+                    OutputFormat::Json => {
+                        output::print_json(&serde_json::json!({"status": status}))?;
+                    }
+                    OutputFormat::Table => {
+                        println!("API Status: {status}");
+                    }
                 }
+                Ok(())
             }
-            Ok(())
         }
+    };
+
+    match settings.timeout {
+        Some(secs) if !exempt => tokio::time::timeout(Duration::from_secs(secs), command)
+            .await
+            .map_err(|_| CliError::new(ErrorCode::Network, format!("Timed out after {secs}s")))?,
+        _ => command.await,
     }
 }
@@ -1,14 +1,23 @@
-use alloy::primitives::U256;
+use alloy::primitives::{Address, U256};
 use anyhow::Result;
 use tabled::Tabled;
 use tabled::settings::Style;
 
 use super::OutputFormat;
+use crate::config::SignatureType;
+use crate::tx::{Sent, Simulation};
 
 pub struct ApprovalStatus {
+    /// The signature type `owner` trades under; unknown for an address
+    /// given on the command line.
+    pub wallet: Option<SignatureType>,
+    pub owner: Address,
     pub contract_name: String,
     pub contract_address: String,
     pub usdc_allowance: U256,
+    /// Whether the allowance is as good as the unlimited one `approve set`
+    /// grants, so `approve set` would skip it.
+    pub usdc_unlimited: bool,
     pub ctf_approved: bool,
     pub usdc_error: Option<String>,
     pub ctf_error: Option<String>,
@@ -16,6 +25,8 @@ pub struct ApprovalStatus {
 
 #[derive(Tabled)]
 struct ApprovalRow {
+    #[tabled(rename = "Wallet")]
+    wallet: String,
     #[tabled(rename = "Contract")]
     contract: String,
     #[tabled(rename = "USDC")]
@@ -24,15 +35,15 @@ struct ApprovalRow {
     ctf: String,
 }
 
-fn format_allowance(allowance: U256) -> String {
-    if allowance == U256::MAX {
+fn format_allowance(allowance: U256, unlimited: bool) -> String {
+    if unlimited {
         "\u{2713} Unlimited".to_string()
     } else if allowance == U256::ZERO {
         "\u{2717} None".to_string()
     } else {
         let usdc_decimals = U256::from(1_000_000);
         let whole = allowance / usdc_decimals;
-        format!("\u{2713} {whole} USDC")
+        format!("\u{2717} {whole} USDC")
     }
 }
 
@@ -44,13 +55,23 @@ fn format_ctf(approved: bool) -> String {
     }
 }
 
-pub fn print_approval_status(statuses: &[ApprovalStatus], output: &OutputFormat) -> Result<()> {
+fn wallet_label(wallet: Option<SignatureType>) -> &'static str {
+    wallet.map_or("address", SignatureType::as_str)
+}
+
+pub fn print_approval_status(
+    owners: &[(Option<SignatureType>, Address)],
+    statuses: &[ApprovalStatus],
+    output: &OutputFormat,
+) -> Result<()> {
     match output {
         OutputFormat::Json => {
             let json: Vec<serde_json::Value> = statuses
                 .iter()
                 .map(|s| {
                     let mut obj = serde_json::json!({
+                        "owner": s.owner.to_string(),
+                        "signature_type": s.wallet,
                         "contract": s.contract_name,
                         "address": s.contract_address,
                         "usdc_allowance": s.usdc_allowance.to_string(),
@@ -66,18 +87,19 @@ pub fn print_approval_status(statuses: &[ApprovalStatus], output: &OutputFormat)
                     obj
                 })
                 .collect();
-            println!("{}", serde_json::to_string_pretty(&json)?);
+            super::print_json(&json)?;
             Ok(())
         }
         OutputFormat::Table => {
             let rows: Vec<ApprovalRow> = statuses
                 .iter()
                 .map(|s| ApprovalRow {
+                    wallet: wallet_label(s.wallet).to_string(),
                     contract: s.contract_name.clone(),
                     usdc: if let Some(ref err) = s.usdc_error {
                         format!("\u{2717} RPC error: {err}")
                     } else {
-                        format_allowance(s.usdc_allowance)
+                        format_allowance(s.usdc_allowance, s.usdc_unlimited)
                     },
                     ctf: if let Some(ref err) = s.ctf_error {
                         format!("\u{2717} RPC error: {err}")
@@ -86,6 +108,9 @@ pub fn print_approval_status(statuses: &[ApprovalStatus], output: &OutputFormat)
                     },
                 })
                 .collect();
+            for &(wallet, address) in owners {
+                println!("{:<12} {address}", wallet_label(wallet));
+            }
             let table = tabled::Table::new(rows).with(Style::rounded()).to_string();
             println!("{table}");
             Ok(())
@@ -93,8 +118,23 @@ pub fn print_approval_status(statuses: &[ApprovalStatus], output: &OutputFormat)
     }
 }
 
-pub fn print_tx_result(step: usize, total: usize, label: &str, tx_hash: alloy::primitives::B256) {
-    let hash_str = format!("{tx_hash}");
+pub fn print_tx_result(step: usize, total: usize, label: &str, sent: &Sent) {
+    let hash_str = format!("{}", sent.transaction_hash);
     let short = &hash_str[..10];
-    println!("  [{step}/{total}] {label:<30} \u{2713} {short}\u{2026}");
+    println!(
+        "  [{step}/{total}] {label:<30} \u{2713} {short}\u{2026}  {} POL",
+        sent.fee
+    );
+}
+
+pub fn print_skipped(step: usize, total: usize, label: &str) {
+    println!("  [{step}/{total}] {label:<30} \u{2713} already set");
+}
+
+pub fn print_simulation(step: usize, total: usize, label: &str, simulation: &Simulation) {
+    match (&simulation.revert_reason, simulation.gas_estimate) {
+        (Some(reason), _) => println!("  [{step}/{total}] {label:<30} \u{2717} reverts: {reason}"),
+        (None, Some(gas)) => println!("  [{step}/{total}] {label:<30} \u{2713} ~{gas} gas"),
+        (None, None) => println!("  [{step}/{total}] {label:<30} \u{2713}"),
+    }
 }
@@ -10,7 +10,8 @@ use tabled::settings::Style;
 use tabled::{Table, Tabled};
 
 use super::END_CURSOR;
-use crate::output::{OutputFormat, format_decimal, truncate};
+use crate::output::display::PriceStyle;
+use crate::output::{OutputFormat, format_date_as, format_decimal, format_price, truncate};
 
 pub fn print_server_time(timestamp: i64, output: &OutputFormat) -> anyhow::Result<()> {
     match output {
@@ -20,7 +21,7 @@ pub fn print_server_time(timestamp: i64, output: &OutputFormat) -> anyhow::Resul
                 Some(dt) => {
                     println!(
                         "Server time: {} ({timestamp})",
-                        dt.format("%Y-%m-%d %H:%M:%S UTC")
+                        format_date_as(&dt, "%Y-%m-%d %H:%M:%S")
                     );
                 }
                 None => println!("Server time: {timestamp}"),
@@ -119,7 +120,7 @@ pub fn print_notifications(
                     notif_type: n.r#type.to_string(),
                     question: truncate(&n.payload.question, 40),
                     side: n.payload.side.to_string(),
-                    price: n.payload.price.to_string(),
+                    price: format_price(n.payload.price, PriceStyle::Probability),
                     size: n.payload.matched_size.to_string(),
                 })
                 .collect();
@@ -134,7 +135,7 @@ pub fn print_notifications(
                         "type": n.r#type,
                         "question": n.payload.question,
                         "side": n.payload.side.to_string(),
-                        "price": n.payload.price.to_string(),
+                        "price": format_price(n.payload.price, PriceStyle::Probability),
                         "outcome": n.payload.outcome,
                         "matched_size": n.payload.matched_size.to_string(),
                         "original_size": n.payload.original_size.to_string(),
@@ -203,7 +204,7 @@ pub fn print_rewards(
                 })
                 .collect();
             let wrapper = json!({"data": data, "next_cursor": result.next_cursor});
-            crate::output::print_json(&wrapper)?;
+            crate::output::print_json_page(&data, &result.next_cursor, &wrapper)?;
         }
     }
     Ok(())
@@ -420,7 +421,7 @@ pub fn print_current_rewards(
                 })
                 .collect();
             let wrapper = json!({"data": data, "next_cursor": result.next_cursor});
-            crate::output::print_json(&wrapper)?;
+            crate::output::print_json_page(&data, &result.next_cursor, &wrapper)?;
         }
     }
     Ok(())
@@ -449,7 +450,9 @@ pub fn print_market_reward(
                 for token in &r.tokens {
                     println!(
                         "  Token ({}): {} | Price: {}",
-                        token.outcome, token.token_id, token.price
+                        token.outcome,
+                        token.token_id,
+                        format_price(token.price, PriceStyle::Probability)
                     );
                 }
             }
@@ -489,7 +492,7 @@ pub fn print_market_reward(
                 })
                 .collect();
             let wrapper = json!({"data": data, "next_cursor": result.next_cursor});
-            crate::output::print_json(&wrapper)?;
+            crate::output::print_json_page(&data, &result.next_cursor, &wrapper)?;
         }
     }
     Ok(())
@@ -5,7 +5,8 @@ use serde_json::json;
 use tabled::settings::Style;
 use tabled::{Table, Tabled};
 
-use crate::output::{DASH, OutputFormat, truncate};
+use crate::output::display::PriceStyle;
+use crate::output::{DASH, OutputFormat, format_price, truncate};
 
 pub fn print_order_book(
     result: &OrderBookSummaryResponse,
@@ -19,7 +20,7 @@ pub fn print_order_book(
                 "Last Trade: {}",
                 result
                     .last_trade_price
-                    .map_or(DASH.into(), |p| p.to_string())
+                    .map_or(DASH.into(), |p| format_price(p, PriceStyle::Probability))
             );
             println!();
 
@@ -39,7 +40,7 @@ pub fn print_order_book(
                     .bids
                     .iter()
                     .map(|o| Row {
-                        price: o.price.to_string(),
+                        price: format_price(o.price, PriceStyle::Probability),
                         size: o.size.to_string(),
                     })
                     .collect();
@@ -57,7 +58,7 @@ pub fn print_order_book(
                     .asks
                     .iter()
                     .map(|o| Row {
-                        price: o.price.to_string(),
+                        price: format_price(o.price, PriceStyle::Probability),
                         size: o.size.to_string(),
                     })
                     .collect();
@@ -101,7 +102,11 @@ pub fn print_last_trade(
     output: &OutputFormat,
 ) -> anyhow::Result<()> {
     match output {
-        OutputFormat::Table => println!("Last Trade: {} ({})", result.price, result.side),
+        OutputFormat::Table => println!(
+            "Last Trade: {} ({})",
+            format_price(result.price, PriceStyle::Probability),
+            result.side
+        ),
         OutputFormat::Json => {
             crate::output::print_json(&json!({
                 "price": result.price.to_string(),
@@ -135,7 +140,7 @@ pub fn print_last_trades_prices(
                 .iter()
                 .map(|t| Row {
                     token_id: truncate(&t.token_id.to_string(), 20),
-                    price: t.price.to_string(),
+                    price: format_price(t.price, PriceStyle::Probability),
                     side: t.side.to_string(),
                 })
                 .collect();
@@ -7,7 +7,8 @@ use tabled::settings::Style;
 use tabled::{Table, Tabled};
 
 use super::END_CURSOR;
-use crate::output::{DASH, OutputFormat, truncate};
+use crate::output::display::PriceStyle;
+use crate::output::{DASH, OutputFormat, format_date, format_price, truncate};
 
 pub fn print_clob_market(result: &MarketResponse, output: &OutputFormat) -> anyhow::Result<()> {
     match output {
@@ -42,7 +43,9 @@ pub fn print_clob_market(result: &MarketResponse, output: &OutputFormat) -> anyh
                     format!("Token ({})", token.outcome),
                     format!(
                         "ID: {} | Price: {} | Winner: {}",
-                        token.token_id, token.price, token.winner
+                        token.token_id,
+                        format_price(token.price, PriceStyle::Probability),
+                        token.winner
                     ),
                 ]);
             }
@@ -93,7 +96,7 @@ pub fn print_clob_markets(
             }
         }
         OutputFormat::Json => {
-            crate::output::print_json(result)?;
+            crate::output::print_json_page(&result.data, &result.next_cursor, result)?;
         }
     }
     Ok(())
@@ -142,7 +145,7 @@ pub fn print_simplified_markets(
             }
         }
         OutputFormat::Json => {
-            crate::output::print_json(result)?;
+            crate::output::print_json_page(&result.data, &result.next_cursor, result)?;
         }
     }
     Ok(())
@@ -208,10 +211,8 @@ pub fn print_price_history(
                 .iter()
                 .map(|p| Row {
                     timestamp: chrono::DateTime::from_timestamp(p.t, 0)
-                        .map_or(p.t.to_string(), |dt| {
-                            dt.format("%Y-%m-%d %H:%M").to_string()
-                        }),
-                    price: p.p.to_string(),
+                        .map_or(p.t.to_string(), |dt| format_date(&dt)),
+                    price: format_price(p.p, PriceStyle::Probability),
                 })
                 .collect();
             let table = Table::new(rows).with(Style::rounded()).to_string();
@@ -5,7 +5,7 @@ mod orders;
 mod prices;
 
 /// Base64-encoded empty cursor returned by the CLOB API when there are no more pages.
-const END_CURSOR: &str = "LTE=";
+pub(crate) const END_CURSOR: &str = "LTE=";
 
 pub(crate) use super::OutputFormat;
 
@@ -7,7 +7,8 @@ use tabled::settings::Style;
 use tabled::{Table, Tabled};
 
 use super::END_CURSOR;
-use crate::output::{OutputFormat, truncate};
+use crate::output::display::PriceStyle;
+use crate::output::{OutputFormat, format_date, format_price, truncate};
 
 pub fn print_orders(result: &Page<OpenOrderResponse>, output: &OutputFormat) -> anyhow::Result<()> {
     match output {
@@ -39,7 +40,7 @@ pub fn print_orders(result: &Page<OpenOrderResponse>, output: &OutputFormat) ->
                 .map(|o| Row {
                     id: truncate(&o.id, 12),
                     side: o.side.to_string(),
-                    price: o.price.to_string(),
+                    price: format_price(o.price, PriceStyle::Probability),
                     original_size: o.original_size.to_string(),
                     size_matched: o.size_matched.to_string(),
                     status: o.status.to_string(),
@@ -74,7 +75,7 @@ pub fn print_orders(result: &Page<OpenOrderResponse>, output: &OutputFormat) ->
                 })
                 .collect();
             let wrapper = json!({"data": data, "next_cursor": result.next_cursor});
-            crate::output::print_json(&wrapper)?;
+            crate::output::print_json_page(&data, &result.next_cursor, &wrapper)?;
         }
     }
     Ok(())
@@ -89,13 +90,16 @@ pub fn print_order_detail(result: &OpenOrderResponse, output: &OutputFormat) ->
                 ["Market".into(), result.market.to_string()],
                 ["Asset ID".into(), result.asset_id.to_string()],
                 ["Side".into(), result.side.to_string()],
-                ["Price".into(), result.price.to_string()],
+                [
+                    "Price".into(),
+                    format_price(result.price, PriceStyle::Probability),
+                ],
                 ["Original Size".into(), result.original_size.to_string()],
                 ["Size Matched".into(), result.size_matched.to_string()],
                 ["Outcome".into(), result.outcome.clone()],
                 ["Order Type".into(), result.order_type.to_string()],
-                ["Created".into(), result.created_at.to_rfc3339()],
-                ["Expiration".into(), result.expiration.to_rfc3339()],
+                ["Created".into(), format_date(&result.created_at)],
+                ["Expiration".into(), format_date(&result.expiration)],
                 ["Trades".into(), result.associate_trades.join(", ")],
             ];
             crate::output::print_detail_table(rows);
@@ -245,10 +249,10 @@ pub fn print_trades(result: &Page<TradeResponse>, output: &OutputFormat) -> anyh
                 .map(|t| Row {
                     id: truncate(&t.id, 12),
                     side: t.side.to_string(),
-                    price: t.price.to_string(),
+                    price: format_price(t.price, PriceStyle::Probability),
                     size: t.size.to_string(),
                     status: t.status.to_string(),
-                    match_time: t.match_time.format("%Y-%m-%d %H:%M").to_string(),
+                    match_time: format_date(&t.match_time),
                 })
                 .collect();
             let table = Table::new(rows).with(Style::rounded()).to_string();
@@ -280,7 +284,7 @@ pub fn print_trades(result: &Page<TradeResponse>, output: &OutputFormat) -> anyh
                 })
                 .collect();
             let wrapper = json!({"data": data, "next_cursor": result.next_cursor});
-            crate::output::print_json(&wrapper)?;
+            crate::output::print_json_page(&data, &result.next_cursor, &wrapper)?;
         }
     }
     Ok(())
@@ -6,11 +6,15 @@ use serde_json::json;
 use tabled::settings::Style;
 use tabled::{Table, Tabled};
 
-use crate::output::{OutputFormat, truncate};
+use crate::output::display::PriceStyle;
+use crate::output::{OutputFormat, format_price, truncate};
 
 pub fn print_price(result: &PriceResponse, output: &OutputFormat) -> anyhow::Result<()> {
     match output {
-        OutputFormat::Table => println!("Price: {}", result.price),
+        OutputFormat::Table => println!(
+            "Price: {}",
+            format_price(result.price, PriceStyle::Probability)
+        ),
         OutputFormat::Json => {
             crate::output::print_json(&json!({"price": result.price.to_string()}))?;
         }
@@ -44,7 +48,7 @@ pub fn print_batch_prices(result: &PricesResponse, output: &OutputFormat) -> any
                     rows.push(Row {
                         token_id: truncate(&token_id.to_string(), 20),
                         side: side.to_string(),
-                        price: price.to_string(),
+                        price: format_price(*price, PriceStyle::Probability),
                     });
                 }
             }
@@ -72,7 +76,10 @@ pub fn print_batch_prices(result: &PricesResponse, output: &OutputFormat) -> any
 
 pub fn print_midpoint(result: &MidpointResponse, output: &OutputFormat) -> anyhow::Result<()> {
     match output {
-        OutputFormat::Table => println!("Midpoint: {}", result.mid),
+        OutputFormat::Table => println!(
+            "Midpoint: {}",
+            format_price(result.mid, PriceStyle::Probability)
+        ),
         OutputFormat::Json => {
             crate::output::print_json(&json!({"midpoint": result.mid.to_string()}))?;
         }
@@ -99,7 +106,7 @@ pub fn print_midpoints(result: &MidpointsResponse, output: &OutputFormat) -> any
                 .iter()
                 .map(|(id, mid)| Row {
                     token_id: truncate(&id.to_string(), 20),
-                    midpoint: mid.to_string(),
+                    midpoint: format_price(*mid, PriceStyle::Probability),
                 })
                 .collect();
             let table = Table::new(rows).with(Style::rounded()).to_string();
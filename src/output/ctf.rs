@@ -1,36 +1,309 @@
-use alloy::primitives::{B256, U256};
+use alloy::primitives::{Address, B256, U256};
 use anyhow::Result;
+use rust_decimal::Decimal;
+use tabled::settings::Style;
+use tabled::{Table, Tabled};
 
-use super::{OutputFormat, print_detail_table};
+use polymarket_client_sdk::gamma::types::response::Market;
+
+use super::{DASH, OutputFormat, active_status, print_detail_table, truncate};
+use crate::config;
+use crate::tx::{Sender, Sent, Simulation};
+
+pub struct RedeemOutcome {
+    pub outcome: String,
+    pub outcome_index: i32,
+    pub size: Decimal,
+}
+
+/// One condition of `ctf redeem-all`, with its transaction once sent.
+pub struct Redemption {
+    pub condition_id: B256,
+    pub title: String,
+    pub neg_risk: bool,
+    pub outcomes: Vec<RedeemOutcome>,
+    pub payout: Decimal,
+    pub sent: Option<Sent>,
+    /// Gas estimate under `--simulate`.
+    pub gas_estimate: Option<u64>,
+    pub error: Option<String>,
+}
 
 pub fn print_tx_result(
     operation: &str,
-    tx_hash: B256,
-    block_number: u64,
+    sender: Sender,
+    sent: &Sent,
     output: &OutputFormat,
 ) -> Result<()> {
+    let tx_hash = sent.transaction_hash;
     match output {
         OutputFormat::Json => {
             let json = serde_json::json!({
                 "operation": operation,
+                "sender": sender.address().to_string(),
+                "signature_type": sender.signature_type(),
                 "transaction_hash": format!("{tx_hash}"),
-                "block_number": block_number,
-                "polygonscan": format!("https://polygonscan.com/tx/{tx_hash}"),
+                "block_number": sent.block_number,
+                "gas_used": sent.gas_used,
+                "fee": sent.fee.to_string(),
+                "polygonscan": config::chain().tx_url(tx_hash),
             });
-            println!("{}", serde_json::to_string_pretty(&json)?);
+            super::print_json(&json)?;
             Ok(())
         }
         OutputFormat::Table => {
             let rows = vec![
                 ["Operation".into(), operation.to_string()],
+                [
+                    "Sender".into(),
+                    format!("{} ({})", sender.address(), sender.signature_type()),
+                ],
                 ["Tx Hash".into(), format!("{tx_hash}")],
-                ["Block".into(), block_number.to_string()],
+                ["Block".into(), sent.block_number.to_string()],
+                ["Gas Used".into(), sent.gas_used.to_string()],
+                ["Fee".into(), format!("{} POL", sent.fee)],
+                ["Polygonscan".into(), config::chain().tx_url(tx_hash)],
+            ];
+            print_detail_table(rows);
+            Ok(())
+        }
+    }
+}
+
+/// Expected change of one of the sender's balances.
+pub struct BalanceChange {
+    pub asset: String,
+    pub amount: Decimal,
+}
+
+pub fn print_simulation(
+    operation: &str,
+    sender: Sender,
+    simulation: &Simulation,
+    changes: &[BalanceChange],
+    output: &OutputFormat,
+) -> Result<()> {
+    match output {
+        OutputFormat::Json => {
+            let changes: Vec<_> = changes
+                .iter()
+                .map(|c| {
+                    serde_json::json!({
+                        "asset": c.asset,
+                        "amount": c.amount.normalize().to_string(),
+                    })
+                })
+                .collect();
+            super::print_json(&serde_json::json!({
+                "operation": operation,
+                "sender": sender.address().to_string(),
+                "signature_type": sender.signature_type(),
+                "simulated": true,
+                "success": simulation.revert_reason.is_none(),
+                "gas_estimate": simulation.gas_estimate,
+                "revert_reason": simulation.revert_reason,
+                "balance_changes": changes,
+            }))
+        }
+        OutputFormat::Table => {
+            let mut rows = vec![
+                ["Operation".into(), format!("{operation} (simulated)")],
                 [
-                    "Polygonscan".into(),
-                    format!("https://polygonscan.com/tx/{tx_hash}"),
+                    "Sender".into(),
+                    format!("{} ({})", sender.address(), sender.signature_type()),
                 ],
             ];
+            match (&simulation.revert_reason, simulation.gas_estimate) {
+                (Some(reason), _) => rows.push(["Result".into(), format!("Reverts: {reason}")]),
+                (None, gas) => {
+                    rows.push(["Result".into(), "Succeeds".into()]);
+                    rows.push([
+                        "Gas Estimate".into(),
+                        gas.map_or_else(|| DASH.to_string(), |g| g.to_string()),
+                    ]);
+                }
+            }
+            for change in changes {
+                rows.push([
+                    change.asset.clone(),
+                    format!("{:+}", change.amount.normalize()),
+                ]);
+            }
+            print_detail_table(rows);
+            Ok(())
+        }
+    }
+}
+
+/// A condition's payout as the ConditionalTokens contract records it.
+pub struct Resolution {
+    pub condition_id: B256,
+    /// One per outcome slot; all zero until the condition is resolved.
+    pub payout_numerators: Vec<U256>,
+    pub payout_denominator: U256,
+}
+
+impl Resolution {
+    pub fn is_resolved(&self) -> bool {
+        !self.payout_denominator.is_zero()
+    }
+
+    /// What one token of each outcome redeems for, in collateral.
+    pub fn payouts(&self) -> Option<Vec<Decimal>> {
+        if !self.is_resolved() {
+            return None;
+        }
+        let decimal = |v: &U256| Decimal::from_str_exact(&v.to_string()).ok();
+        let denominator = decimal(&self.payout_denominator)?;
+        self.payout_numerators
+            .iter()
+            .map(|n| {
+                decimal(n)
+                    .and_then(|n| n.checked_div(denominator))
+                    .map(|p| p.normalize())
+            })
+            .collect()
+    }
+
+    /// The outcome paying out in full, if one does.
+    pub fn winner(&self) -> Option<usize> {
+        if !self.is_resolved() {
+            return None;
+        }
+        self.payout_numerators
+            .iter()
+            .position(|n| *n == self.payout_denominator)
+    }
+}
+
+/// The outcome the gamma API reports as the winner of a closed market: the
+/// one priced at 1.
+fn gamma_winner(market: &Market) -> Option<usize> {
+    if market.closed != Some(true) {
+        return None;
+    }
+    market
+        .outcome_prices
+        .as_ref()?
+        .iter()
+        .position(|p| *p == Decimal::ONE)
+}
+
+pub fn print_condition(
+    resolution: &Resolution,
+    market: Option<&Market>,
+    gamma_error: Option<String>,
+    output: &OutputFormat,
+) -> Result<()> {
+    let outcome_name = |index: usize| {
+        market
+            .and_then(|m| m.outcomes.as_ref())
+            .and_then(|o| o.get(index).cloned())
+            .unwrap_or_else(|| format!("Outcome {index}"))
+    };
+    let payouts = resolution.payouts();
+    let winner = resolution.winner();
+    match output {
+        OutputFormat::Json => {
+            let outcomes: Vec<_> = resolution
+                .payout_numerators
+                .iter()
+                .enumerate()
+                .map(|(index, numerator)| {
+                    serde_json::json!({
+                        "index": index,
+                        "outcome": market
+                            .and_then(|m| m.outcomes.as_ref())
+                            .and_then(|o| o.get(index)),
+                        "payout_numerator": numerator.to_string(),
+                        "payout": payouts.as_ref().map(|p| p[index].to_string()),
+                    })
+                })
+                .collect();
+            let gamma = market.map(|m| {
+                serde_json::json!({
+                    "market_id": m.id,
+                    "question": m.question,
+                    "closed": m.closed,
+                    "uma_resolution_status": m.uma_resolution_status,
+                    "outcome_prices": m
+                        .outcome_prices
+                        .as_ref()
+                        .map(|p| p.iter().map(ToString::to_string).collect::<Vec<_>>()),
+                    "winning_outcome": gamma_winner(m),
+                })
+            });
+            super::print_json(&serde_json::json!({
+                "condition_id": resolution.condition_id.to_string(),
+                "resolved": resolution.is_resolved(),
+                "payout_denominator": resolution.payout_denominator.to_string(),
+                "outcomes": outcomes,
+                "winning_outcome": winner,
+                "gamma": gamma,
+                "gamma_error": gamma_error,
+            }))
+        }
+        OutputFormat::Table => {
+            let mut rows = vec![["Condition ID".into(), resolution.condition_id.to_string()]];
+            if let Some(question) = market.and_then(|m| m.question.clone()) {
+                rows.push(["Question".into(), question]);
+            }
+            rows.push([
+                "Resolved".into(),
+                if resolution.is_resolved() {
+                    "Yes"
+                } else {
+                    "No"
+                }
+                .into(),
+            ]);
+            rows.push([
+                "Payouts".into(),
+                payouts.as_ref().map_or_else(
+                    || DASH.to_string(),
+                    |p| {
+                        p.iter()
+                            .enumerate()
+                            .map(|(i, payout)| format!("{} {payout}", outcome_name(i)))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    },
+                ),
+            ]);
+            rows.push([
+                "Winner".into(),
+                match (resolution.is_resolved(), winner) {
+                    (false, _) => DASH.to_string(),
+                    (true, Some(index)) => outcome_name(index),
+                    (true, None) => "None (split payout)".to_string(),
+                },
+            ]);
+            let gamma = match (market, &gamma_error) {
+                (_, Some(error)) => format!("Unavailable: {error}"),
+                (None, None) => "No market for this condition".to_string(),
+                (Some(m), None) => {
+                    let mut parts = vec![active_status(m.closed, m.active).to_string()];
+                    if let Some(status) = &m.uma_resolution_status {
+                        parts.push(format!("UMA {status}"));
+                    }
+                    if let Some(index) = gamma_winner(m) {
+                        parts.push(format!("winner {}", outcome_name(index)));
+                    }
+                    parts.join(", ")
+                }
+            };
+            rows.push(["Gamma".into(), gamma]);
             print_detail_table(rows);
+
+            let gamma_winner = market.and_then(gamma_winner);
+            if !resolution.is_resolved() {
+                println!("Not resolved on chain: redeeming reverts until it is.");
+            } else if gamma_winner.is_some() && gamma_winner != winner {
+                println!(
+                    "\u{2717} The gamma API's winner differs from the chain's. \
+                     Redemption pays what the chain records."
+                );
+            }
             Ok(())
         }
     }
@@ -42,7 +315,7 @@ pub fn print_condition_id(condition_id: B256, output: &OutputFormat) -> Result<(
             let json = serde_json::json!({
                 "condition_id": format!("{condition_id}"),
             });
-            println!("{}", serde_json::to_string_pretty(&json)?);
+            super::print_json(&json)?;
             Ok(())
         }
         OutputFormat::Table => {
@@ -58,7 +331,7 @@ pub fn print_collection_id(collection_id: B256, output: &OutputFormat) -> Result
             let json = serde_json::json!({
                 "collection_id": format!("{collection_id}"),
             });
-            println!("{}", serde_json::to_string_pretty(&json)?);
+            super::print_json(&json)?;
             Ok(())
         }
         OutputFormat::Table => {
@@ -74,7 +347,7 @@ pub fn print_position_id(position_id: U256, output: &OutputFormat) -> Result<()>
             let json = serde_json::json!({
                 "position_id": position_id.to_string(),
             });
-            println!("{}", serde_json::to_string_pretty(&json)?);
+            super::print_json(&json)?;
             Ok(())
         }
         OutputFormat::Table => {
@@ -83,3 +356,240 @@ pub fn print_position_id(position_id: U256, output: &OutputFormat) -> Result<()>
         }
     }
 }
+
+/// One market of `ctf merge-all`, with its transaction once sent.
+pub struct MergeItem {
+    pub condition_id: B256,
+    pub title: String,
+    pub neg_risk: bool,
+    /// Zero when the on-chain amount is too large to show; `error` says so.
+    pub amount: Decimal,
+    pub sent: Option<Sent>,
+    /// Gas estimate under `--simulate`.
+    pub gas_estimate: Option<u64>,
+    pub error: Option<String>,
+}
+
+fn transaction_cell(
+    sent: Option<&Sent>,
+    gas_estimate: Option<u64>,
+    error: Option<&String>,
+) -> String {
+    match (sent, gas_estimate, error) {
+        (Some(sent), _, _) => format!("{}", sent.transaction_hash),
+        (None, _, Some(error)) => format!("\u{2717} {error}"),
+        (None, Some(gas), None) => format!("\u{2713} ~{gas} gas"),
+        (None, None, None) => DASH.to_string(),
+    }
+}
+
+/// Footer of a batch: what was, or would have been, done.
+fn batch_summary(verb: &str, done: &str, total: Decimal, dry_run: bool, simulated: bool) -> String {
+    if dry_run {
+        format!("Would {verb} ${total:.2} (dry run, nothing sent).")
+    } else if simulated {
+        format!("Would {verb} ${total:.2} (simulated, nothing sent).")
+    } else {
+        format!("{done} ${total:.2}.")
+    }
+}
+
+pub fn print_merge_all(
+    owner: Address,
+    merges: &[MergeItem],
+    dry_run: bool,
+    simulated: bool,
+    output: &OutputFormat,
+) -> Result<()> {
+    let total: Decimal = merges.iter().map(|m| m.amount).sum();
+    match output {
+        OutputFormat::Json => {
+            let items: Vec<_> = merges
+                .iter()
+                .map(|m| {
+                    serde_json::json!({
+                        "condition_id": format!("{}", m.condition_id),
+                        "title": m.title,
+                        "neg_risk": m.neg_risk,
+                        "amount": m.amount.to_string(),
+                        "transaction_hash": m.sent.as_ref().map(|s| format!("{}", s.transaction_hash)),
+                        "block_number": m.sent.as_ref().map(|s| s.block_number),
+                        "gas_used": m.sent.as_ref().map(|s| s.gas_used),
+                        "fee": m.sent.as_ref().map(|s| s.fee.to_string()),
+                        "gas_estimate": m.gas_estimate,
+                        "polygonscan": m.sent.as_ref().map(|s| config::chain().tx_url(s.transaction_hash)),
+                        "error": m.error,
+                    })
+                })
+                .collect();
+            super::print_json(&serde_json::json!({
+                "address": owner.to_string(),
+                "dry_run": dry_run,
+                "simulated": simulated,
+                "total_amount": total.normalize().to_string(),
+                "merges": items,
+            }))
+        }
+        OutputFormat::Table => {
+            if merges.is_empty() {
+                println!("Nothing to merge for {owner}.");
+                return Ok(());
+            }
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "Market")]
+                market: String,
+                #[tabled(rename = "Amount")]
+                amount: String,
+                #[tabled(rename = "Transaction")]
+                transaction: String,
+            }
+            let rows = merges.iter().map(|m| Row {
+                market: truncate(&m.title, 50),
+                amount: format!("${:.2}", m.amount),
+                transaction: transaction_cell(m.sent.as_ref(), m.gas_estimate, m.error.as_ref()),
+            });
+            println!("{}", Table::new(rows).with(Style::rounded()));
+            println!(
+                "{}",
+                batch_summary("merge", "Merged", total, dry_run, simulated)
+            );
+            Ok(())
+        }
+    }
+}
+
+pub fn print_redeem_all(
+    owner: Address,
+    redemptions: &[Redemption],
+    dry_run: bool,
+    simulated: bool,
+    output: &OutputFormat,
+) -> Result<()> {
+    let total: Decimal = redemptions.iter().map(|r| r.payout).sum();
+    match output {
+        OutputFormat::Json => {
+            let items: Vec<_> = redemptions
+                .iter()
+                .map(|r| {
+                    let outcomes: Vec<_> = r
+                        .outcomes
+                        .iter()
+                        .map(|o| {
+                            serde_json::json!({
+                                "outcome": o.outcome,
+                                "outcome_index": o.outcome_index,
+                                "size": o.size.to_string(),
+                            })
+                        })
+                        .collect();
+                    serde_json::json!({
+                        "condition_id": format!("{}", r.condition_id),
+                        "title": r.title,
+                        "neg_risk": r.neg_risk,
+                        "outcomes": outcomes,
+                        "payout": r.payout.normalize().to_string(),
+                        "transaction_hash": r.sent.as_ref().map(|s| format!("{}", s.transaction_hash)),
+                        "block_number": r.sent.as_ref().map(|s| s.block_number),
+                        "gas_used": r.sent.as_ref().map(|s| s.gas_used),
+                        "fee": r.sent.as_ref().map(|s| s.fee.to_string()),
+                        "gas_estimate": r.gas_estimate,
+                        "polygonscan": r.sent.as_ref().map(|s| config::chain().tx_url(s.transaction_hash)),
+                        "error": r.error,
+                    })
+                })
+                .collect();
+            super::print_json(&serde_json::json!({
+                "address": owner.to_string(),
+                "dry_run": dry_run,
+                "simulated": simulated,
+                "total_payout": total.normalize().to_string(),
+                "redemptions": items,
+            }))
+        }
+        OutputFormat::Table => {
+            if redemptions.is_empty() {
+                println!("Nothing to redeem for {owner}.");
+                return Ok(());
+            }
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "Market")]
+                market: String,
+                #[tabled(rename = "Holding")]
+                holding: String,
+                #[tabled(rename = "Payout")]
+                payout: String,
+                #[tabled(rename = "Transaction")]
+                transaction: String,
+            }
+            let rows = redemptions.iter().map(|r| Row {
+                market: truncate(&r.title, 50),
+                holding: r
+                    .outcomes
+                    .iter()
+                    .map(|o| format!("{} {}", o.size.normalize(), o.outcome))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                payout: format!("${:.2}", r.payout),
+                transaction: transaction_cell(r.sent.as_ref(), r.gas_estimate, r.error.as_ref()),
+            });
+            println!("{}", Table::new(rows).with(Style::rounded()));
+            println!(
+                "{}",
+                batch_summary("redeem", "Redeemed", total, dry_run, simulated)
+            );
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn resolution(numerators: &[u64], denominator: u64) -> Resolution {
+        Resolution {
+            condition_id: B256::ZERO,
+            payout_numerators: numerators.iter().map(|&n| U256::from(n)).collect(),
+            payout_denominator: U256::from(denominator),
+        }
+    }
+
+    #[test]
+    fn unresolved_conditions_have_no_payouts() {
+        let r = resolution(&[0, 0], 0);
+        assert!(!r.is_resolved());
+        assert_eq!(r.payouts(), None);
+        assert_eq!(r.winner(), None);
+    }
+
+    #[test]
+    fn winner_pays_in_full() {
+        let r = resolution(&[0, 1], 1);
+        assert_eq!(r.payouts(), Some(vec![Decimal::ZERO, Decimal::ONE]));
+        assert_eq!(r.winner(), Some(1));
+
+        let split = resolution(&[1, 1], 2);
+        assert_eq!(
+            split.payouts(),
+            Some(vec![Decimal::new(5, 1), Decimal::new(5, 1)])
+        );
+        assert_eq!(split.winner(), None);
+    }
+
+    #[test]
+    fn gamma_winner_needs_a_closed_market() {
+        let market = |closed: bool| -> Market {
+            serde_json::from_value(json!({
+                "id": "1",
+                "closed": closed,
+                "outcomePrices": "[\"0\", \"1\"]",
+            }))
+            .unwrap()
+        };
+        assert_eq!(gamma_winner(&market(true)), Some(1));
+        assert_eq!(gamma_winner(&market(false)), None);
+    }
+}
@@ -6,7 +6,8 @@ use serde_json::json;
 use tabled::settings::Style;
 use tabled::{Table, Tabled};
 
-use super::{DASH, OutputFormat, format_decimal, truncate};
+use super::display::PriceStyle;
+use super::{DASH, OutputFormat, format_decimal, format_price, truncate};
 
 fn format_market(m: &Market) -> String {
     match m {
@@ -16,6 +17,27 @@ fn format_market(m: &Market) -> String {
     }
 }
 
+fn position_to_json(p: &Position) -> serde_json::Value {
+    json!({
+        "title": p.title,
+        "slug": p.slug,
+        "outcome": p.outcome,
+        "outcome_index": p.outcome_index,
+        "size": p.size.to_string(),
+        "avg_price": p.avg_price.to_string(),
+        "initial_value": p.initial_value.to_string(),
+        "current_value": p.current_value.to_string(),
+        "cash_pnl": p.cash_pnl.to_string(),
+        "percent_pnl": p.percent_pnl.to_string(),
+        "realized_pnl": p.realized_pnl.to_string(),
+        "cur_price": p.cur_price.to_string(),
+        "condition_id": p.condition_id.to_string(),
+        "proxy_wallet": p.proxy_wallet.to_string(),
+        "redeemable": p.redeemable,
+        "mergeable": p.mergeable,
+    })
+}
+
 pub fn print_positions(positions: &[Position], output: &OutputFormat) -> anyhow::Result<()> {
     match output {
         OutputFormat::Table => {
@@ -44,7 +66,7 @@ pub fn print_positions(positions: &[Position], output: &OutputFormat) -> anyhow:
                     title: truncate(&p.title, 40),
                     outcome: p.outcome.clone(),
                     size: format!("{:.2}", p.size),
-                    avg_price: format!("{:.4}", p.avg_price),
+                    avg_price: format_price(p.avg_price, PriceStyle::Probability),
                     current_value: format_decimal(p.current_value),
                     pnl: format!("{:.2}", p.cash_pnl),
                 })
@@ -53,29 +75,7 @@ pub fn print_positions(positions: &[Position], output: &OutputFormat) -> anyhow:
             println!("{table}");
         }
         OutputFormat::Json => {
-            let data: Vec<_> = positions
-                .iter()
-                .map(|p| {
-                    json!({
-                        "title": p.title,
-                        "slug": p.slug,
-                        "outcome": p.outcome,
-                        "outcome_index": p.outcome_index,
-                        "size": p.size.to_string(),
-                        "avg_price": p.avg_price.to_string(),
-                        "initial_value": p.initial_value.to_string(),
-                        "current_value": p.current_value.to_string(),
-                        "cash_pnl": p.cash_pnl.to_string(),
-                        "percent_pnl": p.percent_pnl.to_string(),
-                        "realized_pnl": p.realized_pnl.to_string(),
-                        "cur_price": p.cur_price.to_string(),
-                        "condition_id": p.condition_id.to_string(),
-                        "proxy_wallet": p.proxy_wallet.to_string(),
-                        "redeemable": p.redeemable,
-                        "mergeable": p.mergeable,
-                    })
-                })
-                .collect();
+            let data: Vec<_> = positions.iter().map(position_to_json).collect();
             super::print_json(&data)?;
         }
     }
@@ -108,7 +108,7 @@ pub fn print_closed_positions(
                 .map(|p| Row {
                     title: truncate(&p.title, 40),
                     outcome: p.outcome.clone(),
-                    avg_price: format!("{:.4}", p.avg_price),
+                    avg_price: format_price(p.avg_price, PriceStyle::Probability),
                     realized_pnl: format!("{:.2}", p.realized_pnl),
                 })
                 .collect();
@@ -188,6 +188,22 @@ pub fn print_traded(t: &Traded, output: &OutputFormat) -> anyhow::Result<()> {
     Ok(())
 }
 
+fn trade_to_json(t: &Trade) -> serde_json::Value {
+    json!({
+        "title": t.title,
+        "slug": t.slug,
+        "side": t.side.to_string(),
+        "outcome": t.outcome,
+        "outcome_index": t.outcome_index,
+        "size": t.size.to_string(),
+        "price": t.price.to_string(),
+        "timestamp": t.timestamp,
+        "condition_id": t.condition_id.to_string(),
+        "proxy_wallet": t.proxy_wallet.to_string(),
+        "transaction_hash": t.transaction_hash.to_string(),
+    })
+}
+
 pub fn print_trades(trades: &[Trade], output: &OutputFormat) -> anyhow::Result<()> {
     match output {
         OutputFormat::Table => {
@@ -215,31 +231,14 @@ pub fn print_trades(trades: &[Trade], output: &OutputFormat) -> anyhow::Result<(
                     side: t.side.to_string(),
                     outcome: t.outcome.clone(),
                     size: format!("{:.2}", t.size),
-                    price: format!("{:.4}", t.price),
+                    price: format_price(t.price, PriceStyle::Probability),
                 })
                 .collect();
             let table = Table::new(rows).with(Style::rounded()).to_string();
             println!("{table}");
         }
         OutputFormat::Json => {
-            let data: Vec<_> = trades
-                .iter()
-                .map(|t| {
-                    json!({
-                        "title": t.title,
-                        "slug": t.slug,
-                        "side": t.side.to_string(),
-                        "outcome": t.outcome,
-                        "outcome_index": t.outcome_index,
-                        "size": t.size.to_string(),
-                        "price": t.price.to_string(),
-                        "timestamp": t.timestamp,
-                        "condition_id": t.condition_id.to_string(),
-                        "proxy_wallet": t.proxy_wallet.to_string(),
-                        "transaction_hash": t.transaction_hash.to_string(),
-                    })
-                })
-                .collect();
+            let data: Vec<_> = trades.iter().map(trade_to_json).collect();
             super::print_json(&data)?;
         }
     }
@@ -444,6 +443,16 @@ pub fn print_live_volume(volume: &[LiveVolume], output: &OutputFormat) -> anyhow
     Ok(())
 }
 
+fn leaderboard_entry_to_json(e: &TraderLeaderboardEntry) -> serde_json::Value {
+    json!({
+        "rank": e.rank,
+        "proxy_wallet": e.proxy_wallet.to_string(),
+        "user_name": e.user_name,
+        "pnl": e.pnl.to_string(),
+        "volume": e.vol.to_string(),
+    })
+}
+
 pub fn print_leaderboard(
     entries: &[TraderLeaderboardEntry],
     output: &OutputFormat,
@@ -478,18 +487,7 @@ pub fn print_leaderboard(
             println!("{table}");
         }
         OutputFormat::Json => {
-            let data: Vec<_> = entries
-                .iter()
-                .map(|e| {
-                    json!({
-                        "rank": e.rank,
-                        "proxy_wallet": e.proxy_wallet.to_string(),
-                        "user_name": e.user_name,
-                        "pnl": e.pnl.to_string(),
-                        "volume": e.vol.to_string(),
-                    })
-                })
-                .collect();
+            let data: Vec<_> = entries.iter().map(leaderboard_entry_to_json).collect();
             super::print_json(&data)?;
         }
     }
@@ -603,3 +601,96 @@ pub fn print_builder_volume(
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::schema::validate::assert_conforms;
+
+    const WALLET: &str = "0x56687bf447db6ffa42ffe2204a05edaa20f55839";
+    const CONDITION: &str = "0xabababababababababababababababababababababababababababababababab";
+
+    fn position() -> Position {
+        serde_json::from_value(json!({
+            "proxyWallet": WALLET,
+            "asset": "123",
+            "conditionId": CONDITION,
+            "size": "10",
+            "avgPrice": "0.5",
+            "initialValue": "5",
+            "currentValue": "6",
+            "cashPnl": "1",
+            "percentPnl": "20",
+            "totalBought": "10",
+            "realizedPnl": "0",
+            "percentRealizedPnl": "0",
+            "curPrice": "0.6",
+            "redeemable": false,
+            "mergeable": false,
+            "title": "Will it rain?",
+            "slug": "will-it-rain",
+            "icon": "",
+            "eventSlug": "rain",
+            "eventId": "",
+            "outcome": "Yes",
+            "outcomeIndex": 0,
+            "oppositeOutcome": "No",
+            "oppositeAsset": "456",
+            "endDate": "2024-11-05",
+            "negativeRisk": false,
+        }))
+        .unwrap()
+    }
+
+    fn trade() -> Trade {
+        serde_json::from_value(json!({
+            "proxyWallet": WALLET,
+            "side": "BUY",
+            "asset": "123",
+            "conditionId": CONDITION,
+            "size": "10",
+            "price": "0.5",
+            "timestamp": 1_730_808_000,
+            "title": "Will it rain?",
+            "slug": "will-it-rain",
+            "icon": "",
+            "eventSlug": "rain",
+            "outcome": "Yes",
+            "outcomeIndex": 0,
+            "name": "alice",
+            "pseudonym": "",
+            "transactionHash": CONDITION,
+        }))
+        .unwrap()
+    }
+
+    fn leaderboard_entry() -> TraderLeaderboardEntry {
+        serde_json::from_value(json!({
+            "rank": "1",
+            "proxyWallet": WALLET,
+            "userName": "alice",
+            "vol": "1000",
+            "pnl": "-12.5",
+            "verifiedBadge": null,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn positions_conform_to_schema() {
+        assert_conforms("data positions", &vec![position_to_json(&position())]);
+    }
+
+    #[test]
+    fn trades_conform_to_schema() {
+        assert_conforms("data trades", &vec![trade_to_json(&trade())]);
+    }
+
+    #[test]
+    fn leaderboard_conforms_to_schema() {
+        assert_conforms(
+            "data leaderboard",
+            &vec![leaderboard_entry_to_json(&leaderboard_entry())],
+        );
+    }
+}
@@ -0,0 +1,337 @@
+//! Display settings for table output: timezone, number precision and price style.
+//!
+//! Set once per `run` from the global flags, falling back to the `display`
+//! section of the config file. JSON output is never affected.
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use chrono::{DateTime, FixedOffset, Local, Offset, Utc};
+use polymarket_client_sdk::types::Decimal;
+use rust_decimal::RoundingStrategy;
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+
+use super::DASH;
+
+/// Timezone dates are shown in: `utc`, `local`, or a fixed offset like `+02:00`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) enum Timezone {
+    #[default]
+    Utc,
+    Local,
+    Fixed(FixedOffset),
+}
+
+impl FromStr for Timezone {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "utc" | "z" => return Ok(Self::Utc),
+            "local" => return Ok(Self::Local),
+            _ => {}
+        }
+        let invalid =
+            || format!("Invalid timezone `{s}`: expected utc, local, or an offset like +02:00");
+        let (sign, rest) = match s.as_bytes().first() {
+            Some(b'+') => (1, &s[1..]),
+            Some(b'-') => (-1, &s[1..]),
+            _ => return Err(invalid()),
+        };
+        let (hours, minutes) = match rest.split_once(':') {
+            Some((h, m)) => (h, m),
+            None if rest.len() == 4 => rest.split_at(2),
+            None => (rest, "0"),
+        };
+        let hours: i32 = hours.parse().map_err(|_| invalid())?;
+        let minutes: i32 = minutes.parse().map_err(|_| invalid())?;
+        if hours > 23 || minutes > 59 {
+            return Err(invalid());
+        }
+        FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+            .map(Self::Fixed)
+            .ok_or_else(invalid)
+    }
+}
+
+impl fmt::Display for Timezone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Utc => f.write_str("utc"),
+            Self::Local => f.write_str("local"),
+            Self::Fixed(offset) => write!(f, "{offset}"),
+        }
+    }
+}
+
+impl Serialize for Timezone {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Timezone {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// How dollar amounts are shown.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum Numbers {
+    /// `$1.5M`, `$12.3K`
+    #[default]
+    Abbreviated,
+    /// `$1,500,000.00`
+    Full,
+}
+
+/// How prices (implied probabilities between 0 and 1) are shown.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum PriceStyle {
+    /// `0.65`
+    Probability,
+    /// `65.00¢`
+    Cents,
+    /// `1.54`
+    Decimal,
+    /// `-186`, `+150`
+    American,
+    /// `7/13`, `3/2`
+    Fractional,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Settings {
+    pub timezone: Timezone,
+    pub numbers: Numbers,
+    /// `None` keeps each table's own style (cents for markets, probabilities elsewhere).
+    pub price_style: Option<PriceStyle>,
+}
+
+static SETTINGS: Mutex<Settings> = Mutex::new(Settings {
+    timezone: Timezone::Utc,
+    numbers: Numbers::Abbreviated,
+    price_style: None,
+});
+
+pub(crate) fn set(settings: Settings) {
+    *SETTINGS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = settings;
+}
+
+/// Flags take priority over the `display` section of the config file.
+pub(crate) fn resolve(
+    timezone: Option<Timezone>,
+    numbers: Option<Numbers>,
+    price_style: Option<PriceStyle>,
+) -> anyhow::Result<Settings> {
+    let config = crate::config::load_display_config()?;
+    Ok(Settings {
+        timezone: timezone.or(config.timezone).unwrap_or_default(),
+        numbers: numbers.or(config.numbers).unwrap_or_default(),
+        price_style: price_style.or(config.price_style),
+    })
+}
+
+pub(crate) fn current() -> Settings {
+    *SETTINGS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+pub(crate) fn format_decimal_as(n: Decimal, numbers: Numbers) -> String {
+    let sign = if n.is_sign_negative() && !n.is_zero() {
+        "-"
+    } else {
+        ""
+    };
+    if numbers == Numbers::Full {
+        return format!(
+            "{sign}${}",
+            group_thousands(&format!("{:.2}", round_cents(n.abs())))
+        );
+    }
+    let abs = n.abs().to_f64().unwrap_or(0.0);
+    if abs >= 1_000_000.0 {
+        format!("{sign}${:.1}M", abs / 1_000_000.0)
+    } else if abs >= 1_000.0 {
+        format!("{sign}${:.1}K", abs / 1_000.0)
+    } else {
+        format!("{sign}${abs:.2}")
+    }
+}
+
+/// `Decimal`'s `{:.2}` truncates; round half away from zero first.
+fn round_cents(n: Decimal) -> Decimal {
+    n.round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero)
+}
+
+fn group_thousands(s: &str) -> String {
+    let (int, frac) = s.split_once('.').unwrap_or((s, ""));
+    let mut grouped = String::with_capacity(s.len() + int.len() / 3);
+    for (i, c) in int.chars().enumerate() {
+        if i > 0 && (int.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    if !frac.is_empty() {
+        grouped.push('.');
+        grouped.push_str(frac);
+    }
+    grouped
+}
+
+/// `d` converted to `timezone`, with the label to print after it.
+pub(crate) fn localize(d: &DateTime<Utc>, timezone: Timezone) -> (DateTime<FixedOffset>, String) {
+    let offset = match timezone {
+        Timezone::Utc => return (d.fixed_offset(), "UTC".into()),
+        Timezone::Local => d.with_timezone(&Local).offset().fix(),
+        Timezone::Fixed(offset) => offset,
+    };
+    (d.with_timezone(&offset), offset.to_string())
+}
+
+pub(crate) fn format_date_in(d: &DateTime<Utc>, timezone: Timezone, fmt: &str) -> String {
+    let (local, label) = localize(d, timezone);
+    format!("{} {label}", local.format(fmt))
+}
+
+pub(crate) fn format_price_as(p: Decimal, style: PriceStyle) -> String {
+    let one = Decimal::ONE;
+    let is_probability = p > Decimal::ZERO && p < one;
+    match style {
+        PriceStyle::Probability => p.round_dp(4).normalize().to_string(),
+        PriceStyle::Cents => format!("{:.2}¢", round_cents(p * Decimal::from(100))),
+        _ if !is_probability => DASH.into(),
+        PriceStyle::Decimal => format!("{:.2}", round_cents(one / p)),
+        PriceStyle::American => {
+            let odds = if p > Decimal::new(5, 1) {
+                -(p / (one - p)) * Decimal::from(100)
+            } else {
+                (one - p) / p * Decimal::from(100)
+            };
+            let odds = odds.round();
+            if odds.is_sign_positive() {
+                format!("+{odds}")
+            } else {
+                odds.to_string()
+            }
+        }
+        PriceStyle::Fractional => {
+            let (num, den) = approximate_fraction(((one - p) / p).to_f64().unwrap_or(0.0), 100);
+            format!("{num}/{den}")
+        }
+    }
+}
+
+/// Closest fraction to `x` with a denominator of at most `max_den`.
+fn approximate_fraction(x: f64, max_den: u64) -> (u64, u64) {
+    let mut best = (x.round().max(0.0) as u64, 1);
+    let mut best_err = (x - best.0 as f64).abs();
+    for den in 2..=max_den {
+        let num = (x * den as f64).round().max(0.0) as u64;
+        let err = (x - num as f64 / den as f64).abs();
+        if err + 1e-9 < best_err {
+            best = (num, den);
+            best_err = err;
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn timezone_parses_names_and_offsets() {
+        assert_eq!("UTC".parse::<Timezone>(), Ok(Timezone::Utc));
+        assert_eq!("local".parse::<Timezone>(), Ok(Timezone::Local));
+        let plus_two = FixedOffset::east_opt(2 * 3600).unwrap();
+        assert_eq!("+02:00".parse::<Timezone>(), Ok(Timezone::Fixed(plus_two)));
+        assert_eq!("+0200".parse::<Timezone>(), Ok(Timezone::Fixed(plus_two)));
+        assert_eq!("+2".parse::<Timezone>(), Ok(Timezone::Fixed(plus_two)));
+        let minus = FixedOffset::west_opt(5 * 3600 + 30 * 60).unwrap();
+        assert_eq!("-05:30".parse::<Timezone>(), Ok(Timezone::Fixed(minus)));
+        assert!("Europe/Paris".parse::<Timezone>().is_err());
+        assert!("+25:00".parse::<Timezone>().is_err());
+    }
+
+    #[test]
+    fn dates_convert_to_timezone() {
+        let d = Utc.with_ymd_and_hms(2024, 11, 5, 23, 30, 0).unwrap();
+        let fmt = "%Y-%m-%d %H:%M";
+        assert_eq!(
+            format_date_in(&d, Timezone::Utc, fmt),
+            "2024-11-05 23:30 UTC"
+        );
+        let tz = "+02:00".parse().unwrap();
+        assert_eq!(format_date_in(&d, tz, fmt), "2024-11-06 01:30 +02:00");
+    }
+
+    #[test]
+    fn full_numbers_are_grouped() {
+        assert_eq!(
+            format_decimal_as(dec!(1_500_000), Numbers::Full),
+            "$1,500,000.00"
+        );
+        assert_eq!(
+            format_decimal_as(dec!(-1234.567), Numbers::Full),
+            "-$1,234.57"
+        );
+        assert_eq!(format_decimal_as(dec!(999), Numbers::Full), "$999.00");
+        assert_eq!(
+            format_decimal_as(dec!(1_500_000), Numbers::Abbreviated),
+            "$1.5M"
+        );
+    }
+
+    #[test]
+    fn price_styles() {
+        let p = dec!(0.65);
+        assert_eq!(format_price_as(p, PriceStyle::Probability), "0.65");
+        assert_eq!(format_price_as(p, PriceStyle::Cents), "65.00¢");
+        assert_eq!(format_price_as(dec!(0.12345), PriceStyle::Cents), "12.35¢");
+        assert_eq!(format_price_as(p, PriceStyle::Decimal), "1.54");
+        assert_eq!(format_price_as(p, PriceStyle::American), "-186");
+        assert_eq!(format_price_as(p, PriceStyle::Fractional), "7/13");
+    }
+
+    #[test]
+    fn underdog_odds() {
+        let p = dec!(0.4);
+        assert_eq!(format_price_as(p, PriceStyle::Decimal), "2.50");
+        assert_eq!(format_price_as(p, PriceStyle::American), "+150");
+        assert_eq!(format_price_as(p, PriceStyle::Fractional), "3/2");
+        assert_eq!(format_price_as(dec!(0.5), PriceStyle::American), "+100");
+        assert_eq!(format_price_as(dec!(0.5), PriceStyle::Fractional), "1/1");
+    }
+
+    #[test]
+    fn odds_undefined_at_certainty() {
+        for p in [dec!(0), dec!(1)] {
+            assert_eq!(format_price_as(p, PriceStyle::American), DASH);
+            assert_eq!(format_price_as(p, PriceStyle::Decimal), DASH);
+        }
+        assert_eq!(format_price_as(dec!(1), PriceStyle::Probability), "1");
+    }
+
+    #[test]
+    fn probability_rounds_long_averages() {
+        assert_eq!(
+            format_price_as(dec!(0.523456), PriceStyle::Probability),
+            "0.5235"
+        );
+    }
+}
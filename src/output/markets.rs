@@ -1,10 +1,10 @@
 use polymarket_client_sdk::gamma::types::response::Market;
-use polymarket_client_sdk::types::Decimal;
 use tabled::settings::Style;
 use tabled::{Table, Tabled};
 
+use super::display::PriceStyle;
 use super::{
-    DASH, OutputFormat, active_status, detail_field, format_date, format_decimal,
+    DASH, OutputFormat, active_status, detail_field, format_date, format_decimal, format_price,
     print_detail_table, print_json, truncate,
 };
 
@@ -28,10 +28,7 @@ fn market_to_row(m: &Market) -> MarketRow {
         .outcome_prices
         .as_ref()
         .and_then(|p| p.first())
-        .map_or_else(
-            || DASH.into(),
-            |p| format!("{:.2}¢", p * Decimal::from(100)),
-        );
+        .map_or_else(|| DASH.into(), |p| format_price(*p, PriceStyle::Cents));
 
     MarketRow {
         question: truncate(question, 60),
@@ -82,7 +79,7 @@ pub fn print_market(m: &Market, output: &OutputFormat) -> anyhow::Result<()> {
             .as_ref()
             .map(|p| p
                 .iter()
-                .map(|v| format!("{v:.4}"))
+                .map(|v| format_price(*v, PriceStyle::Probability))
                 .collect::<Vec<_>>()
                 .join(", "))
             .unwrap_or_default()
@@ -105,12 +102,16 @@ pub fn print_market(m: &Market, output: &OutputFormat) -> anyhow::Result<()> {
     detail_field!(
         rows,
         "Best Bid",
-        m.best_bid.map(|v| format!("{v:.4}")).unwrap_or_default()
+        m.best_bid
+            .map(|v| format_price(v, PriceStyle::Probability))
+            .unwrap_or_default()
     );
     detail_field!(
         rows,
         "Best Ask",
-        m.best_ask.map(|v| format!("{v:.4}")).unwrap_or_default()
+        m.best_ask
+            .map(|v| format_price(v, PriceStyle::Probability))
+            .unwrap_or_default()
     );
     detail_field!(
         rows,
@@ -121,7 +122,7 @@ pub fn print_market(m: &Market, output: &OutputFormat) -> anyhow::Result<()> {
         rows,
         "Last Trade",
         m.last_trade_price
-            .map(|v| format!("{v:.4}"))
+            .map(|v| format_price(v, PriceStyle::Probability))
             .unwrap_or_default()
     );
     detail_field!(rows, "Status", active_status(m.closed, m.active).into());
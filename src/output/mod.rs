@@ -4,20 +4,27 @@ pub(crate) mod clob;
 pub(crate) mod comments;
 pub(crate) mod ctf;
 pub(crate) mod data;
+pub(crate) mod display;
 pub(crate) mod events;
 pub(crate) mod markets;
 pub(crate) mod profiles;
+pub(crate) mod schema;
 pub(crate) mod series;
 pub(crate) mod sports;
 pub(crate) mod tags;
+pub(crate) mod template;
+pub(crate) mod tx;
+
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use chrono::{DateTime, Utc};
 use polymarket_client_sdk::types::Decimal;
-use rust_decimal::prelude::ToPrimitive;
 use tabled::Table;
 use tabled::settings::object::Columns;
 use tabled::settings::{Modify, Style, Width};
 
+use crate::error::ErrorReport;
+
 pub(crate) const DASH: &str = "—";
 
 #[derive(Clone, Copy, Debug, clap::ValueEnum)]
@@ -26,6 +33,25 @@ pub(crate) enum OutputFormat {
     Json,
 }
 
+/// Value of the global `--output` flag. Commands only ever see an
+/// [`OutputFormat`]: templates are rendered from the JSON output.
+#[derive(Clone, Copy, Debug, clap::ValueEnum, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum OutputFlag {
+    Table,
+    Json,
+    Template,
+}
+
+impl OutputFlag {
+    pub(crate) fn format(self) -> OutputFormat {
+        match self {
+            Self::Table => OutputFormat::Table,
+            Self::Json | Self::Template => OutputFormat::Json,
+        }
+    }
+}
+
 pub(crate) fn truncate(s: &str, max: usize) -> String {
     if s.chars().count() <= max {
         return s.to_string();
@@ -36,20 +62,21 @@ pub(crate) fn truncate(s: &str, max: usize) -> String {
 }
 
 pub(crate) fn format_decimal(n: Decimal) -> String {
-    let f = n.to_f64().unwrap_or(0.0);
-    let abs = f.abs();
-    let sign = if f < 0.0 { "-" } else { "" };
-    if abs >= 1_000_000.0 {
-        format!("{sign}${:.1}M", abs / 1_000_000.0)
-    } else if abs >= 1_000.0 {
-        format!("{sign}${:.1}K", abs / 1_000.0)
-    } else {
-        format!("{sign}${abs:.2}")
-    }
+    display::format_decimal_as(n, display::current().numbers)
 }
 
 pub(crate) fn format_date(d: &DateTime<Utc>) -> String {
-    d.format("%Y-%m-%d %H:%M UTC").to_string()
+    format_date_as(d, "%Y-%m-%d %H:%M")
+}
+
+/// `d` in the configured timezone, formatted with `fmt` and followed by the zone.
+pub(crate) fn format_date_as(d: &DateTime<Utc>, fmt: &str) -> String {
+    display::format_date_in(d, display::current().timezone, fmt)
+}
+
+/// A price in the configured `--price-style`, or `default` when none is set.
+pub(crate) fn format_price(p: Decimal, default: display::PriceStyle) -> String {
+    display::format_price_as(p, display::current().price_style.unwrap_or(default))
 }
 
 pub(crate) fn active_status(closed: Option<bool>, active: Option<bool>) -> &'static str {
@@ -62,18 +89,66 @@ pub(crate) fn active_status(closed: Option<bool>, active: Option<bool>) -> &'sta
     }
 }
 
+/// Set by `--envelope`: wrap JSON output as `{"schema_version", "data", "next_cursor"}`.
+static ENVELOPE: AtomicBool = AtomicBool::new(false);
+
+pub(crate) fn set_envelope(enabled: bool) {
+    ENVELOPE.store(enabled, Ordering::Relaxed);
+}
+
 pub(crate) fn print_json(data: &(impl serde::Serialize + ?Sized)) -> anyhow::Result<()> {
+    if template::print_active(data)? {
+        return Ok(());
+    }
+    if ENVELOPE.load(Ordering::Relaxed) {
+        return print_envelope(data, None);
+    }
     println!("{}", serde_json::to_string_pretty(data)?);
     Ok(())
 }
 
-pub(crate) fn print_error(error: &anyhow::Error, format: OutputFormat) {
-    match format {
-        OutputFormat::Json => {
-            println!("{}", serde_json::json!({"error": error.to_string()}));
+/// Print one page of a paginated listing. Without `--envelope` the `page`
+/// object is printed as before; with it, `data` is enveloped and the cursor
+/// is reported as `next_cursor` (`null` on the last page).
+pub(crate) fn print_json_page(
+    data: &(impl serde::Serialize + ?Sized),
+    next_cursor: &str,
+    page: &(impl serde::Serialize + ?Sized),
+) -> anyhow::Result<()> {
+    if template::print_active(data)? {
+        return Ok(());
+    }
+    if ENVELOPE.load(Ordering::Relaxed) {
+        let cursor =
+            (!next_cursor.is_empty() && next_cursor != clob::END_CURSOR).then_some(next_cursor);
+        return print_envelope(data, cursor);
+    }
+    println!("{}", serde_json::to_string_pretty(page)?);
+    Ok(())
+}
+
+fn print_envelope(
+    data: &(impl serde::Serialize + ?Sized),
+    next_cursor: Option<&str>,
+) -> anyhow::Result<()> {
+    let envelope = serde_json::json!({
+        "schema_version": schema::SCHEMA_VERSION,
+        "data": data,
+        "next_cursor": next_cursor,
+    });
+    println!("{}", serde_json::to_string_pretty(&envelope)?);
+    Ok(())
+}
+
+/// Errors always go to stderr so stdout stays parseable. In JSON mode the
+/// error is an object with a stable `code`, see [`crate::error::ErrorCode`].
+pub(crate) fn print_error(report: &ErrorReport, output: OutputFlag) {
+    match output {
+        OutputFlag::Json => {
+            eprintln!("{}", report.to_json());
         }
-        OutputFormat::Table => {
-            eprintln!("Error: {error}");
+        OutputFlag::Table | OutputFlag::Template => {
+            eprintln!("Error: {}", report.message);
         }
     }
 }
@@ -1,6 +1,6 @@
 use polymarket_client_sdk::gamma::types::response::PublicProfile;
 
-use super::{OutputFormat, detail_field, print_detail_table, print_json};
+use super::{OutputFormat, detail_field, format_date, print_detail_table, print_json};
 
 pub fn print_profile(p: &PublicProfile, output: &OutputFormat) -> anyhow::Result<()> {
     if matches!(output, OutputFormat::Json) {
@@ -37,7 +37,7 @@ pub fn print_profile(p: &PublicProfile, output: &OutputFormat) -> anyhow::Result
     detail_field!(
         rows,
         "Created At",
-        p.created_at.map(|d| d.to_string()).unwrap_or_default()
+        p.created_at.as_ref().map(format_date).unwrap_or_default()
     );
 
     print_detail_table(rows);
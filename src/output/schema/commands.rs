@@ -0,0 +1,1087 @@
+use serde_json::Value;
+
+use super::{
+    address, any, array, boolean, bytes32, date_time, decimal, integer, map, nullable, object,
+    object_with_optional, one_of, reference, string, uint256,
+};
+
+/// Shape of a command's JSON output.
+pub(crate) enum Shape {
+    Plain(Value),
+    /// A CLOB page: `{"data": [items], "next_cursor": …, extra…}`. With
+    /// `--envelope` the items become `data` and `next_cursor` moves to the envelope.
+    Page {
+        items: Value,
+        extra: Vec<(&'static str, Value)>,
+    },
+}
+
+pub(crate) struct CommandSchema {
+    pub command: &'static str,
+    pub description: &'static str,
+    pub shape: Shape,
+}
+
+fn plain(command: &'static str, description: &'static str, data: Value) -> CommandSchema {
+    CommandSchema {
+        command,
+        description,
+        shape: Shape::Plain(data),
+    }
+}
+
+fn page(command: &'static str, description: &'static str, items: Value) -> CommandSchema {
+    CommandSchema {
+        command,
+        description,
+        shape: Shape::Page {
+            items,
+            extra: Vec::new(),
+        },
+    }
+}
+
+/// CLOB market listings are printed as the API's page, which also carries
+/// `limit` and `count`.
+fn api_page(command: &'static str, description: &'static str, items: Value) -> CommandSchema {
+    CommandSchema {
+        command,
+        description,
+        shape: Shape::Page {
+            items,
+            extra: vec![("limit", integer()), ("count", integer())],
+        },
+    }
+}
+
+fn tx_result() -> Value {
+    object(&[
+        ("operation", string()),
+        ("sender", address()),
+        ("signature_type", string()),
+        ("transaction_hash", bytes32()),
+        ("block_number", integer()),
+        ("gas_used", integer()),
+        ("fee", decimal()),
+        ("polygonscan", string()),
+    ])
+}
+
+/// One step of `approve set` or `approve revoke`: sent, skipped because
+/// it's already in place, or checked under `--simulate`.
+fn approval_step() -> Value {
+    one_of(&[
+        object(&[
+            ("step", integer()),
+            ("type", string()),
+            ("contract", string()),
+            ("tx_hash", bytes32()),
+            ("gas_used", integer()),
+            ("fee", decimal()),
+        ]),
+        object(&[
+            ("step", integer()),
+            ("type", string()),
+            ("contract", string()),
+            ("skipped", boolean()),
+        ]),
+        object(&[
+            ("step", integer()),
+            ("type", string()),
+            ("contract", string()),
+            ("simulated", boolean()),
+            ("gas_estimate", nullable(integer())),
+            ("revert_reason", nullable(string())),
+        ]),
+    ])
+}
+
+/// A single CTF transaction, or its check under `--simulate`.
+fn tx_or_simulation() -> Value {
+    one_of(&[
+        tx_result(),
+        object(&[
+            ("operation", string()),
+            ("sender", address()),
+            ("signature_type", string()),
+            ("simulated", boolean()),
+            ("success", boolean()),
+            ("gas_estimate", nullable(integer())),
+            ("revert_reason", nullable(string())),
+            (
+                "balance_changes",
+                array(object(&[("asset", string()), ("amount", decimal())])),
+            ),
+        ]),
+    ])
+}
+
+/// A transaction from the journal or the node.
+fn journal_entry(extra: &[(&'static str, Value)]) -> Value {
+    let mut fields = vec![
+        ("hash", bytes32()),
+        ("status", string()),
+        ("from", address()),
+        ("to", address()),
+        ("nonce", integer()),
+        ("gas_limit", integer()),
+        ("max_fee", decimal()),
+        ("priority_fee", decimal()),
+        ("sent_at", nullable(date_time())),
+        ("replaces", nullable(bytes32())),
+        ("polygonscan", string()),
+    ];
+    fields.extend_from_slice(extra);
+    object(&fields)
+}
+
+/// A `tx speedup` or `tx cancel` replacement, or its check under `--simulate`.
+fn replacement() -> Value {
+    one_of(&[
+        object(&[
+            ("operation", string()),
+            ("replaces", bytes32()),
+            ("transaction_hash", bytes32()),
+            ("block_number", integer()),
+            ("gas_used", integer()),
+            ("fee", decimal()),
+            ("polygonscan", string()),
+        ]),
+        object(&[
+            ("operation", string()),
+            ("replaces", bytes32()),
+            ("simulated", boolean()),
+            ("success", boolean()),
+            ("max_fee", decimal()),
+            ("priority_fee", decimal()),
+            ("gas_estimate", nullable(integer())),
+            ("revert_reason", nullable(string())),
+        ]),
+    ])
+}
+
+fn success() -> Value {
+    object(&[("success", boolean())])
+}
+
+fn wallet_saved() -> Value {
+    object_with_optional(
+        &[
+            ("profile", string()),
+            ("address", address()),
+            ("proxy_address", nullable(address())),
+            ("signature_type", string()),
+            ("config_path", string()),
+        ],
+        &[("derivation_path", string()), ("mnemonic", string())],
+    )
+}
+
+fn config_update() -> Value {
+    object(&[
+        ("key", string()),
+        ("value", any()),
+        ("profile", nullable(string())),
+    ])
+}
+
+fn market_token() -> Value {
+    object(&[
+        ("token_id", uint256()),
+        ("outcome", string()),
+        ("price", decimal()),
+        ("winner", boolean()),
+    ])
+}
+
+fn rewards_config() -> Value {
+    object(&[
+        ("asset_address", address()),
+        ("start_date", string()),
+        ("end_date", string()),
+        ("rate_per_day", decimal()),
+        ("total_rewards", decimal()),
+    ])
+}
+
+fn open_order() -> Value {
+    object(&[
+        ("id", string()),
+        ("status", string()),
+        ("market", bytes32()),
+        ("asset_id", uint256()),
+        ("side", string()),
+        ("price", decimal()),
+        ("original_size", decimal()),
+        ("size_matched", decimal()),
+        ("outcome", string()),
+        ("order_type", string()),
+        ("created_at", date_time()),
+        ("expiration", date_time()),
+    ])
+}
+
+fn post_order() -> Value {
+    object(&[
+        ("order_id", string()),
+        ("status", string()),
+        ("success", boolean()),
+        ("error_msg", nullable(string())),
+        ("making_amount", decimal()),
+        ("taking_amount", decimal()),
+        ("transaction_hashes", array(bytes32())),
+        ("trade_ids", array(string())),
+    ])
+}
+
+fn cancel_result() -> Value {
+    object(&[
+        ("canceled", array(string())),
+        ("not_canceled", map(string())),
+    ])
+}
+
+fn market_value() -> Value {
+    object(&[("market", string()), ("value", decimal())])
+}
+
+/// Every command that prints JSON, with the schema of its output.
+#[allow(clippy::too_many_lines)]
+pub(crate) fn registry() -> Vec<CommandSchema> {
+    vec![
+        // ── Gamma ───────────────────────────────────────────────────
+        plain("markets list", "Markets", array(reference("Market"))),
+        plain("markets get", "A market", reference("Market")),
+        plain(
+            "markets search",
+            "Markets matching a query",
+            array(reference("Market")),
+        ),
+        plain("markets tags", "Tags of a market", array(reference("Tag"))),
+        plain("events list", "Events", array(reference("Event"))),
+        plain("events get", "An event", reference("Event")),
+        plain("events tags", "Tags of an event", array(reference("Tag"))),
+        plain("tags list", "Tags", array(reference("Tag"))),
+        plain("tags get", "A tag", reference("Tag")),
+        plain(
+            "tags related",
+            "Relationships of a tag",
+            array(reference("RelatedTag")),
+        ),
+        plain(
+            "tags related-tags",
+            "Tags related to a tag",
+            array(reference("Tag")),
+        ),
+        plain("series list", "Series", array(reference("Series"))),
+        plain("series get", "A series", reference("Series")),
+        plain("comments list", "Comments", array(reference("Comment"))),
+        plain("comments get", "A comment", reference("Comment")),
+        plain(
+            "comments by-user",
+            "Comments by a user",
+            array(reference("Comment")),
+        ),
+        plain(
+            "profiles get",
+            "A public profile",
+            reference("PublicProfile"),
+        ),
+        plain(
+            "sports list",
+            "Sports metadata",
+            array(reference("SportsMetadata")),
+        ),
+        plain(
+            "sports market-types",
+            "Sports market types",
+            reference("SportsMarketTypesResponse"),
+        ),
+        plain("sports teams", "Teams", array(reference("Team"))),
+        plain("status", "API health", object(&[("status", string())])),
+        // ── Approvals ───────────────────────────────────────────────
+        plain(
+            "approve check",
+            "Approval status per wallet and exchange contract",
+            array(object_with_optional(
+                &[
+                    ("owner", address()),
+                    ("signature_type", nullable(string())),
+                    ("contract", string()),
+                    ("address", address()),
+                    ("usdc_allowance", uint256()),
+                    ("usdc_approved", boolean()),
+                    ("ctf_approved", boolean()),
+                ],
+                &[("usdc_error", string()), ("ctf_error", string())],
+            )),
+        ),
+        plain(
+            "approve set",
+            "Approval transactions sent, skipped or checked under --simulate",
+            array(approval_step()),
+        ),
+        plain(
+            "approve revoke",
+            "Revocation transactions sent, skipped or checked under --simulate",
+            array(approval_step()),
+        ),
+        // ── CTF ─────────────────────────────────────────────────────
+        plain("ctf split", "Split transaction", tx_or_simulation()),
+        plain("ctf merge", "Merge transaction", tx_or_simulation()),
+        plain("ctf redeem", "Redeem transaction", tx_or_simulation()),
+        plain(
+            "ctf redeem-neg-risk",
+            "Redeem transaction",
+            tx_or_simulation(),
+        ),
+        plain("ctf transfer", "Transfer transaction", tx_or_simulation()),
+        plain(
+            "ctf condition",
+            "Resolution of a condition on chain, next to the gamma API's",
+            object(&[
+                ("condition_id", bytes32()),
+                ("resolved", boolean()),
+                ("payout_denominator", uint256()),
+                (
+                    "outcomes",
+                    array(object(&[
+                        ("index", integer()),
+                        ("outcome", nullable(string())),
+                        ("payout_numerator", uint256()),
+                        ("payout", nullable(decimal())),
+                    ])),
+                ),
+                ("winning_outcome", nullable(integer())),
+                (
+                    "gamma",
+                    nullable(object(&[
+                        ("market_id", string()),
+                        ("question", nullable(string())),
+                        ("closed", nullable(boolean())),
+                        ("uma_resolution_status", nullable(string())),
+                        ("outcome_prices", nullable(array(decimal()))),
+                        ("winning_outcome", nullable(integer())),
+                    ])),
+                ),
+                ("gamma_error", nullable(string())),
+            ]),
+        ),
+        plain(
+            "ctf merge-all",
+            "Merges of complete outcome sets",
+            object(&[
+                ("address", address()),
+                ("dry_run", boolean()),
+                ("simulated", boolean()),
+                ("total_amount", decimal()),
+                (
+                    "merges",
+                    array(object(&[
+                        ("condition_id", bytes32()),
+                        ("title", string()),
+                        ("neg_risk", boolean()),
+                        ("amount", decimal()),
+                        ("transaction_hash", nullable(bytes32())),
+                        ("block_number", nullable(integer())),
+                        ("gas_used", nullable(integer())),
+                        ("fee", nullable(decimal())),
+                        ("gas_estimate", nullable(integer())),
+                        ("polygonscan", nullable(string())),
+                        ("error", nullable(string())),
+                    ])),
+                ),
+            ]),
+        ),
+        plain(
+            "ctf redeem-all",
+            "Redemptions of every resolved position",
+            object(&[
+                ("address", address()),
+                ("dry_run", boolean()),
+                ("simulated", boolean()),
+                ("total_payout", decimal()),
+                (
+                    "redemptions",
+                    array(object(&[
+                        ("condition_id", bytes32()),
+                        ("title", string()),
+                        ("neg_risk", boolean()),
+                        (
+                            "outcomes",
+                            array(object(&[
+                                ("outcome", string()),
+                                ("outcome_index", integer()),
+                                ("size", decimal()),
+                            ])),
+                        ),
+                        ("payout", decimal()),
+                        ("transaction_hash", nullable(bytes32())),
+                        ("block_number", nullable(integer())),
+                        ("gas_used", nullable(integer())),
+                        ("fee", nullable(decimal())),
+                        ("gas_estimate", nullable(integer())),
+                        ("polygonscan", nullable(string())),
+                        ("error", nullable(string())),
+                    ])),
+                ),
+            ]),
+        ),
+        plain(
+            "ctf condition-id",
+            "Condition ID",
+            object(&[("condition_id", bytes32())]),
+        ),
+        plain(
+            "ctf collection-id",
+            "Collection ID",
+            object(&[("collection_id", bytes32())]),
+        ),
+        plain(
+            "ctf position-id",
+            "Position (token) ID",
+            object(&[("position_id", uint256())]),
+        ),
+        // ── Transactions ────────────────────────────────────────────
+        plain(
+            "tx list",
+            "Journaled transactions, newest first",
+            array(journal_entry(&[])),
+        ),
+        plain(
+            "tx status",
+            "Transaction status",
+            journal_entry(&[("block_number", nullable(integer()))]),
+        ),
+        plain("tx speedup", "Replacement transaction", replacement()),
+        plain("tx cancel", "Replacement transaction", replacement()),
+        // ── Data API ────────────────────────────────────────────────
+        plain(
+            "data positions",
+            "Open positions",
+            array(object(&[
+                ("title", string()),
+                ("slug", string()),
+                ("outcome", string()),
+                ("outcome_index", integer()),
+                ("size", decimal()),
+                ("avg_price", decimal()),
+                ("initial_value", decimal()),
+                ("current_value", decimal()),
+                ("cash_pnl", decimal()),
+                ("percent_pnl", decimal()),
+                ("realized_pnl", decimal()),
+                ("cur_price", decimal()),
+                ("condition_id", bytes32()),
+                ("proxy_wallet", address()),
+                ("redeemable", boolean()),
+                ("mergeable", boolean()),
+            ])),
+        ),
+        plain(
+            "data closed-positions",
+            "Closed positions",
+            array(object(&[
+                ("title", string()),
+                ("slug", string()),
+                ("outcome", string()),
+                ("outcome_index", integer()),
+                ("avg_price", decimal()),
+                ("total_bought", decimal()),
+                ("realized_pnl", decimal()),
+                ("cur_price", decimal()),
+                ("condition_id", bytes32()),
+                ("proxy_wallet", address()),
+                ("timestamp", integer()),
+            ])),
+        ),
+        plain(
+            "data value",
+            "Total position value",
+            array(object(&[("user", address()), ("value", decimal())])),
+        ),
+        plain(
+            "data traded",
+            "Number of markets traded",
+            object(&[("user", address()), ("traded", integer())]),
+        ),
+        plain(
+            "data trades",
+            "Trade history",
+            array(object(&[
+                ("title", string()),
+                ("slug", string()),
+                ("side", string()),
+                ("outcome", string()),
+                ("outcome_index", integer()),
+                ("size", decimal()),
+                ("price", decimal()),
+                ("timestamp", integer()),
+                ("condition_id", bytes32()),
+                ("proxy_wallet", address()),
+                ("transaction_hash", bytes32()),
+            ])),
+        ),
+        plain(
+            "data activity",
+            "On-chain activity",
+            array(object(&[
+                ("activity_type", string()),
+                ("title", string()),
+                ("size", decimal()),
+                ("usdc_size", decimal()),
+                ("timestamp", integer()),
+                ("transaction_hash", bytes32()),
+                ("proxy_wallet", address()),
+            ])),
+        ),
+        plain(
+            "data holders",
+            "Top holders per token",
+            array(object(&[
+                ("token", uint256()),
+                (
+                    "holders",
+                    array(object(&[
+                        ("proxy_wallet", address()),
+                        ("name", string()),
+                        ("pseudonym", nullable(string())),
+                        ("amount", decimal()),
+                        ("outcome_index", integer()),
+                    ])),
+                ),
+            ])),
+        ),
+        plain("data open-interest", "Open interest", array(market_value())),
+        plain(
+            "data volume",
+            "Live volume",
+            array(object(&[
+                ("total", decimal()),
+                ("markets", array(market_value())),
+            ])),
+        ),
+        plain(
+            "data leaderboard",
+            "Trader leaderboard",
+            array(object(&[
+                ("rank", integer()),
+                ("proxy_wallet", address()),
+                ("user_name", nullable(string())),
+                ("pnl", decimal()),
+                ("volume", decimal()),
+            ])),
+        ),
+        plain(
+            "data builder-leaderboard",
+            "Builder leaderboard",
+            array(object(&[
+                ("rank", integer()),
+                ("builder", string()),
+                ("volume", decimal()),
+                ("active_users", integer()),
+                ("verified", boolean()),
+            ])),
+        ),
+        plain(
+            "data builder-volume",
+            "Builder volume time series",
+            array(object(&[
+                ("date", date_time()),
+                ("builder", string()),
+                ("volume", decimal()),
+                ("active_users", integer()),
+                ("rank", integer()),
+                ("verified", boolean()),
+            ])),
+        ),
+        // ── Bridge ──────────────────────────────────────────────────
+        plain(
+            "bridge deposit",
+            "Deposit addresses",
+            object(&[
+                ("evm", address()),
+                ("svm", string()),
+                ("btc", string()),
+                ("note", nullable(string())),
+            ]),
+        ),
+        plain(
+            "bridge supported-assets",
+            "Assets accepted for deposit",
+            array(object(&[
+                ("chain_id", integer()),
+                ("chain_name", string()),
+                ("token_name", string()),
+                ("token_symbol", string()),
+                ("token_address", string()),
+                ("token_decimals", integer()),
+                ("min_checkout_usd", decimal()),
+            ])),
+        ),
+        plain(
+            "bridge status",
+            "Deposit transactions",
+            array(object(&[
+                ("from_chain_id", integer()),
+                ("from_token_address", string()),
+                ("from_amount_base_unit", uint256()),
+                ("to_chain_id", integer()),
+                ("to_token_address", address()),
+                ("status", string()),
+                ("tx_hash", nullable(string())),
+                ("created_time_ms", nullable(integer())),
+            ])),
+        ),
+        // ── Wallet ──────────────────────────────────────────────────
+        plain("wallet create", "The new wallet", wallet_saved()),
+        plain("wallet import", "The imported wallet", wallet_saved()),
+        plain(
+            "wallet derive",
+            "Accounts of a recovery phrase",
+            array(object(&[
+                ("index", integer()),
+                ("derivation_path", string()),
+                ("address", address()),
+                ("proxy_address", nullable(address())),
+            ])),
+        ),
+        plain(
+            "config list",
+            "All settings",
+            array(object(&[
+                ("key", string()),
+                ("value", any()),
+                ("source", string()),
+                ("description", string()),
+            ])),
+        ),
+        plain(
+            "config get",
+            "One setting",
+            object(&[("key", string()), ("value", any()), ("source", string())]),
+        ),
+        plain("config set", "Changed setting", config_update()),
+        plain("config unset", "Removed setting", config_update()),
+        plain(
+            "config edit",
+            "Edited config file",
+            object(&[("path", string())]),
+        ),
+        plain(
+            "wallet encrypt",
+            "Encrypted profile",
+            object(&[("profile", string()), ("keystore", string())]),
+        ),
+        plain(
+            "wallet export",
+            "Decrypted key",
+            object(&[("address", address()), ("private_key", bytes32())]),
+        ),
+        plain(
+            "wallet address",
+            "Wallet address",
+            object(&[("address", address())]),
+        ),
+        plain(
+            "wallet show",
+            "Wallet configuration",
+            object(&[
+                ("profile", string()),
+                ("address", nullable(address())),
+                ("trading_wallet", nullable(address())),
+                ("funder", nullable(address())),
+                ("signature_type", string()),
+                ("chain", string()),
+                ("config_path", string()),
+                ("source", string()),
+                ("signer_url", nullable(string())),
+                ("configured", boolean()),
+            ]),
+        ),
+        plain(
+            "wallet detect",
+            "Wallets of the key and the recommended signature type",
+            object(&[
+                ("address", address()),
+                ("current", string()),
+                ("recommended", string()),
+                ("reason", string()),
+                ("saved", boolean()),
+                (
+                    "wallets",
+                    array(object(&[
+                        ("signature_type", string()),
+                        ("address", address()),
+                        ("deployed", nullable(boolean())),
+                        ("usdc", decimal()),
+                        ("pol", decimal()),
+                    ])),
+                ),
+            ]),
+        ),
+        plain(
+            "wallet balances",
+            "On-chain balances of the key's wallets next to the CLOB's",
+            object(&[
+                (
+                    "wallets",
+                    array(object(&[
+                        ("signature_type", string()),
+                        ("address", address()),
+                        ("trading", boolean()),
+                        (
+                            "balances",
+                            array(object(&[
+                                ("asset", string()),
+                                ("token_id", nullable(uint256())),
+                                ("balance", decimal()),
+                                ("clob_balance", nullable(decimal())),
+                            ])),
+                        ),
+                    ])),
+                ),
+                ("clob_error", nullable(string())),
+            ]),
+        ),
+        plain("wallet send", "Transfer transaction", tx_or_simulation()),
+        plain(
+            "wallet list",
+            "Wallet profiles",
+            array(object(&[
+                ("name", string()),
+                ("address", nullable(address())),
+                ("signature_type", string()),
+                ("funder", nullable(address())),
+                ("encrypted", boolean()),
+                ("signer_url", nullable(string())),
+                ("watch_only", boolean()),
+                ("active", boolean()),
+            ])),
+        ),
+        plain(
+            "wallet set-funder",
+            "Funder of the profile in use",
+            object(&[
+                ("profile", string()),
+                ("funder", nullable(address())),
+                ("verified", boolean()),
+            ]),
+        ),
+        plain(
+            "wallet use",
+            "New active profile",
+            object(&[("active_profile", string())]),
+        ),
+        plain(
+            "wallet rename",
+            "Renamed profile",
+            object(&[("from", string()), ("to", string())]),
+        ),
+        plain(
+            "wallet remove",
+            "Removed profile",
+            object(&[("removed", string()), ("active_profile", string())]),
+        ),
+        plain(
+            "wallet reset",
+            "Reset result",
+            one_of(&[
+                object(&[("reset", boolean()), ("deleted", string())]),
+                object(&[("reset", boolean()), ("reason", string())]),
+            ]),
+        ),
+        // ── CLOB: public ────────────────────────────────────────────
+        plain("clob ok", "CLOB health", object(&[("status", string())])),
+        plain("clob price", "Price", object(&[("price", decimal())])),
+        plain(
+            "clob batch-prices",
+            "Prices by token ID and side",
+            nullable(map(map(decimal()))),
+        ),
+        plain(
+            "clob midpoint",
+            "Midpoint price",
+            object(&[("midpoint", decimal())]),
+        ),
+        plain("clob midpoints", "Midpoints by token ID", map(decimal())),
+        plain("clob spread", "Spread", object(&[("spread", decimal())])),
+        plain(
+            "clob spreads",
+            "Spreads by token ID",
+            nullable(map(decimal())),
+        ),
+        plain(
+            "clob book",
+            "Order book",
+            reference("OrderBookSummaryResponse"),
+        ),
+        plain(
+            "clob books",
+            "Order books",
+            array(reference("OrderBookSummaryResponse")),
+        ),
+        plain(
+            "clob last-trade",
+            "Last trade price",
+            object(&[("price", decimal()), ("side", string())]),
+        ),
+        plain(
+            "clob last-trades",
+            "Last trade prices",
+            array(object(&[
+                ("token_id", uint256()),
+                ("price", decimal()),
+                ("side", string()),
+            ])),
+        ),
+        plain("clob market", "A CLOB market", reference("MarketResponse")),
+        api_page("clob markets", "CLOB markets", reference("MarketResponse")),
+        api_page(
+            "clob sampling-markets",
+            "Markets eligible for rewards",
+            reference("MarketResponse"),
+        ),
+        api_page(
+            "clob simplified-markets",
+            "Simplified CLOB markets",
+            reference("SimplifiedMarketResponse"),
+        ),
+        api_page(
+            "clob sampling-simp-markets",
+            "Simplified markets eligible for rewards",
+            reference("SimplifiedMarketResponse"),
+        ),
+        plain(
+            "clob tick-size",
+            "Minimum tick size",
+            object(&[("minimum_tick_size", decimal())]),
+        ),
+        plain(
+            "clob fee-rate",
+            "Base fee",
+            object(&[("base_fee_bps", integer())]),
+        ),
+        plain(
+            "clob neg-risk",
+            "Negative risk flag",
+            object(&[("neg_risk", boolean())]),
+        ),
+        plain(
+            "clob price-history",
+            "Price history",
+            array(object(&[("timestamp", integer()), ("price", decimal())])),
+        ),
+        plain(
+            "clob time",
+            "Server time",
+            object(&[("timestamp", integer())]),
+        ),
+        plain(
+            "clob geoblock",
+            "Geoblock status",
+            object(&[
+                ("blocked", boolean()),
+                ("ip", string()),
+                ("country", string()),
+                ("region", string()),
+            ]),
+        ),
+        // ── CLOB: orders ────────────────────────────────────────────
+        page("clob orders", "Open orders", open_order()),
+        plain(
+            "clob order",
+            "An order",
+            object(&[
+                ("id", string()),
+                ("status", string()),
+                ("owner", string()),
+                ("maker_address", address()),
+                ("market", bytes32()),
+                ("asset_id", uint256()),
+                ("side", string()),
+                ("price", decimal()),
+                ("original_size", decimal()),
+                ("size_matched", decimal()),
+                ("outcome", string()),
+                ("order_type", string()),
+                ("created_at", date_time()),
+                ("expiration", date_time()),
+                ("associate_trades", array(string())),
+            ]),
+        ),
+        plain("clob create-order", "Order placement result", post_order()),
+        plain(
+            "clob post-orders",
+            "Order placement results",
+            array(post_order()),
+        ),
+        plain("clob market-order", "Order placement result", post_order()),
+        plain("clob cancel", "Cancellation result", cancel_result()),
+        plain("clob cancel-orders", "Cancellation result", cancel_result()),
+        plain("clob cancel-all", "Cancellation result", cancel_result()),
+        plain("clob cancel-market", "Cancellation result", cancel_result()),
+        page(
+            "clob trades",
+            "Trades",
+            object(&[
+                ("id", string()),
+                ("taker_order_id", string()),
+                ("market", bytes32()),
+                ("asset_id", uint256()),
+                ("side", string()),
+                ("size", decimal()),
+                ("price", decimal()),
+                ("fee_rate_bps", decimal()),
+                ("status", string()),
+                ("match_time", date_time()),
+                ("outcome", string()),
+                ("trader_side", string()),
+                ("transaction_hash", bytes32()),
+            ]),
+        ),
+        // ── CLOB: account ───────────────────────────────────────────
+        plain(
+            "clob balance",
+            "Balance and allowances",
+            object(&[("balance", decimal()), ("allowances", map(string()))]),
+        ),
+        plain("clob update-balance", "Acknowledgement", success()),
+        plain(
+            "clob notifications",
+            "Notifications",
+            array(object(&[
+                ("type", integer()),
+                ("question", string()),
+                ("side", string()),
+                ("price", decimal()),
+                ("outcome", string()),
+                ("matched_size", decimal()),
+                ("original_size", decimal()),
+                ("order_id", string()),
+                ("trade_id", string()),
+                ("market", bytes32()),
+            ])),
+        ),
+        plain("clob delete-notifications", "Acknowledgement", success()),
+        page(
+            "clob rewards",
+            "Daily reward earnings",
+            object(&[
+                ("date", string()),
+                ("condition_id", bytes32()),
+                ("asset_address", address()),
+                ("maker_address", address()),
+                ("earnings", decimal()),
+                ("asset_rate", decimal()),
+            ]),
+        ),
+        plain(
+            "clob earnings",
+            "Total earnings per day",
+            array(object(&[
+                ("date", string()),
+                ("asset_address", address()),
+                ("maker_address", address()),
+                ("earnings", decimal()),
+                ("asset_rate", decimal()),
+            ])),
+        ),
+        plain(
+            "clob earnings-markets",
+            "Earnings per market",
+            array(object(&[
+                ("condition_id", bytes32()),
+                ("question", string()),
+                ("market_slug", string()),
+                ("event_slug", string()),
+                ("earning_percentage", decimal()),
+                ("rewards_max_spread", decimal()),
+                ("rewards_min_size", decimal()),
+                ("market_competitiveness", decimal()),
+                ("maker_address", address()),
+                ("tokens", array(market_token())),
+                ("rewards_config", array(rewards_config())),
+                (
+                    "earnings",
+                    array(object(&[
+                        ("asset_address", address()),
+                        ("earnings", decimal()),
+                        ("asset_rate", decimal()),
+                    ])),
+                ),
+            ])),
+        ),
+        plain(
+            "clob reward-percentages",
+            "Reward percentages by market",
+            map(decimal()),
+        ),
+        page(
+            "clob current-rewards",
+            "Active reward programs",
+            object(&[
+                ("condition_id", bytes32()),
+                ("rewards_max_spread", decimal()),
+                ("rewards_min_size", decimal()),
+                ("rewards_config", array(rewards_config())),
+            ]),
+        ),
+        page(
+            "clob market-reward",
+            "Reward programs of a market",
+            object(&[
+                ("condition_id", bytes32()),
+                ("question", string()),
+                ("market_slug", string()),
+                ("event_slug", string()),
+                ("rewards_max_spread", decimal()),
+                ("rewards_min_size", decimal()),
+                ("market_competitiveness", decimal()),
+                ("tokens", array(market_token())),
+                (
+                    "rewards_config",
+                    array(object(&[
+                        ("id", integer()),
+                        ("asset_address", address()),
+                        ("start_date", string()),
+                        ("end_date", string()),
+                        ("rate_per_day", decimal()),
+                        ("total_rewards", decimal()),
+                        ("total_days", decimal()),
+                    ])),
+                ),
+            ]),
+        ),
+        plain(
+            "clob order-scoring",
+            "Whether an order is scoring",
+            object(&[("scoring", boolean())]),
+        ),
+        plain(
+            "clob orders-scoring",
+            "Scoring status by order ID",
+            map(boolean()),
+        ),
+        plain(
+            "clob api-keys",
+            "API keys (debug representation)",
+            object(&[("api_keys", string())]),
+        ),
+        plain("clob delete-api-key", "API response", any()),
+        plain(
+            "clob create-api-key",
+            "The new API key; secrets are redacted",
+            object(&[
+                ("api_key", string()),
+                ("secret", string()),
+                ("passphrase", string()),
+            ]),
+        ),
+        plain(
+            "clob account-status",
+            "Account restrictions",
+            object(&[("closed_only", boolean())]),
+        ),
+    ]
+}
@@ -0,0 +1,532 @@
+//! JSON Schemas for the `-o json` output of every command, served by
+//! `polymarket schema <command>`.
+//!
+//! The schemas are the output contract: when a command's JSON shape changes
+//! (including through an SDK bump), its schema changes with it and
+//! [`SCHEMA_VERSION`] is bumped. The tests below enforce both.
+
+mod commands;
+mod sdk;
+#[cfg(test)]
+pub(crate) mod validate;
+
+use std::collections::BTreeMap;
+
+use serde_json::{Map, Value, json};
+
+pub(crate) use commands::{CommandSchema, Shape, registry};
+
+/// Version of the JSON output contract, reported as `schema_version` in the
+/// `--envelope` output and as `x-schema-version` in every schema document.
+pub(crate) const SCHEMA_VERSION: u32 = 22;
+
+const DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+fn string() -> Value {
+    json!({"type": "string"})
+}
+
+/// Decimals are always serialized as strings to keep their precision.
+fn decimal() -> Value {
+    json!({"type": "string", "format": "decimal"})
+}
+
+fn integer() -> Value {
+    json!({"type": "integer"})
+}
+
+fn number() -> Value {
+    json!({"type": "number"})
+}
+
+fn boolean() -> Value {
+    json!({"type": "boolean"})
+}
+
+fn date_time() -> Value {
+    json!({"type": "string", "format": "date-time"})
+}
+
+fn date() -> Value {
+    json!({"type": "string", "format": "date"})
+}
+
+fn address() -> Value {
+    json!({"type": "string", "pattern": "^0x[0-9a-fA-F]{40}$"})
+}
+
+fn bytes32() -> Value {
+    json!({"type": "string", "pattern": "^0x[0-9a-fA-F]{64}$"})
+}
+
+/// Token IDs and other 256-bit integers, as decimal strings.
+fn uint256() -> Value {
+    json!({"type": "string", "pattern": "^[0-9]+$"})
+}
+
+/// An integer the API sends as a string.
+fn integer_string() -> Value {
+    json!({"type": "string", "pattern": "^-?[0-9]+$"})
+}
+
+/// A string holding JSON-encoded data, as the Gamma API sends `outcomes`.
+fn json_string() -> Value {
+    json!({"type": "string", "contentMediaType": "application/json"})
+}
+
+/// A 256-bit integer as `0x`-prefixed hex, how the SDK serializes `U256`.
+fn hex_uint() -> Value {
+    json!({"type": "string", "pattern": "^0x[0-9a-fA-F]+$"})
+}
+
+/// Any JSON value; used where the CLI passes through an untyped API response.
+fn any() -> Value {
+    json!({})
+}
+
+fn nullable(schema: Value) -> Value {
+    match schema.get("type").and_then(Value::as_str) {
+        Some(ty) => {
+            let mut schema = schema.clone();
+            schema["type"] = json!([ty, "null"]);
+            schema
+        }
+        None => json!({"anyOf": [schema, {"type": "null"}]}),
+    }
+}
+
+/// Values the API sends as `""` when absent.
+fn or_empty(schema: Value) -> Value {
+    json!({"anyOf": [schema, {"const": ""}]})
+}
+
+fn array(items: Value) -> Value {
+    json!({"type": "array", "items": items})
+}
+
+/// A JSON object keyed by arbitrary strings (token IDs, addresses, …).
+fn map(values: Value) -> Value {
+    json!({"type": "object", "additionalProperties": values})
+}
+
+/// An object with exactly these fields, all of which are always present.
+fn object(fields: &[(&str, Value)]) -> Value {
+    object_with_optional(fields, &[])
+}
+
+/// Like [`object`], plus `optional` fields that are omitted when not applicable.
+fn object_with_optional(fields: &[(&str, Value)], optional: &[(&str, Value)]) -> Value {
+    let properties: Map<String, Value> = fields
+        .iter()
+        .chain(optional)
+        .map(|(name, schema)| ((*name).to_string(), schema.clone()))
+        .collect();
+    let required: Vec<&str> = fields.iter().map(|(name, _)| *name).collect();
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
+fn one_of(schemas: &[Value]) -> Value {
+    json!({"oneOf": schemas})
+}
+
+/// Reference to a shared definition from [`sdk::definitions`].
+fn reference(name: &str) -> Value {
+    json!({"$ref": format!("#/$defs/{name}")})
+}
+
+/// Wrapper printed with `--envelope`.
+fn envelope(data: Value) -> Value {
+    object(&[
+        (
+            "schema_version",
+            json!({"type": "integer", "const": SCHEMA_VERSION}),
+        ),
+        ("data", data),
+        ("next_cursor", nullable(string())),
+    ])
+}
+
+impl CommandSchema {
+    /// The schema of the command's JSON output, as printed with or without `--envelope`.
+    fn output(&self, enveloped: bool) -> Value {
+        match (&self.shape, enveloped) {
+            (Shape::Plain(data), false) => data.clone(),
+            (Shape::Plain(data), true) => envelope(data.clone()),
+            (Shape::Page { items, extra }, false) => {
+                let mut fields = vec![("data", array(items.clone())), ("next_cursor", string())];
+                fields.extend(extra.iter().cloned());
+                object(&fields)
+            }
+            (Shape::Page { items, .. }, true) => envelope(array(items.clone())),
+        }
+    }
+
+    /// A standalone JSON Schema document, with the shared definitions it uses.
+    pub(crate) fn document(&self, enveloped: bool) -> Value {
+        let output = self.output(enveloped);
+        let definitions = sdk::definitions();
+        let mut used = BTreeMap::new();
+        collect_refs(&output, &definitions, &mut used);
+
+        let mut doc = json!({
+            "$schema": DRAFT,
+            "title": format!("polymarket {}", self.command),
+            "description": self.description,
+            "x-schema-version": SCHEMA_VERSION,
+        });
+        if let (Value::Object(doc), Value::Object(output)) = (&mut doc, output) {
+            doc.extend(output);
+        }
+        if !used.is_empty() {
+            doc["$defs"] = json!(used);
+        }
+        doc
+    }
+}
+
+/// Gather every definition reachable from `schema`.
+fn collect_refs(
+    schema: &Value,
+    definitions: &BTreeMap<&'static str, Value>,
+    used: &mut BTreeMap<&'static str, Value>,
+) {
+    match schema {
+        Value::Object(fields) => {
+            if let Some(Value::String(target)) = fields.get("$ref") {
+                let name = target.trim_start_matches("#/$defs/");
+                if let Some((name, def)) = definitions.get_key_value(name)
+                    && !used.contains_key(name)
+                {
+                    used.insert(name, def.clone());
+                    collect_refs(def, definitions, used);
+                }
+            }
+            for value in fields.values() {
+                collect_refs(value, definitions, used);
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_refs(item, definitions, used);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Look up the schema for a command given as words, e.g. `["markets", "list"]`.
+pub(crate) fn find(command: &[String]) -> Option<CommandSchema> {
+    let name = command.join(" ");
+    registry().into_iter().find(|c| c.command == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+    use serde::Serialize;
+    use serde::de::DeserializeOwned;
+
+    use super::validate::validate;
+
+    /// Fingerprint of the published schemas for each `SCHEMA_VERSION`.
+    ///
+    /// When `schema_changes_bump_version` fails, the JSON output contract has
+    /// changed: bump `SCHEMA_VERSION` and append the new fingerprint here.
+    const PUBLISHED: &[(u32, u64)] = &[
+        (1, 0x3d4cf859179c5397),
+        (2, 0xff6e9a8d33359d2c),
+        (3, 0xf427a4075118e407),
+        (4, 0xe6e743f7e1eb4563),
+        (5, 0x3156e348a6266a51),
+        (6, 0x1e5d345da28ade6a),
+        (7, 0x8f21e2fe145b40c6),
+        (8, 0xe84a74418c301065),
+        (9, 0x51b42a14799a5e74),
+        (10, 0xcdfb7c1e8b61e0c1),
+        (11, 0x5271fec146c08b3a),
+        (12, 0x241e1591b22e7b42),
+        (13, 0x26ceecc5f5de2ef0),
+        (14, 0x398659d098e1266c),
+        (15, 0x46c839112d55169c),
+        (16, 0x29446c8d224d205e),
+        (17, 0xbb8407cba84a1aa7),
+        (18, 0x497ff3f3b70e2b43),
+        (19, 0xa887f4fe45935d0c),
+        (20, 0x74c5d581061a8c01),
+        (21, 0x82f1a4ad250630cc),
+        (22, 0x2a0b5ff4c75079ed),
+    ];
+
+    /// Commands that never print JSON.
+    const NO_JSON_OUTPUT: &[&str] = &["setup", "shell", "upgrade", "schema"];
+
+    fn fingerprint() -> u64 {
+        // FNV-1a: stable across Rust releases, unlike `DefaultHasher`.
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for command in registry() {
+            let text = format!(
+                "{}{}{}",
+                command.command,
+                command.document(false),
+                command.document(true)
+            );
+            for byte in text.bytes() {
+                hash ^= u64::from(byte);
+                hash = hash.wrapping_mul(0x0100_0000_01b3);
+            }
+        }
+        hash
+    }
+
+    #[test]
+    fn schema_changes_bump_version() {
+        let fingerprint = fingerprint();
+        let &(version, published) = PUBLISHED.last().unwrap();
+        assert_eq!(
+            (SCHEMA_VERSION, fingerprint),
+            (version, published),
+            "JSON output schemas changed (fingerprint {fingerprint:#x}): \
+             bump SCHEMA_VERSION and add it to PUBLISHED"
+        );
+        assert!(
+            PUBLISHED
+                .windows(2)
+                .all(|w| w[0].0 < w[1].0 && w[0].1 != w[1].1),
+            "each schema version needs its own fingerprint"
+        );
+    }
+
+    fn leaf_commands(cmd: &clap::Command, prefix: &str, out: &mut Vec<String>) {
+        for sub in cmd.get_subcommands() {
+            let name = format!("{prefix}{}", sub.get_name());
+            if sub.has_subcommands() {
+                leaf_commands(sub, &format!("{name} "), out);
+            } else {
+                out.push(name);
+            }
+        }
+    }
+
+    #[test]
+    fn every_command_has_a_schema() {
+        let mut leaves = Vec::new();
+        leaf_commands(&crate::Cli::command(), "", &mut leaves);
+        let leaves: Vec<String> = leaves
+            .into_iter()
+            .filter(|c| !NO_JSON_OUTPUT.contains(&c.as_str()))
+            .collect();
+        let registered: Vec<&str> = registry().iter().map(|c| c.command).collect::<Vec<_>>();
+        let registered: Vec<String> = registered.iter().map(|s| (*s).to_string()).collect();
+
+        for leaf in &leaves {
+            assert!(registered.contains(leaf), "no schema for `{leaf}`");
+        }
+        for command in &registered {
+            assert!(
+                leaves.contains(command),
+                "schema for unknown command `{command}`"
+            );
+        }
+    }
+
+    #[test]
+    fn references_resolve() {
+        for command in registry() {
+            for enveloped in [false, true] {
+                let doc = command.document(enveloped);
+                let defs = doc.get("$defs").cloned().unwrap_or_default();
+                let mut refs = Vec::new();
+                find_refs(&doc, &mut refs);
+                for r in refs {
+                    let name = r.trim_start_matches("#/$defs/");
+                    assert!(
+                        defs.get(name).is_some(),
+                        "{}: dangling {r}",
+                        command.command
+                    );
+                }
+            }
+        }
+    }
+
+    fn find_refs(v: &Value, out: &mut Vec<String>) {
+        match v {
+            Value::Object(m) => {
+                if let Some(Value::String(r)) = m.get("$ref") {
+                    out.push(r.clone());
+                }
+                m.values().for_each(|v| find_refs(v, out));
+            }
+            Value::Array(a) => a.iter().for_each(|v| find_refs(v, out)),
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn find_by_command_words() {
+        let words = |s: &str| s.split(' ').map(String::from).collect::<Vec<_>>();
+        assert!(find(&words("markets list")).is_some());
+        assert!(find(&words("clob orders")).is_some());
+        assert!(find(&words("markets nope")).is_none());
+    }
+
+    #[test]
+    fn envelope_wraps_page_items() {
+        let orders = find(&["clob".into(), "orders".into()]).unwrap();
+        let plain = orders.document(false);
+        assert_eq!(plain["properties"]["next_cursor"]["type"], "string");
+        let enveloped = orders.document(true);
+        assert_eq!(
+            enveloped["properties"]["schema_version"]["const"],
+            SCHEMA_VERSION
+        );
+        assert_eq!(enveloped["properties"]["data"]["type"], "array");
+        assert_eq!(
+            enveloped["properties"]["next_cursor"]["type"],
+            json!(["string", "null"])
+        );
+    }
+
+    // ── Conformance ─────────────────────────────────────────────────
+
+    /// Build a value that satisfies `schema`, with every optional value present
+    /// so the whole shape is exercised. Recursive definitions (events contain
+    /// markets, series contain events, …) stop at empty arrays and nulls.
+    fn sample(root: &Value, schema: &Value, depth: usize) -> Value {
+        const MAX_DEPTH: usize = 3;
+        if let Some(Value::String(r)) = schema.get("$ref") {
+            let def = &root["$defs"][r.trim_start_matches("#/$defs/")];
+            return sample(root, def, depth + 1);
+        }
+        if let Some(Value::Array(options)) = schema.get("anyOf") {
+            if depth >= MAX_DEPTH {
+                return Value::Null;
+            }
+            return sample(root, &options[0], depth);
+        }
+        if let Some(Value::Array(values)) = schema.get("enum") {
+            return values[0].clone();
+        }
+        let ty = match schema.get("type") {
+            Some(Value::String(t)) => t.as_str(),
+            Some(Value::Array(ts)) if depth >= MAX_DEPTH && ts.contains(&json!("null")) => {
+                return Value::Null;
+            }
+            Some(Value::Array(ts)) => ts[0].as_str().unwrap(),
+            _ => return Value::Null,
+        };
+        match ty {
+            "string" => match (
+                schema.get("format").and_then(Value::as_str),
+                schema.get("pattern").and_then(Value::as_str),
+            ) {
+                (Some("decimal"), _) => json!("0.5"),
+                _ if schema.get("contentMediaType").is_some() => json!("[]"),
+                (Some("date-time"), _) => json!("2024-11-05T12:00:00Z"),
+                (Some("date"), _) => json!("2024-11-05"),
+                (_, Some("^0x[0-9a-fA-F]{40}$")) => {
+                    json!("0x4D97DCd97eC945f40cF65F87097ACe5EA0476045")
+                }
+                (_, Some("^0x[0-9a-fA-F]{64}$")) => json!(format!("0x{}", "ab".repeat(32))),
+                (_, Some("^[0-9]+$" | "^-?[0-9]+$")) => json!("123"),
+                (_, Some("^0x[0-9a-fA-F]+$")) => json!("0x7b"),
+                _ => json!("text"),
+            },
+            "integer" => json!(7),
+            "number" => json!(1.5),
+            "boolean" => json!(true),
+            "array" if depth >= MAX_DEPTH => json!([]),
+            "array" => json!([sample(root, &schema["items"], depth)]),
+            "object" => match schema.get("properties").and_then(Value::as_object) {
+                Some(properties) => properties
+                    .iter()
+                    .map(|(name, s)| (name.clone(), sample(root, s, depth)))
+                    .collect::<Map<_, _>>()
+                    .into(),
+                None => json!({}),
+            },
+            _ => Value::Null,
+        }
+    }
+
+    /// Deserialize a sample of definition `name` into `T`, serialize it back,
+    /// and check the result against the definition. Fails when the SDK type
+    /// gains, loses, renames or retypes a field.
+    fn round_trip<T: Serialize + DeserializeOwned>(name: &str) {
+        let defs: Map<String, Value> = sdk::definitions()
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect();
+        let root = json!({"$defs": defs, "$ref": format!("#/$defs/{name}")});
+        let input = sample(&root, &root, 0);
+        let parsed: T = serde_json::from_value(input.clone())
+            .unwrap_or_else(|e| panic!("{name}: sample does not deserialize: {e}\n{input:#}"));
+        let output = serde_json::to_value(&parsed).unwrap();
+        if let Err(e) = validate(&root, &output) {
+            panic!("{name} no longer matches its schema: {e}");
+        }
+    }
+
+    #[test]
+    fn sdk_types_match_definitions() {
+        use polymarket_client_sdk::clob::types::response as clob;
+        use polymarket_client_sdk::gamma::types::response as gamma;
+
+        round_trip::<gamma::Market>("Market");
+        round_trip::<gamma::Event>("Event");
+        round_trip::<gamma::Series>("Series");
+        round_trip::<gamma::Tag>("Tag");
+        round_trip::<gamma::RelatedTag>("RelatedTag");
+        round_trip::<gamma::Comment>("Comment");
+        round_trip::<gamma::PublicProfile>("PublicProfile");
+        round_trip::<gamma::SportsMetadata>("SportsMetadata");
+        round_trip::<gamma::SportsMarketTypesResponse>("SportsMarketTypesResponse");
+        round_trip::<gamma::Team>("Team");
+        round_trip::<clob::OrderBookSummaryResponse>("OrderBookSummaryResponse");
+        round_trip::<clob::MarketResponse>("MarketResponse");
+        round_trip::<clob::SimplifiedMarketResponse>("SimplifiedMarketResponse");
+    }
+
+    #[test]
+    fn every_definition_is_round_tripped() {
+        // Nested definitions are covered through their parents.
+        let top_level = [
+            "Market",
+            "Event",
+            "Series",
+            "Tag",
+            "RelatedTag",
+            "Comment",
+            "PublicProfile",
+            "SportsMetadata",
+            "SportsMarketTypesResponse",
+            "Team",
+            "OrderBookSummaryResponse",
+            "MarketResponse",
+            "SimplifiedMarketResponse",
+        ];
+        let defs = sdk::definitions();
+        let mut reachable = BTreeMap::new();
+        for name in top_level {
+            collect_refs(&reference(name), &defs, &mut reachable);
+        }
+        for name in defs.keys() {
+            assert!(reachable.contains_key(name), "{name} is not round-tripped");
+        }
+    }
+
+    #[test]
+    fn validator_rejects_shape_changes() {
+        let doc = json!({"type": "object", "properties": {"a": string()}, "required": ["a"], "additionalProperties": false});
+        assert!(validate(&doc, &json!({"a": "x"})).is_ok());
+        assert!(validate(&doc, &json!({})).is_err());
+        assert!(validate(&doc, &json!({"a": "x", "b": 1})).is_err());
+        assert!(validate(&doc, &json!({"a": 1})).is_err());
+    }
+}
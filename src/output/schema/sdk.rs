@@ -0,0 +1,688 @@
+//! Definitions for the SDK response types that commands print as-is.
+//!
+//! Property names and types follow the types' `Serialize` impls; the
+//! `sdk_types_match_definitions` test keeps them in sync with the SDK.
+
+use std::collections::BTreeMap;
+
+use serde_json::{Value, json};
+
+use super::{
+    address, array, boolean, bytes32, date, date_time, decimal, hex_uint, integer, integer_string,
+    json_string, nullable, number, object, or_empty, reference, string,
+};
+
+fn tick_size() -> Value {
+    let mut schema = decimal();
+    schema["enum"] = json!(["0.1", "0.01", "0.001", "0.0001"]);
+    schema
+}
+
+#[allow(clippy::too_many_lines)]
+pub(super) fn definitions() -> BTreeMap<&'static str, Value> {
+    let mut defs = BTreeMap::new();
+    defs.insert(
+        "Market",
+        object(&[
+            ("id", string()),
+            ("question", nullable(string())),
+            ("conditionId", or_empty(bytes32())),
+            ("slug", nullable(string())),
+            ("twitterCardImage", nullable(string())),
+            ("resolutionSource", nullable(string())),
+            ("endDate", nullable(date_time())),
+            ("category", nullable(string())),
+            ("ammType", nullable(string())),
+            ("liquidity", nullable(decimal())),
+            ("sponsorName", nullable(string())),
+            ("sponsorImage", nullable(string())),
+            ("startDate", nullable(date_time())),
+            ("xAxisValue", nullable(string())),
+            ("yAxisValue", nullable(string())),
+            ("denominationToken", nullable(hex_uint())),
+            ("fee", nullable(decimal())),
+            ("image", nullable(string())),
+            ("icon", nullable(string())),
+            ("lowerBound", nullable(string())),
+            ("upperBound", nullable(string())),
+            ("description", nullable(string())),
+            ("outcomes", nullable(json_string())),
+            ("outcomePrices", nullable(json_string())),
+            ("volume", nullable(decimal())),
+            ("active", nullable(boolean())),
+            ("marketType", nullable(string())),
+            ("formatType", nullable(string())),
+            ("lowerBoundDate", nullable(date())),
+            ("upperBoundDate", nullable(date())),
+            ("closed", nullable(boolean())),
+            ("marketMakerAddress", or_empty(address())),
+            ("createdBy", nullable(integer())),
+            ("updatedBy", nullable(integer())),
+            ("createdAt", nullable(date_time())),
+            ("updatedAt", nullable(date_time())),
+            ("closedTime", nullable(string())),
+            ("wideFormat", nullable(boolean())),
+            ("new", nullable(boolean())),
+            ("mailchimpTag", nullable(string())),
+            ("featured", nullable(boolean())),
+            ("archived", nullable(boolean())),
+            ("resolvedBy", nullable(string())),
+            ("restricted", nullable(boolean())),
+            ("marketGroup", nullable(integer())),
+            ("groupItemTitle", nullable(string())),
+            ("groupItemThreshold", nullable(string())),
+            ("questionID", or_empty(bytes32())),
+            ("umaEndDate", nullable(string())),
+            ("enableOrderBook", nullable(boolean())),
+            ("orderPriceMinTickSize", nullable(decimal())),
+            ("orderMinSize", nullable(decimal())),
+            ("umaResolutionStatus", nullable(string())),
+            ("curationOrder", nullable(integer())),
+            ("volumeNum", nullable(decimal())),
+            ("liquidityNum", nullable(decimal())),
+            ("endDateIso", nullable(date())),
+            ("startDateIso", nullable(date())),
+            ("umaEndDateIso", nullable(date())),
+            ("hasReviewedDates", nullable(boolean())),
+            ("readyForCron", nullable(boolean())),
+            ("commentsEnabled", nullable(boolean())),
+            ("volume24hr", nullable(decimal())),
+            ("volume1wk", nullable(decimal())),
+            ("volume1mo", nullable(decimal())),
+            ("volume1yr", nullable(decimal())),
+            ("gameStartTime", nullable(string())),
+            ("secondsDelay", nullable(integer())),
+            ("clobTokenIds", nullable(json_string())),
+            ("disqusThread", nullable(string())),
+            ("shortOutcomes", nullable(string())),
+            ("teamAID", nullable(string())),
+            ("teamBID", nullable(string())),
+            ("umaBond", nullable(string())),
+            ("umaReward", nullable(decimal())),
+            ("fpmmLive", nullable(boolean())),
+            ("volume24hrAmm", nullable(decimal())),
+            ("volume1wkAmm", nullable(decimal())),
+            ("volume1moAmm", nullable(decimal())),
+            ("volume1yrAmm", nullable(decimal())),
+            ("volume24hrClob", nullable(decimal())),
+            ("volume1wkClob", nullable(decimal())),
+            ("volume1moClob", nullable(decimal())),
+            ("volume1yrClob", nullable(decimal())),
+            ("volumeAmm", nullable(decimal())),
+            ("volumeClob", nullable(decimal())),
+            ("liquidityAmm", nullable(decimal())),
+            ("liquidityClob", nullable(decimal())),
+            ("makerBaseFee", nullable(integer())),
+            ("takerBaseFee", nullable(integer())),
+            ("makerRebatesFeeShareBps", nullable(integer())),
+            ("customLiveness", nullable(integer())),
+            ("acceptingOrders", nullable(boolean())),
+            ("notificationsEnabled", nullable(boolean())),
+            ("score", nullable(integer())),
+            ("imageOptimized", nullable(reference("ImageOptimization"))),
+            ("iconOptimized", nullable(reference("ImageOptimization"))),
+            ("events", nullable(array(reference("Event")))),
+            ("categories", nullable(array(reference("Category")))),
+            ("tags", nullable(array(reference("Tag")))),
+            ("creator", nullable(string())),
+            ("ready", nullable(boolean())),
+            ("funded", nullable(boolean())),
+            ("pastSlugs", nullable(string())),
+            ("readyTimestamp", nullable(date_time())),
+            ("fundedTimestamp", nullable(date_time())),
+            ("acceptingOrdersTimestamp", nullable(date_time())),
+            ("competitive", nullable(decimal())),
+            ("rewardsMinSize", nullable(decimal())),
+            ("rewardsMaxSpread", nullable(decimal())),
+            ("spread", nullable(decimal())),
+            ("automaticallyResolved", nullable(boolean())),
+            ("oneDayPriceChange", nullable(decimal())),
+            ("oneHourPriceChange", nullable(decimal())),
+            ("oneWeekPriceChange", nullable(decimal())),
+            ("oneMonthPriceChange", nullable(decimal())),
+            ("oneYearPriceChange", nullable(decimal())),
+            ("lastTradePrice", nullable(decimal())),
+            ("bestBid", nullable(decimal())),
+            ("bestAsk", nullable(decimal())),
+            ("automaticallyActive", nullable(boolean())),
+            ("clearBookOnStart", nullable(boolean())),
+            ("chartColor", nullable(string())),
+            ("seriesColor", nullable(string())),
+            ("showGmpSeries", nullable(boolean())),
+            ("showGmpOutcome", nullable(boolean())),
+            ("manualActivation", nullable(boolean())),
+            ("negRiskOther", nullable(boolean())),
+            ("gameId", nullable(string())),
+            ("groupItemRange", nullable(string())),
+            ("sportsMarketType", nullable(string())),
+            ("line", nullable(decimal())),
+            ("umaResolutionStatuses", nullable(string())),
+            ("pendingDeployment", nullable(boolean())),
+            ("deploying", nullable(boolean())),
+            ("deployingTimestamp", nullable(date_time())),
+            ("scheduledDeploymentTimestamp", nullable(date_time())),
+            ("rfqEnabled", nullable(boolean())),
+            ("eventStartTime", nullable(date_time())),
+            ("submittedBy", nullable(string())),
+            ("requiresTranslation", nullable(boolean())),
+            ("pagerDutyNotificationEnabled", nullable(boolean())),
+            ("approved", nullable(boolean())),
+            ("cyom", nullable(boolean())),
+            ("feesEnabled", nullable(boolean())),
+            ("holdingRewardsEnabled", nullable(boolean())),
+            ("negRisk", nullable(boolean())),
+            ("negRiskRequestID", or_empty(bytes32())),
+            ("negRiskMarketID", or_empty(bytes32())),
+            ("sentDiscord", nullable(boolean())),
+            ("twitterCardLastRefreshed", nullable(integer_string())),
+            ("twitterCardLocation", nullable(string())),
+            ("twitterCardLastValidated", nullable(string())),
+            ("clobRewards", nullable(array(reference("ClobReward")))),
+            ("categoryMailchimpTag", nullable(string())),
+            ("subcategory", nullable(string())),
+        ]),
+    );
+    defs.insert(
+        "Event",
+        object(&[
+            ("id", string()),
+            ("ticker", nullable(string())),
+            ("slug", nullable(string())),
+            ("title", nullable(string())),
+            ("subtitle", nullable(string())),
+            ("description", nullable(string())),
+            ("resolutionSource", nullable(string())),
+            ("startDate", nullable(date_time())),
+            ("creationDate", nullable(date_time())),
+            ("endDate", nullable(date_time())),
+            ("image", nullable(string())),
+            ("icon", nullable(string())),
+            ("active", nullable(boolean())),
+            ("closed", nullable(boolean())),
+            ("archived", nullable(boolean())),
+            ("new", nullable(boolean())),
+            ("featured", nullable(boolean())),
+            ("restricted", nullable(boolean())),
+            ("liquidity", nullable(decimal())),
+            ("volume", nullable(decimal())),
+            ("openInterest", nullable(decimal())),
+            ("sortBy", nullable(string())),
+            ("category", nullable(string())),
+            ("subcategory", nullable(string())),
+            ("isTemplate", nullable(boolean())),
+            ("templateVariables", nullable(string())),
+            ("publishedAt", nullable(string())),
+            ("createdBy", nullable(string())),
+            ("updatedBy", nullable(string())),
+            ("createdAt", nullable(date_time())),
+            ("updatedAt", nullable(date_time())),
+            ("commentsEnabled", nullable(boolean())),
+            ("competitive", nullable(decimal())),
+            ("volume24hr", nullable(decimal())),
+            ("volume1wk", nullable(decimal())),
+            ("volume1mo", nullable(decimal())),
+            ("volume1yr", nullable(decimal())),
+            ("featuredImage", nullable(string())),
+            ("disqusThread", nullable(string())),
+            ("parentEvent", nullable(string())),
+            ("parentEventId", nullable(string())),
+            ("sportsradarMatchId", nullable(string())),
+            ("turnProviderId", nullable(string())),
+            ("enableOrderBook", nullable(boolean())),
+            ("liquidityAmm", nullable(decimal())),
+            ("liquidityClob", nullable(decimal())),
+            ("negRisk", nullable(boolean())),
+            ("negRiskMarketID", or_empty(bytes32())),
+            ("negRiskFeeBips", nullable(integer())),
+            ("commentCount", nullable(integer())),
+            ("imageOptimized", nullable(reference("ImageOptimization"))),
+            ("iconOptimized", nullable(reference("ImageOptimization"))),
+            (
+                "featuredImageOptimized",
+                nullable(reference("ImageOptimization")),
+            ),
+            ("subEvents", nullable(array(string()))),
+            ("markets", nullable(array(reference("Market")))),
+            ("series", nullable(array(reference("Series")))),
+            ("categories", nullable(array(reference("Category")))),
+            ("collections", nullable(array(reference("Collection")))),
+            ("tags", nullable(array(reference("Tag")))),
+            ("cyom", nullable(boolean())),
+            ("closedTime", nullable(date_time())),
+            ("showAllOutcomes", nullable(boolean())),
+            ("showMarketImages", nullable(boolean())),
+            ("automaticallyResolved", nullable(boolean())),
+            ("enableNegRisk", nullable(boolean())),
+            ("automaticallyActive", nullable(boolean())),
+            ("eventDate", nullable(date())),
+            ("startTime", nullable(date_time())),
+            ("eventWeek", nullable(integer())),
+            ("seriesSlug", nullable(string())),
+            ("score", nullable(string())),
+            ("elapsed", nullable(string())),
+            ("period", nullable(string())),
+            ("live", nullable(boolean())),
+            ("ended", nullable(boolean())),
+            ("finishedTimestamp", nullable(date_time())),
+            ("gmpChartMode", nullable(string())),
+            ("eventCreators", nullable(array(reference("EventCreator")))),
+            ("tweetCount", nullable(integer())),
+            ("chats", nullable(array(reference("Chat")))),
+            ("featuredOrder", nullable(integer())),
+            ("estimateValue", nullable(boolean())),
+            ("cantEstimate", nullable(boolean())),
+            ("estimatedValue", nullable(string())),
+            ("templates", nullable(array(reference("Template")))),
+            ("spreadsMainLine", nullable(decimal())),
+            ("totalsMainLine", nullable(decimal())),
+            ("carouselMap", nullable(string())),
+            ("pendingDeployment", nullable(boolean())),
+            ("deploying", nullable(boolean())),
+            ("deployingTimestamp", nullable(date_time())),
+            ("scheduledDeploymentTimestamp", nullable(date_time())),
+            ("gameStatus", nullable(string())),
+            ("requiresTranslation", nullable(boolean())),
+            ("negRiskAugmented", nullable(boolean())),
+            ("gameId", nullable(integer())),
+            ("electionType", nullable(string())),
+            ("countryName", nullable(string())),
+            ("color", nullable(string())),
+            ("cumulativeMarkets", nullable(boolean())),
+            ("awayTeamName", nullable(string())),
+            ("homeTeamName", nullable(string())),
+        ]),
+    );
+    defs.insert(
+        "Series",
+        object(&[
+            ("id", string()),
+            ("ticker", nullable(string())),
+            ("slug", nullable(string())),
+            ("title", nullable(string())),
+            ("subtitle", nullable(string())),
+            ("seriesType", nullable(string())),
+            ("recurrence", nullable(string())),
+            ("description", nullable(string())),
+            ("image", nullable(string())),
+            ("icon", nullable(string())),
+            ("layout", nullable(string())),
+            ("active", nullable(boolean())),
+            ("closed", nullable(boolean())),
+            ("archived", nullable(boolean())),
+            ("new", nullable(boolean())),
+            ("featured", nullable(boolean())),
+            ("restricted", nullable(boolean())),
+            ("isTemplate", nullable(boolean())),
+            ("templateVariables", nullable(boolean())),
+            ("publishedAt", nullable(string())),
+            ("createdBy", nullable(string())),
+            ("updatedBy", nullable(string())),
+            ("createdAt", nullable(date_time())),
+            ("updatedAt", nullable(date_time())),
+            ("commentsEnabled", nullable(boolean())),
+            ("competitive", nullable(decimal())),
+            ("volume24hr", nullable(decimal())),
+            ("volume", nullable(decimal())),
+            ("liquidity", nullable(decimal())),
+            ("startDate", nullable(date_time())),
+            ("pythTokenID", nullable(string())),
+            ("cgAssetName", nullable(string())),
+            ("score", nullable(integer())),
+            ("events", nullable(array(reference("Event")))),
+            ("collections", nullable(array(reference("Collection")))),
+            ("categories", nullable(array(reference("Category")))),
+            ("tags", nullable(array(reference("Tag")))),
+            ("commentCount", nullable(integer())),
+            ("chats", nullable(array(reference("Chat")))),
+            ("requiresTranslation", nullable(boolean())),
+        ]),
+    );
+    defs.insert(
+        "Tag",
+        object(&[
+            ("id", string()),
+            ("label", nullable(string())),
+            ("slug", nullable(string())),
+            ("forceShow", nullable(boolean())),
+            ("publishedAt", nullable(string())),
+            ("createdBy", nullable(integer())),
+            ("updatedBy", nullable(integer())),
+            ("createdAt", nullable(date_time())),
+            ("updatedAt", nullable(date_time())),
+            ("forceHide", nullable(boolean())),
+            ("isCarousel", nullable(boolean())),
+            ("requiresTranslation", nullable(boolean())),
+        ]),
+    );
+    defs.insert(
+        "RelatedTag",
+        object(&[
+            ("id", string()),
+            ("tagID", nullable(string())),
+            ("relatedTagID", nullable(string())),
+            ("rank", nullable(integer())),
+        ]),
+    );
+    defs.insert(
+        "Comment",
+        object(&[
+            ("id", string()),
+            ("body", nullable(string())),
+            ("parentEntityType", nullable(string())),
+            ("parentEntityID", nullable(integer())),
+            ("parentCommentID", nullable(string())),
+            ("userAddress", or_empty(address())),
+            ("replyAddress", or_empty(address())),
+            ("createdAt", nullable(date_time())),
+            ("updatedAt", nullable(date_time())),
+            ("profile", nullable(reference("CommentProfile"))),
+            ("reactions", nullable(array(reference("Reaction")))),
+            ("reportCount", nullable(integer())),
+            ("reactionCount", nullable(integer())),
+        ]),
+    );
+    defs.insert(
+        "PublicProfile",
+        object(&[
+            ("createdAt", nullable(date_time())),
+            ("proxyWallet", or_empty(address())),
+            ("profileImage", nullable(string())),
+            ("displayUsernamePublic", nullable(boolean())),
+            ("bio", nullable(string())),
+            ("pseudonym", nullable(string())),
+            ("name", nullable(string())),
+            ("users", nullable(array(reference("PublicProfileUser")))),
+            ("xUsername", nullable(string())),
+            ("verifiedBadge", nullable(boolean())),
+        ]),
+    );
+    defs.insert(
+        "SportsMetadata",
+        object(&[
+            ("id", nullable(integer())),
+            ("sport", string()),
+            ("image", string()),
+            ("resolution", string()),
+            ("ordering", string()),
+            ("tags", string()),
+            ("series", string()),
+            ("createdAt", nullable(date_time())),
+        ]),
+    );
+    defs.insert(
+        "SportsMarketTypesResponse",
+        object(&[("marketTypes", array(string()))]),
+    );
+    defs.insert(
+        "Team",
+        object(&[
+            ("id", integer()),
+            ("name", nullable(string())),
+            ("league", nullable(string())),
+            ("record", nullable(string())),
+            ("logo", nullable(string())),
+            ("abbreviation", nullable(string())),
+            ("alias", nullable(string())),
+            ("createdAt", nullable(date_time())),
+            ("updatedAt", nullable(date_time())),
+            ("color", nullable(string())),
+            ("providerId", nullable(integer())),
+        ]),
+    );
+    defs.insert(
+        "OrderBookSummaryResponse",
+        object(&[
+            ("market", bytes32()),
+            ("asset_id", hex_uint()),
+            // Milliseconds since the epoch.
+            ("timestamp", integer_string()),
+            ("hash", nullable(string())),
+            ("bids", array(reference("OrderSummary"))),
+            ("asks", array(reference("OrderSummary"))),
+            ("min_order_size", decimal()),
+            ("neg_risk", boolean()),
+            ("tick_size", tick_size()),
+            ("last_trade_price", nullable(decimal())),
+        ]),
+    );
+    defs.insert(
+        "MarketResponse",
+        object(&[
+            ("enable_order_book", boolean()),
+            ("active", boolean()),
+            ("closed", boolean()),
+            ("archived", boolean()),
+            ("accepting_orders", boolean()),
+            ("accepting_order_timestamp", nullable(date_time())),
+            ("minimum_order_size", decimal()),
+            ("minimum_tick_size", decimal()),
+            ("condition_id", or_empty(bytes32())),
+            ("question_id", or_empty(bytes32())),
+            ("question", string()),
+            ("description", string()),
+            ("market_slug", string()),
+            ("end_date_iso", nullable(date_time())),
+            ("game_start_time", nullable(date_time())),
+            ("seconds_delay", integer()),
+            ("fpmm", or_empty(address())),
+            ("maker_base_fee", decimal()),
+            ("taker_base_fee", decimal()),
+            ("notifications_enabled", boolean()),
+            ("neg_risk", boolean()),
+            ("neg_risk_market_id", or_empty(bytes32())),
+            ("neg_risk_request_id", or_empty(bytes32())),
+            ("icon", string()),
+            ("image", string()),
+            ("rewards", reference("Rewards")),
+            ("is_50_50_outcome", boolean()),
+            ("tokens", array(reference("Token"))),
+            ("tags", array(string())),
+        ]),
+    );
+    defs.insert(
+        "SimplifiedMarketResponse",
+        object(&[
+            ("condition_id", or_empty(bytes32())),
+            ("tokens", array(reference("Token"))),
+            ("rewards", reference("Rewards")),
+            ("active", boolean()),
+            ("closed", boolean()),
+            ("archived", boolean()),
+            ("accepting_orders", boolean()),
+        ]),
+    );
+    defs.insert(
+        "ImageOptimization",
+        object(&[
+            ("id", nullable(string())),
+            ("imageUrlSource", nullable(string())),
+            ("imageUrlOptimized", nullable(string())),
+            ("imageSizeKbSource", nullable(number())),
+            ("imageSizeKbOptimized", nullable(number())),
+            ("imageOptimizedComplete", nullable(boolean())),
+            ("imageOptimizedLastUpdated", nullable(string())),
+            ("relID", nullable(string())),
+            ("field", nullable(string())),
+            ("relname", nullable(string())),
+        ]),
+    );
+    defs.insert(
+        "Category",
+        object(&[
+            ("id", string()),
+            ("label", nullable(string())),
+            ("parentCategory", nullable(string())),
+            ("slug", nullable(string())),
+            ("publishedAt", nullable(string())),
+            ("createdBy", nullable(string())),
+            ("updatedBy", nullable(string())),
+            ("createdAt", nullable(date_time())),
+            ("updatedAt", nullable(date_time())),
+        ]),
+    );
+    defs.insert(
+        "ClobReward",
+        object(&[
+            ("id", nullable(string())),
+            ("assetAddress", or_empty(address())),
+            ("conditionId", or_empty(bytes32())),
+            ("startDate", nullable(date())),
+            ("endDate", nullable(date())),
+            ("rewardsAmount", nullable(decimal())),
+            ("rewardsDailyRate", nullable(decimal())),
+        ]),
+    );
+    defs.insert(
+        "Collection",
+        object(&[
+            ("id", string()),
+            ("ticker", nullable(string())),
+            ("slug", nullable(string())),
+            ("title", nullable(string())),
+            ("subtitle", nullable(string())),
+            ("collectionType", nullable(string())),
+            ("description", nullable(string())),
+            ("tags", nullable(string())),
+            ("image", nullable(string())),
+            ("icon", nullable(string())),
+            ("headerImage", nullable(string())),
+            ("layout", nullable(string())),
+            ("active", nullable(boolean())),
+            ("closed", nullable(boolean())),
+            ("archived", nullable(boolean())),
+            ("new", nullable(boolean())),
+            ("featured", nullable(boolean())),
+            ("restricted", nullable(boolean())),
+            ("isTemplate", nullable(boolean())),
+            ("templateVariables", nullable(string())),
+            ("publishedAt", nullable(string())),
+            ("createdBy", nullable(string())),
+            ("updatedBy", nullable(string())),
+            ("createdAt", nullable(date_time())),
+            ("updatedAt", nullable(date_time())),
+            ("commentsEnabled", nullable(boolean())),
+            ("imageOptimized", nullable(reference("ImageOptimization"))),
+            ("iconOptimized", nullable(reference("ImageOptimization"))),
+            (
+                "headerImageOptimized",
+                nullable(reference("ImageOptimization")),
+            ),
+        ]),
+    );
+    defs.insert(
+        "EventCreator",
+        object(&[
+            ("id", string()),
+            ("creatorName", nullable(string())),
+            ("creatorHandle", nullable(string())),
+            ("creatorUrl", nullable(string())),
+            ("creatorImage", nullable(string())),
+            ("createdAt", nullable(date_time())),
+            ("updatedAt", nullable(date_time())),
+        ]),
+    );
+    defs.insert(
+        "Chat",
+        object(&[
+            ("id", string()),
+            ("channelId", nullable(string())),
+            ("channelName", nullable(string())),
+            ("channelImage", nullable(string())),
+            ("live", nullable(boolean())),
+            ("startTime", nullable(date_time())),
+            ("endTime", nullable(date_time())),
+        ]),
+    );
+    defs.insert(
+        "Template",
+        object(&[
+            ("id", string()),
+            ("eventTitle", nullable(string())),
+            ("eventSlug", nullable(string())),
+            ("eventImage", nullable(string())),
+            ("marketTitle", nullable(string())),
+            ("description", nullable(string())),
+            ("resolutionSource", nullable(string())),
+            ("negRisk", nullable(boolean())),
+            ("sortBy", nullable(string())),
+            ("showMarketImages", nullable(boolean())),
+            ("seriesSlug", nullable(string())),
+            ("outcomes", nullable(string())),
+        ]),
+    );
+    defs.insert(
+        "CommentProfile",
+        object(&[
+            ("name", nullable(string())),
+            ("pseudonym", nullable(string())),
+            ("displayUsernamePublic", nullable(boolean())),
+            ("bio", nullable(string())),
+            ("isMod", nullable(boolean())),
+            ("isCreator", nullable(boolean())),
+            ("proxyWallet", or_empty(address())),
+            ("baseAddress", or_empty(address())),
+            ("profileImage", nullable(string())),
+            (
+                "profileImageOptimized",
+                nullable(reference("ImageOptimization")),
+            ),
+            ("positions", nullable(array(reference("CommentPosition")))),
+        ]),
+    );
+    defs.insert(
+        "Reaction",
+        object(&[
+            ("id", string()),
+            ("commentID", nullable(integer())),
+            ("reactionType", nullable(string())),
+            ("icon", nullable(string())),
+            ("userAddress", or_empty(address())),
+            ("createdAt", nullable(date_time())),
+            ("profile", nullable(reference("CommentProfile"))),
+        ]),
+    );
+    defs.insert(
+        "PublicProfileUser",
+        object(&[
+            ("id", nullable(string())),
+            ("creator", nullable(boolean())),
+            ("mod", nullable(boolean())),
+        ]),
+    );
+    defs.insert(
+        "OrderSummary",
+        object(&[("price", decimal()), ("size", decimal())]),
+    );
+    defs.insert(
+        "Rewards",
+        object(&[
+            ("rates", array(reference("RewardRate"))),
+            ("min_size", decimal()),
+            ("max_spread", decimal()),
+        ]),
+    );
+    defs.insert(
+        "Token",
+        object(&[
+            ("token_id", hex_uint()),
+            ("outcome", string()),
+            ("price", decimal()),
+            ("winner", boolean()),
+        ]),
+    );
+    defs.insert(
+        "CommentPosition",
+        object(&[
+            ("tokenId", nullable(hex_uint())),
+            ("positionSize", nullable(decimal())),
+        ]),
+    );
+    defs.insert(
+        "RewardRate",
+        object(&[
+            ("asset_address", address()),
+            ("rewards_daily_rate", decimal()),
+        ]),
+    );
+    defs
+}
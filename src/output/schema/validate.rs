@@ -0,0 +1,134 @@
+//! A minimal validator for the subset of JSON Schema used by the registry,
+//! for tests that check real serialized output against the published schemas.
+
+use serde::Serialize;
+use serde_json::Value;
+
+use super::find;
+
+pub(crate) fn validate(doc: &Value, value: &Value) -> Result<(), String> {
+    check(doc, doc, value, "$")
+}
+
+fn check(root: &Value, schema: &Value, value: &Value, path: &str) -> Result<(), String> {
+    if let Some(Value::String(r)) = schema.get("$ref") {
+        let name = r.trim_start_matches("#/$defs/");
+        let def = &root["$defs"][name];
+        return check(root, def, value, path);
+    }
+    if let Some(Value::Array(options)) = schema.get("anyOf").or_else(|| schema.get("oneOf")) {
+        let matches = options
+            .iter()
+            .filter(|s| check(root, s, value, path).is_ok())
+            .count();
+        return if matches > 0 {
+            Ok(())
+        } else {
+            Err(format!("{path}: matches no alternative"))
+        };
+    }
+    if let Some(expected) = schema.get("const")
+        && expected != value
+    {
+        return Err(format!("{path}: expected {expected}, got {value}"));
+    }
+    if let Some(Value::Array(values)) = schema.get("enum")
+        && !values.contains(value)
+    {
+        return Err(format!("{path}: {value} is not one of {values:?}"));
+    }
+    if let Some(ty) = schema.get("type") {
+        let types: Vec<&str> = match ty {
+            Value::String(t) => vec![t.as_str()],
+            Value::Array(ts) => ts.iter().filter_map(Value::as_str).collect(),
+            _ => vec![],
+        };
+        let actual = match value {
+            Value::Null => "null",
+            Value::Bool(_) => "boolean",
+            Value::Number(n) if n.is_i64() || n.is_u64() => "integer",
+            Value::Number(_) => "number",
+            Value::String(_) => "string",
+            Value::Array(_) => "array",
+            Value::Object(_) => "object",
+        };
+        let ok = types.contains(&actual) || (actual == "integer" && types.contains(&"number"));
+        if !ok {
+            return Err(format!("{path}: expected {types:?}, got {value}"));
+        }
+    }
+    if let (Some(Value::String(pattern)), Value::String(s)) = (schema.get("pattern"), value)
+        && !matches_pattern(pattern, s)
+    {
+        return Err(format!("{path}: {s:?} does not match {pattern}"));
+    }
+    if let (Some("decimal"), Value::String(s)) =
+        (schema.get("format").and_then(Value::as_str), value)
+        && s.parse::<polymarket_client_sdk::types::Decimal>().is_err()
+    {
+        return Err(format!("{path}: {s:?} is not a decimal"));
+    }
+    match value {
+        Value::Object(fields) => {
+            let properties = schema.get("properties").and_then(Value::as_object);
+            if let Some(Value::Array(required)) = schema.get("required") {
+                for name in required.iter().filter_map(Value::as_str) {
+                    if !fields.contains_key(name) {
+                        return Err(format!("{path}: missing field {name:?}"));
+                    }
+                }
+            }
+            for (name, field) in fields {
+                let field_path = format!("{path}.{name}");
+                match (
+                    properties.and_then(|p| p.get(name)),
+                    schema.get("additionalProperties"),
+                ) {
+                    (Some(s), _) => check(root, s, field, &field_path)?,
+                    (None, Some(Value::Bool(false))) => {
+                        return Err(format!("{path}: unexpected field {name:?}"));
+                    }
+                    (None, Some(s @ Value::Object(_))) => check(root, s, field, &field_path)?,
+                    (None, _) => {}
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(s) = schema.get("items") {
+                for (i, item) in items.iter().enumerate() {
+                    check(root, s, item, &format!("{path}[{i}]"))?;
+                }
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// The patterns used by the schemas above.
+fn matches_pattern(pattern: &str, s: &str) -> bool {
+    let digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    let hex = |s: &str, len: usize| {
+        s.len() == len + 2 && s.starts_with("0x") && s[2..].bytes().all(|b| b.is_ascii_hexdigit())
+    };
+    match pattern {
+        "^[0-9]+$" => digits(s),
+        "^-?[0-9]+$" => digits(s.strip_prefix('-').unwrap_or(s)),
+        "^0x[0-9a-fA-F]+$" => s.len() > 2 && hex(s, s.len() - 2),
+        "^0x[0-9a-fA-F]{40}$" => hex(s, 40),
+        "^0x[0-9a-fA-F]{64}$" => hex(s, 64),
+        _ => panic!("validator does not support pattern {pattern}"),
+    }
+}
+
+/// Check a command's output against its published schema.
+pub(crate) fn assert_conforms(command: &str, output: &impl Serialize) {
+    let words: Vec<String> = command.split(' ').map(String::from).collect();
+    let doc = find(&words)
+        .unwrap_or_else(|| panic!("no schema for {command}"))
+        .document(false);
+    let value = serde_json::to_value(output).unwrap();
+    if let Err(e) = validate(&doc, &value) {
+        panic!("`{command}` output does not match its schema: {e}\n{value:#}");
+    }
+}
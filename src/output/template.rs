@@ -0,0 +1,292 @@
+use std::path::Path;
+use std::sync::Mutex;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use minijinja::{Environment, ErrorKind, Value};
+use polymarket_client_sdk::types::Decimal;
+use rust_decimal::prelude::FromPrimitive;
+
+use super::display::{PriceStyle, format_price_as};
+use super::{format_date, format_decimal, truncate};
+
+const TEMPLATE_NAME: &str = "output";
+
+/// Template set by `-o template` for the command currently running.
+/// Replaced on every `run`, so shell commands don't inherit each other's templates.
+static ACTIVE: Mutex<Option<Environment<'static>>> = Mutex::new(None);
+
+/// Compile `--template` / `--template-file` and make it the active template,
+/// or clear the active template when neither is given.
+pub(crate) fn activate(inline: Option<&str>, file: Option<&Path>) -> Result<()> {
+    let source = match (inline, file) {
+        (Some(t), _) => Some(t.to_string()),
+        (None, Some(path)) => Some(
+            std::fs::read_to_string(path)
+                .context(format!("Failed to read template file {}", path.display()))?,
+        ),
+        (None, None) => None,
+    };
+    let env = source.map(compile).transpose()?;
+    *ACTIVE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = env;
+    Ok(())
+}
+
+fn compile(source: String) -> Result<Environment<'static>> {
+    let mut env = Environment::new();
+    // Missing values (`null` in the JSON output) render as empty strings, not "none".
+    env.set_formatter(|out, state, value| {
+        if value.is_none() {
+            return Ok(());
+        }
+        minijinja::escape_formatter(out, state, value)
+    });
+    env.add_filter("format_decimal", filter_format_decimal);
+    env.add_filter("format_date", filter_format_date);
+    env.add_filter("truncate", filter_truncate);
+    env.add_filter("percent", filter_percent);
+    env.add_filter("cents", filter_cents);
+    env.add_template_owned(TEMPLATE_NAME, source)
+        .context("Invalid output template")?;
+    Ok(env)
+}
+
+/// Render `data` through the active template, one line per record.
+/// Returns `Ok(false)` when no template is active.
+pub(crate) fn print_active(data: &(impl serde::Serialize + ?Sized)) -> Result<bool> {
+    let guard = ACTIVE
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let Some(env) = guard.as_ref() else {
+        return Ok(false);
+    };
+    for line in render(env, &serde_json::to_value(data)?)? {
+        println!("{line}");
+    }
+    Ok(true)
+}
+
+fn render(env: &Environment<'_>, data: &serde_json::Value) -> Result<Vec<String>> {
+    let template = env.get_template(TEMPLATE_NAME)?;
+    let records: Vec<&serde_json::Value> = match data {
+        serde_json::Value::Array(items) => items.iter().collect(),
+        other => vec![other],
+    };
+    records
+        .into_iter()
+        .map(|record| {
+            template
+                .render(record_context(record))
+                .context("Failed to render output template")
+        })
+        .collect()
+}
+
+/// Top-level fields are exposed directly, with `snake_case` aliases for the
+/// `camelCase` keys used by the API types. Non-object records are exposed as `value`.
+fn record_context(record: &serde_json::Value) -> Value {
+    let serde_json::Value::Object(fields) = record else {
+        return Value::from_serialize(serde_json::json!({ "value": record }));
+    };
+    let mut ctx = fields.clone();
+    for (key, val) in fields {
+        let snake = to_snake_case(key);
+        if snake != *key {
+            ctx.entry(snake).or_insert_with(|| val.clone());
+        }
+    }
+    Value::from_serialize(&ctx)
+}
+
+fn to_snake_case(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 4);
+    for (i, c) in s.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+fn invalid(msg: String) -> minijinja::Error {
+    minijinja::Error::new(ErrorKind::InvalidOperation, msg)
+}
+
+/// Decimals are serialized as strings by the API types, so accept both forms.
+fn to_decimal(v: &Value) -> Result<Decimal, minijinja::Error> {
+    if let Some(s) = v.as_str() {
+        return s
+            .trim()
+            .parse()
+            .map_err(|_| invalid(format!("not a number: {s}")));
+    }
+    if let Ok(i) = i64::try_from(v.clone()) {
+        return Ok(Decimal::from(i));
+    }
+    f64::try_from(v.clone())
+        .ok()
+        .and_then(Decimal::from_f64)
+        .ok_or_else(|| invalid(format!("not a number: {v}")))
+}
+
+fn filter_format_decimal(v: &Value) -> Result<String, minijinja::Error> {
+    if v.is_undefined() || v.is_none() {
+        return Ok(String::new());
+    }
+    to_decimal(v).map(format_decimal)
+}
+
+fn filter_format_date(v: &Value) -> Result<String, minijinja::Error> {
+    if v.is_undefined() || v.is_none() {
+        return Ok(String::new());
+    }
+    if let Some(s) = v.as_str() {
+        if let Ok(d) = DateTime::parse_from_rfc3339(s) {
+            return Ok(format_date(&d.with_timezone(&Utc)));
+        }
+        if let Some(d) = NaiveDate::parse_from_str(s, "%Y-%m-%d")
+            .ok()
+            .and_then(|d| d.and_hms_opt(0, 0, 0))
+        {
+            return Ok(format_date(&d.and_utc()));
+        }
+        return Ok(s.to_string());
+    }
+    i64::try_from(v.clone())
+        .ok()
+        .and_then(|ts| DateTime::from_timestamp(ts, 0))
+        .map(|d| format_date(&d))
+        .ok_or_else(|| invalid(format!("not a date: {v}")))
+}
+
+fn filter_truncate(v: &Value, max: Option<usize>) -> String {
+    let s = v.as_str().map_or_else(|| v.to_string(), str::to_string);
+    truncate(&s, max.unwrap_or(40))
+}
+
+fn filter_percent(v: &Value, decimals: Option<u32>) -> Result<String, minijinja::Error> {
+    if v.is_undefined() || v.is_none() {
+        return Ok(String::new());
+    }
+    let pct = (to_decimal(v)? * Decimal::from(100)).round_dp(decimals.unwrap_or(1));
+    Ok(format!("{pct}%"))
+}
+
+fn filter_cents(v: &Value) -> Result<String, minijinja::Error> {
+    if v.is_undefined() || v.is_none() {
+        return Ok(String::new());
+    }
+    Ok(format_price_as(to_decimal(v)?, PriceStyle::Cents))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn render_str(template: &str, data: &serde_json::Value) -> Vec<String> {
+        render(&compile(template.to_string()).unwrap(), data).unwrap()
+    }
+
+    #[test]
+    fn renders_one_line_per_array_element() {
+        let data = json!([{"question": "A?"}, {"question": "B?"}]);
+        assert_eq!(render_str("{{ question }}", &data), vec!["A?", "B?"]);
+    }
+
+    #[test]
+    fn renders_single_object_once() {
+        let data = json!({"status": "OK"});
+        assert_eq!(render_str("api={{ status }}", &data), vec!["api=OK"]);
+    }
+
+    #[test]
+    fn exposes_snake_case_aliases() {
+        let data = json!({"bestBid": "0.41", "bestAsk": "0.43"});
+        assert_eq!(
+            render_str("{{ best_bid }}/{{ bestAsk }}", &data),
+            vec!["0.41/0.43"]
+        );
+    }
+
+    #[test]
+    fn scalar_records_are_exposed_as_value() {
+        let data = json!(["0x1", "0x2"]);
+        assert_eq!(render_str("{{ value }}", &data), vec!["0x1", "0x2"]);
+    }
+
+    #[test]
+    fn format_decimal_filter_accepts_strings_and_numbers() {
+        let data = json!({"a": "1500000", "b": 2500});
+        assert_eq!(
+            render_str("{{ a | format_decimal }} {{ b | format_decimal }}", &data),
+            vec!["$1.5M $2.5K"]
+        );
+    }
+
+    #[test]
+    fn price_filters_format_probabilities() {
+        let data = json!({"p": "0.655"});
+        assert_eq!(
+            render_str(
+                "{{ p | cents }} {{ p | percent }} {{ p | percent(0) }}",
+                &data
+            ),
+            vec!["65.50¢ 65.5% 66%"]
+        );
+    }
+
+    #[test]
+    fn cents_filter_rounds_midpoints_like_the_table() {
+        let data = json!({"p": "0.12345"});
+        assert_eq!(render_str("{{ p | cents }}", &data), vec!["12.35¢"]);
+    }
+
+    #[test]
+    fn format_date_filter_handles_rfc3339_and_timestamps() {
+        let data = json!({"d": "2024-11-05T12:30:00Z", "t": 0});
+        assert_eq!(
+            render_str("{{ d | format_date }}|{{ t | format_date }}", &data),
+            vec!["2024-11-05 12:30 UTC|1970-01-01 00:00 UTC"]
+        );
+    }
+
+    #[test]
+    fn truncate_filter_defaults_and_custom_length() {
+        let data = json!({"q": "Will it rain tomorrow?"});
+        assert_eq!(
+            render_str("{{ q | truncate(8) }}", &data),
+            vec!["Will it\u{2026}"]
+        );
+    }
+
+    #[test]
+    fn missing_fields_render_empty() {
+        let data = json!({"a": null});
+        assert_eq!(
+            render_str(
+                "[{{ a }}][{{ missing }}][{{ missing | format_decimal }}]",
+                &data
+            ),
+            vec!["[][][]"]
+        );
+    }
+
+    #[test]
+    fn invalid_template_is_an_error() {
+        assert!(compile("{{ unclosed".to_string()).is_err());
+    }
+
+    #[test]
+    fn snake_case_conversion() {
+        assert_eq!(to_snake_case("outcomePrices"), "outcome_prices");
+        assert_eq!(to_snake_case("id"), "id");
+    }
+}
@@ -0,0 +1,218 @@
+use std::str::FromStr;
+
+use alloy::primitives::B256;
+use alloy::primitives::utils::format_units;
+use anyhow::Result;
+use rust_decimal::Decimal;
+use tabled::settings::Style;
+use tabled::{Table, Tabled};
+
+use super::{DASH, OutputFormat, format_date, print_detail_table};
+use crate::config;
+use crate::journal::{Entry, Status};
+use crate::tx::{Replacement, Sent, SimulatedReplacement};
+
+/// `wei` in gwei, without trailing zeros.
+fn gwei(wei: u128) -> String {
+    format_units(wei, "gwei")
+        .ok()
+        .and_then(|s| Decimal::from_str(&s).ok())
+        .map_or_else(|| wei.to_string(), |d| d.normalize().to_string())
+}
+
+fn entry_json(entry: &Entry, status: Status) -> serde_json::Value {
+    serde_json::json!({
+        "hash": entry.hash.to_string(),
+        "status": status,
+        "from": entry.from.to_string(),
+        "to": entry.to.to_string(),
+        "nonce": entry.nonce,
+        "gas_limit": entry.gas_limit,
+        "max_fee": gwei(entry.max_fee_per_gas),
+        "priority_fee": gwei(entry.max_priority_fee_per_gas),
+        "sent_at": entry.sent_at.map(|d| d.to_rfc3339()),
+        "replaces": entry.replaces.map(|h| h.to_string()),
+        "polygonscan": config::chain().tx_url(entry.hash),
+    })
+}
+
+pub fn print_entries(entries: &[Entry], output: &OutputFormat) -> Result<()> {
+    match output {
+        OutputFormat::Json => {
+            let items: Vec<_> = entries.iter().map(|e| entry_json(e, e.status)).collect();
+            super::print_json(&items)
+        }
+        OutputFormat::Table => {
+            if entries.is_empty() {
+                println!("No transactions found.");
+                return Ok(());
+            }
+            #[derive(Tabled)]
+            struct Row {
+                #[tabled(rename = "Sent")]
+                sent: String,
+                #[tabled(rename = "Hash")]
+                hash: String,
+                #[tabled(rename = "Nonce")]
+                nonce: u64,
+                #[tabled(rename = "To")]
+                to: String,
+                #[tabled(rename = "Status")]
+                status: String,
+            }
+            let rows = entries.iter().map(|e| Row {
+                sent: e.sent_at.as_ref().map_or_else(|| DASH.into(), format_date),
+                hash: e.hash.to_string(),
+                nonce: e.nonce,
+                to: e.to.to_string(),
+                status: match e.replaces {
+                    Some(_) => format!("{} (replacement)", e.status),
+                    None => e.status.to_string(),
+                },
+            });
+            println!("{}", Table::new(rows).with(Style::rounded()));
+            Ok(())
+        }
+    }
+}
+
+pub fn print_status(
+    entry: &Entry,
+    status: Status,
+    block_number: Option<u64>,
+    output: &OutputFormat,
+) -> Result<()> {
+    match output {
+        OutputFormat::Json => {
+            let mut json = entry_json(entry, status);
+            json["block_number"] = serde_json::json!(block_number);
+            super::print_json(&json)
+        }
+        OutputFormat::Table => {
+            let mut rows = vec![
+                ["Tx Hash".into(), entry.hash.to_string()],
+                ["Status".into(), status.to_string()],
+                [
+                    "Block".into(),
+                    block_number.map_or_else(|| DASH.into(), |b| b.to_string()),
+                ],
+                ["From".into(), entry.from.to_string()],
+                ["To".into(), entry.to.to_string()],
+                ["Nonce".into(), entry.nonce.to_string()],
+                ["Gas Limit".into(), entry.gas_limit.to_string()],
+                [
+                    "Max Fee".into(),
+                    format!("{} gwei", gwei(entry.max_fee_per_gas)),
+                ],
+                [
+                    "Priority Fee".into(),
+                    format!("{} gwei", gwei(entry.max_priority_fee_per_gas)),
+                ],
+            ];
+            if let Some(sent_at) = &entry.sent_at {
+                rows.push(["Sent".into(), format_date(sent_at)]);
+            }
+            if let Some(replaces) = entry.replaces {
+                rows.push(["Replaces".into(), replaces.to_string()]);
+            }
+            rows.push(["Polygonscan".into(), config::chain().tx_url(entry.hash)]);
+            print_detail_table(rows);
+            if status == Status::Pending {
+                println!(
+                    "Still pending: `polymarket tx speedup {0}` or `polymarket tx cancel {0}` replace it.",
+                    entry.hash
+                );
+            }
+            Ok(())
+        }
+    }
+}
+
+fn operation(replacement: Replacement) -> &'static str {
+    match replacement {
+        Replacement::SpeedUp => "speedup",
+        Replacement::Cancel => "cancel",
+    }
+}
+
+pub fn print_replacement(
+    replacement: Replacement,
+    replaced: B256,
+    sent: &Sent,
+    output: &OutputFormat,
+) -> Result<()> {
+    let operation = operation(replacement);
+    let tx_hash = sent.transaction_hash;
+    match output {
+        OutputFormat::Json => super::print_json(&serde_json::json!({
+            "operation": operation,
+            "replaces": replaced.to_string(),
+            "transaction_hash": tx_hash.to_string(),
+            "block_number": sent.block_number,
+            "gas_used": sent.gas_used,
+            "fee": sent.fee.to_string(),
+            "polygonscan": config::chain().tx_url(tx_hash),
+        })),
+        OutputFormat::Table => {
+            let rows = vec![
+                ["Operation".into(), operation.to_string()],
+                ["Replaces".into(), replaced.to_string()],
+                ["Tx Hash".into(), tx_hash.to_string()],
+                ["Block".into(), sent.block_number.to_string()],
+                ["Gas Used".into(), sent.gas_used.to_string()],
+                ["Fee".into(), format!("{} POL", sent.fee)],
+                ["Polygonscan".into(), config::chain().tx_url(tx_hash)],
+            ];
+            print_detail_table(rows);
+            Ok(())
+        }
+    }
+}
+
+pub fn print_replacement_simulation(
+    replacement: Replacement,
+    replaced: B256,
+    simulated: &SimulatedReplacement,
+    output: &OutputFormat,
+) -> Result<()> {
+    let operation = operation(replacement);
+    let simulation = &simulated.simulation;
+    match output {
+        OutputFormat::Json => super::print_json(&serde_json::json!({
+            "operation": operation,
+            "replaces": replaced.to_string(),
+            "simulated": true,
+            "success": simulation.revert_reason.is_none(),
+            "max_fee": gwei(simulated.max_fee_per_gas),
+            "priority_fee": gwei(simulated.max_priority_fee_per_gas),
+            "gas_estimate": simulation.gas_estimate,
+            "revert_reason": simulation.revert_reason,
+        })),
+        OutputFormat::Table => {
+            let mut rows = vec![
+                ["Operation".into(), format!("{operation} (simulated)")],
+                ["Replaces".into(), replaced.to_string()],
+                [
+                    "Max Fee".into(),
+                    format!("{} gwei", gwei(simulated.max_fee_per_gas)),
+                ],
+                [
+                    "Priority Fee".into(),
+                    format!("{} gwei", gwei(simulated.max_priority_fee_per_gas)),
+                ],
+            ];
+            match (&simulation.revert_reason, simulation.gas_estimate) {
+                (Some(reason), _) => rows.push(["Result".into(), format!("Reverts: {reason}")]),
+                (None, gas) => {
+                    rows.push(["Result".into(), "Succeeds".into()]);
+                    rows.push([
+                        "Gas Estimate".into(),
+                        gas.map_or_else(|| DASH.to_string(), |g| g.to_string()),
+                    ]);
+                }
+            }
+            print_detail_table(rows);
+            Ok(())
+        }
+    }
+}
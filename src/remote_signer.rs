@@ -0,0 +1,468 @@
+//! Signing through a remote signer over JSON-RPC, such as Web3Signer or clef.
+//!
+//! The key never touches this host: typed data is signed with
+//! `eth_signTypedData_v4` and transactions with `eth_signTransaction`.
+
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use alloy::consensus::{SignableTransaction, TxEnvelope};
+use alloy::dyn_abi::TypedData;
+use alloy::eips::eip2718::Decodable2718;
+use alloy::network::TxSigner;
+use alloy::primitives::{Address, B256, Bytes, ChainId, Signature, TxKind, U256};
+use alloy::signers::{Error as SignerError, Result as SignerResult, Signer};
+use alloy::sol;
+use alloy::sol_types::{Eip712Domain, SolStruct};
+use anyhow::{Context, Result, bail};
+use async_trait::async_trait;
+use polymarket_client_sdk::clob::types::Order;
+use polymarket_client_sdk::contract_config;
+use serde_json::{Value, json};
+
+use crate::error::CliError;
+
+sol! {
+    /// Message signed to create or derive CLOB API keys, as built by the SDK.
+    #[derive(serde::Serialize)]
+    struct ClobAuth {
+        address address;
+        string  timestamp;
+        uint256 nonce;
+        string  message;
+    }
+}
+
+const CLOB_AUTH_MESSAGE: &str = "This message attests that I control the given wallet";
+
+/// How far from now the SDK's API-key timestamp may be, in seconds.
+const CLOB_AUTH_WINDOW: i64 = 30;
+
+pub(crate) struct RemoteSigner {
+    url: String,
+    address: Address,
+    chain_id: Option<ChainId>,
+    client: reqwest::Client,
+    /// The SDK hands signers a bare EIP-712 hash, so the typed data behind
+    /// each hash we expect to sign is registered here first.
+    expected: Mutex<Vec<TypedData>>,
+}
+
+impl RemoteSigner {
+    pub fn new(url: String, address: Address) -> Self {
+        Self {
+            url,
+            address,
+            chain_id: None,
+            client: reqwest::Client::new(),
+            expected: Mutex::new(Vec::new()),
+        }
+    }
+
+    #[must_use]
+    pub fn with_chain_id(mut self, chain_id: Option<ChainId>) -> Self {
+        self.chain_id = chain_id;
+        self
+    }
+
+    /// Register `order` as about to be signed, for either exchange.
+    pub fn expect_order(&self, order: &Order) {
+        let chain_id = self.chain_id.unwrap_or(polymarket_client_sdk::POLYGON);
+        let typed = [false, true]
+            .into_iter()
+            .filter_map(|neg_risk| contract_config(chain_id, neg_risk))
+            .map(|config| {
+                let domain = Eip712Domain {
+                    name: Some("Polymarket CTF Exchange".into()),
+                    version: Some("1".into()),
+                    chain_id: Some(U256::from(chain_id)),
+                    verifying_contract: Some(config.exchange),
+                    ..Eip712Domain::default()
+                };
+                let mut typed = TypedData::from_struct(order, Some(domain));
+                // The SDK serializes the salt as a number; keep it exact.
+                typed.message["salt"] = json!(order.salt.to_string());
+                typed
+            });
+        self.expected
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .extend(typed);
+    }
+
+    /// The typed data whose EIP-712 hash is `hash`: a registered payload, or
+    /// the SDK's API-key message for a timestamp around now.
+    fn typed_data_for(&self, hash: &B256) -> Option<TypedData> {
+        let expected = self
+            .expected
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if let Some(typed) = expected
+            .iter()
+            .find(|t| t.eip712_signing_hash().ok().as_ref() == Some(hash))
+        {
+            return Some(typed.clone());
+        }
+
+        let domain = Eip712Domain {
+            name: Some("ClobAuthDomain".into()),
+            version: Some("1".into()),
+            chain_id: self.chain_id.map(U256::from),
+            ..Eip712Domain::default()
+        };
+        let now = chrono::Utc::now().timestamp();
+        (now - CLOB_AUTH_WINDOW..=now + CLOB_AUTH_WINDOW)
+            .map(|timestamp| ClobAuth {
+                address: self.address,
+                timestamp: timestamp.to_string(),
+                nonce: U256::ZERO,
+                message: CLOB_AUTH_MESSAGE.to_string(),
+            })
+            .find(|auth| auth.eip712_signing_hash(&domain) == *hash)
+            .map(|auth| TypedData::from_struct(&auth, Some(domain)))
+    }
+
+    async fn call(&self, method: &str, params: Value) -> Result<Value> {
+        let body = json!({"jsonrpc": "2.0", "id": 1, "method": method, "params": params});
+        let response = self
+            .client
+            .post(&self.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(serde_json::to_vec(&body)?)
+            .send()
+            .await
+            .context(format!("Failed to reach remote signer at {}", self.url))?
+            .error_for_status()?
+            .bytes()
+            .await?;
+        let response: Value = serde_json::from_slice(&response)
+            .context("Remote signer sent an invalid JSON-RPC response")?;
+        if let Some(error) = response.get("error") {
+            let message = error["message"]
+                .as_str()
+                .map_or_else(|| error.to_string(), str::to_string);
+            bail!(CliError::auth(format!(
+                "Remote signer refused `{method}`: {message}"
+            )));
+        }
+        response
+            .get("result")
+            .cloned()
+            .context("Remote signer response has no result")
+    }
+
+    /// Check that `signature` over `hash` is from this signer's address.
+    fn verify(&self, signature: &Signature, hash: &B256) -> Result<()> {
+        let signer = signature
+            .recover_address_from_prehash(hash)
+            .context("Remote signer returned an invalid signature")?;
+        if signer != self.address {
+            bail!(CliError::auth(format!(
+                "Remote signer signed as {signer}, expected {}",
+                self.address
+            )));
+        }
+        Ok(())
+    }
+
+    async fn sign_typed_data_remote(&self, typed: &TypedData, hash: &B256) -> Result<Signature> {
+        let result = self
+            .call("eth_signTypedData_v4", json!([self.address, typed]))
+            .await?;
+        let signature = result
+            .as_str()
+            .and_then(|s| Signature::from_str(s).ok())
+            .context("Remote signer returned an invalid signature")?;
+        self.verify(&signature, hash)?;
+        Ok(signature)
+    }
+
+    /// `tx` as an `eth_signTransaction` request object.
+    fn transaction_request(&self, tx: &dyn SignableTransaction<Signature>) -> Value {
+        let quantity = |n: u128| format!("{n:#x}");
+        let mut request = json!({
+            "from": self.address,
+            "type": quantity(tx.ty().into()),
+            "nonce": quantity(tx.nonce().into()),
+            "gas": quantity(tx.gas_limit().into()),
+            "value": tx.value(),
+            "data": tx.input(),
+        });
+        if let Some(chain_id) = tx.chain_id() {
+            request["chainId"] = json!(quantity(chain_id.into()));
+        }
+        if let TxKind::Call(to) = tx.kind() {
+            request["to"] = json!(to);
+        }
+        if tx.is_dynamic_fee() {
+            request["maxFeePerGas"] = json!(quantity(tx.max_fee_per_gas()));
+            request["maxPriorityFeePerGas"] =
+                json!(quantity(tx.max_priority_fee_per_gas().unwrap_or_default()));
+        } else {
+            request["gasPrice"] = json!(quantity(tx.gas_price().unwrap_or_default()));
+        }
+        if let Some(access_list) = tx.access_list().filter(|l| !l.is_empty()) {
+            request["accessList"] = json!(access_list);
+        }
+        request
+    }
+
+    async fn sign_transaction_remote(
+        &self,
+        tx: &dyn SignableTransaction<Signature>,
+    ) -> Result<Signature> {
+        let result = self
+            .call("eth_signTransaction", json!([self.transaction_request(tx)]))
+            .await?;
+        // Web3Signer returns the raw transaction, clef `{raw, tx}`.
+        let raw = result
+            .as_str()
+            .or_else(|| result["raw"].as_str())
+            .and_then(|s| Bytes::from_str(s).ok())
+            .context("Remote signer returned an invalid transaction")?;
+        let signed = TxEnvelope::decode_2718(&mut raw.as_ref())
+            .context("Remote signer returned an invalid transaction")?;
+        let hash = tx.signature_hash();
+        if signed.signature_hash() != hash {
+            bail!(CliError::auth(
+                "Remote signer signed a different transaction than requested"
+            ));
+        }
+        self.verify(signed.signature(), &hash)?;
+        Ok(*signed.signature())
+    }
+}
+
+#[async_trait]
+impl Signer for RemoteSigner {
+    async fn sign_hash(&self, hash: &B256) -> SignerResult<Signature> {
+        let typed = self.typed_data_for(hash).ok_or_else(|| {
+            SignerError::message("Remote signer can only sign typed data the CLI built")
+        })?;
+        self.sign_typed_data_remote(&typed, hash)
+            .await
+            .map_err(SignerError::other)
+    }
+
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    fn chain_id(&self) -> Option<ChainId> {
+        self.chain_id
+    }
+
+    fn set_chain_id(&mut self, chain_id: Option<ChainId>) {
+        self.chain_id = chain_id;
+    }
+}
+
+#[async_trait]
+impl TxSigner<Signature> for RemoteSigner {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &mut dyn SignableTransaction<Signature>,
+    ) -> SignerResult<Signature> {
+        self.sign_transaction_remote(tx)
+            .await
+            .map_err(SignerError::other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+
+    use alloy::consensus::TxEip1559;
+    use alloy::eips::eip2718::Encodable2718;
+    use alloy::network::TxSignerSync;
+    use alloy::signers::SignerSync;
+    use alloy::signers::local::PrivateKeySigner;
+    use polymarket_client_sdk::POLYGON;
+
+    use super::*;
+
+    fn quantity(value: &Value) -> u128 {
+        let hex = value.as_str().unwrap().trim_start_matches("0x");
+        u128::from_str_radix(hex, 16).unwrap()
+    }
+
+    /// Answer one JSON-RPC request the way Web3Signer would, signing with `key`.
+    fn respond(key: &PrivateKeySigner, request: &Value) -> Value {
+        let params = &request["params"];
+        match request["method"].as_str().unwrap() {
+            "eth_signTypedData_v4" => {
+                let typed: TypedData = serde_json::from_value(params[1].clone()).unwrap();
+                let signature = key
+                    .sign_hash_sync(&typed.eip712_signing_hash().unwrap())
+                    .unwrap();
+                json!(format!("0x{}", alloy::hex::encode(signature.as_bytes())))
+            }
+            "eth_signTransaction" => {
+                let tx = &params[0];
+                let mut unsigned = TxEip1559 {
+                    chain_id: quantity(&tx["chainId"]) as u64,
+                    nonce: quantity(&tx["nonce"]) as u64,
+                    gas_limit: quantity(&tx["gas"]) as u64,
+                    max_fee_per_gas: quantity(&tx["maxFeePerGas"]),
+                    max_priority_fee_per_gas: quantity(&tx["maxPriorityFeePerGas"]),
+                    to: TxKind::Call(serde_json::from_value(tx["to"].clone()).unwrap()),
+                    value: serde_json::from_value(tx["value"].clone()).unwrap(),
+                    input: serde_json::from_value(tx["data"].clone()).unwrap(),
+                    ..TxEip1559::default()
+                };
+                let signature = key.sign_transaction_sync(&mut unsigned).unwrap();
+                let signed = TxEnvelope::from(unsigned.into_signed(signature));
+                json!(format!("0x{}", alloy::hex::encode(signed.encoded_2718())))
+            }
+            method => panic!("unexpected method {method}"),
+        }
+    }
+
+    /// A local stand-in for a remote signer holding `key`; returns its URL.
+    fn stand_in(key: PrivateKeySigner) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line == "\r\n" {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':')
+                        && name.eq_ignore_ascii_case("content-length")
+                    {
+                        length = value.trim().parse().unwrap();
+                    }
+                }
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                let request: Value = serde_json::from_slice(&body).unwrap();
+                let response = serde_json::to_string(&json!({
+                    "jsonrpc": "2.0",
+                    "id": request["id"],
+                    "result": respond(&key, &request),
+                }))
+                .unwrap();
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\
+                     Content-Length: {}\r\nConnection: close\r\n\r\n{response}",
+                    response.len()
+                )
+                .unwrap();
+            }
+        });
+        url
+    }
+
+    fn remote(key: &PrivateKeySigner) -> RemoteSigner {
+        RemoteSigner::new(stand_in(key.clone()), key.address()).with_chain_id(Some(POLYGON))
+    }
+
+    #[tokio::test]
+    async fn signs_registered_order() {
+        let key = PrivateKeySigner::random();
+        let signer = remote(&key);
+        let mut order = Order::default();
+        order.salt = U256::from(u64::MAX);
+        order.maker = key.address();
+        order.signer = key.address();
+        order.tokenId = U256::from(12345);
+        order.makerAmount = U256::from(1_000_000);
+        order.takerAmount = U256::from(2_000_000);
+        signer.expect_order(&order);
+
+        let domain = Eip712Domain {
+            name: Some("Polymarket CTF Exchange".into()),
+            version: Some("1".into()),
+            chain_id: Some(U256::from(POLYGON)),
+            verifying_contract: Some(contract_config(POLYGON, true).unwrap().exchange),
+            ..Eip712Domain::default()
+        };
+        let hash = order.eip712_signing_hash(&domain);
+        let signature = signer.sign_hash(&hash).await.unwrap();
+        assert_eq!(
+            signature.recover_address_from_prehash(&hash).unwrap(),
+            key.address()
+        );
+    }
+
+    #[tokio::test]
+    async fn signs_clob_auth_message() {
+        let key = PrivateKeySigner::random();
+        let signer = remote(&key);
+        let auth = ClobAuth {
+            address: key.address(),
+            timestamp: chrono::Utc::now().timestamp().to_string(),
+            nonce: U256::ZERO,
+            message: CLOB_AUTH_MESSAGE.to_string(),
+        };
+        let domain = Eip712Domain {
+            name: Some("ClobAuthDomain".into()),
+            version: Some("1".into()),
+            chain_id: Some(U256::from(POLYGON)),
+            ..Eip712Domain::default()
+        };
+        let hash = auth.eip712_signing_hash(&domain);
+        let signature = signer.sign_hash(&hash).await.unwrap();
+        assert_eq!(
+            signature.recover_address_from_prehash(&hash).unwrap(),
+            key.address()
+        );
+    }
+
+    #[tokio::test]
+    async fn refuses_unknown_hash() {
+        let signer = remote(&PrivateKeySigner::random());
+        assert!(signer.sign_hash(&B256::repeat_byte(1)).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn signs_transactions() {
+        let key = PrivateKeySigner::random();
+        let signer = remote(&key);
+        let mut tx = TxEip1559 {
+            chain_id: POLYGON,
+            nonce: 7,
+            gas_limit: 100_000,
+            max_fee_per_gas: 50_000_000_000,
+            max_priority_fee_per_gas: 30_000_000_000,
+            to: TxKind::Call(Address::repeat_byte(0x11)),
+            value: U256::from(1),
+            input: Bytes::from_static(&[0xde, 0xad]),
+            ..TxEip1559::default()
+        };
+        let signature = signer.sign_transaction(&mut tx).await.unwrap();
+        assert_eq!(
+            signature
+                .recover_address_from_prehash(&tx.signature_hash())
+                .unwrap(),
+            key.address()
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_signature_from_another_key() {
+        let key = PrivateKeySigner::random();
+        let signer = RemoteSigner::new(stand_in(PrivateKeySigner::random()), key.address())
+            .with_chain_id(Some(POLYGON));
+        let mut tx = TxEip1559 {
+            chain_id: POLYGON,
+            to: TxKind::Call(Address::repeat_byte(0x11)),
+            ..TxEip1559::default()
+        };
+        let err = signer.sign_transaction(&mut tx).await.unwrap_err();
+        assert!(err.to_string().contains("signed as"), "{err}");
+    }
+}
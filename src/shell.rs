@@ -38,9 +38,11 @@ pub async fn run_shell() -> anyhow::Result<()> {
 
                 match crate::Cli::try_parse_from(&full_args) {
                     Ok(cli) => {
-                        let output = cli.output;
+                        crate::config::set_profile_flag(cli.profile.clone());
+                        let output = cli.output_flag();
                         if let Err(e) = crate::run(cli).await {
-                            crate::output::print_error(&e, output);
+                            let report = crate::error::ErrorReport::new(&e);
+                            crate::output::print_error(&report, output);
                         }
                     }
                     Err(e) => {
@@ -0,0 +1,924 @@
+//! Sending contract calls from the wallet of the active signature type.
+
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use alloy::network::{Ethereum, Network, ReceiptResponse, TransactionBuilder};
+use alloy::primitives::utils::format_units;
+use alloy::primitives::{Address, B256, Bytes, U256};
+use alloy::providers::{PendingTransactionError, Provider, WatchTxError};
+use alloy::sol;
+use alloy::sol_types::SolCall;
+use alloy::transports::TransportError;
+use anyhow::{Context, Result};
+use clap::Args;
+use polymarket_client_sdk::types::Decimal;
+use polymarket_client_sdk::wallet_contract_config;
+
+use crate::auth;
+use crate::config::{self, SignatureType};
+use crate::error::{CliError, ErrorCode};
+use crate::journal;
+
+sol! {
+    /// Custom errors of the neg-risk adapter and the CTF exchanges, for
+    /// naming reverts. The CTF itself and USDC revert with messages.
+    interface KnownErrors {
+        error InvalidIndexSet();
+        error LengthMismatch();
+        error UnexpectedCollateralToken();
+        error NoConvertiblePositions();
+        error NotApprovedForAll();
+        error MarketNotPrepared();
+        error MarketAlreadyPrepared();
+        error MarketAlreadyDetermined();
+        error IndexOutOfBounds();
+        error OnlyOracle();
+        error NotAdmin();
+        error NotOwner();
+        error NotOperator();
+        error Paused();
+        error InvalidSignature();
+        error OrderFilledOrCancelled();
+        error OrderExpired();
+        error InvalidNonce();
+        error InvalidFeeRate();
+        error NotCrossing();
+        error MismatchedTokenIds();
+        error TooLittleTokensReceived();
+    }
+
+    struct ProxyCall {
+        uint8 typeCode;
+        address to;
+        uint256 value;
+        bytes data;
+    }
+
+    interface IProxyWalletFactory {
+        function proxy(ProxyCall[] calls) external payable returns (bytes[] returnValues);
+    }
+
+    #[sol(rpc)]
+    interface ISafeOwners {
+        function getThreshold() external view returns (uint256);
+    }
+
+    interface IGnosisSafe {
+        function execTransaction(
+            address to,
+            uint256 value,
+            bytes data,
+            uint8 operation,
+            uint256 safeTxGas,
+            uint256 baseGas,
+            uint256 gasPrice,
+            address gasToken,
+            address refundReceiver,
+            bytes signatures
+        ) external payable returns (bool success);
+    }
+}
+
+type TransactionRequest = <Ethereum as Network>::TransactionRequest;
+
+/// Gas, nonce and confirmation options of the commands that send
+/// transactions.
+#[derive(Args, Clone, Debug, Default)]
+#[command(next_help_heading = "Transaction options")]
+pub struct TxArgs {
+    /// Max fee per gas in gwei [default: estimated]
+    #[arg(long, value_name = "GWEI", value_parser = parse_gwei)]
+    max_fee: Option<u128>,
+
+    /// Max priority fee per gas in gwei [default: estimated]
+    #[arg(long, value_name = "GWEI", value_parser = parse_gwei)]
+    priority_fee: Option<u128>,
+
+    /// Gas limit [default: estimated]
+    #[arg(long)]
+    gas_limit: Option<u64>,
+
+    /// Nonce of the first transaction; later ones count up from it
+    #[arg(long)]
+    nonce: Option<u64>,
+
+    /// Scale the estimated fees, e.g. 1.5 to get through a gas spike
+    #[arg(long, value_parser = parse_multiplier)]
+    gas_multiplier: Option<f64>,
+
+    /// Blocks to wait for, counting the one the transaction is in [default: 1]
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u64).range(1..))]
+    confirmations: Option<u64>,
+
+    /// Seconds to wait for each transaction to confirm [default: no limit]
+    #[arg(long, value_name = "SECS")]
+    tx_timeout: Option<u64>,
+
+    /// Check transactions against the current chain state without sending them
+    #[arg(long)]
+    simulate: bool,
+}
+
+fn parse_gwei(s: &str) -> Result<u128, String> {
+    let gwei = Decimal::from_str(s.trim()).map_err(|_| format!("invalid gwei amount `{s}`"))?;
+    let wei = gwei
+        .checked_mul(Decimal::from(1_000_000_000u64))
+        .filter(|wei| !wei.is_sign_negative() && wei.fract().is_zero())
+        .ok_or_else(|| format!("invalid gwei amount `{s}`"))?;
+    u128::try_from(wei).map_err(|_| format!("invalid gwei amount `{s}`"))
+}
+
+fn parse_multiplier(s: &str) -> Result<f64, String> {
+    match s.trim().parse::<f64>() {
+        Ok(m) if m.is_finite() && m > 0.0 => Ok(m),
+        _ => Err(format!(
+            "invalid multiplier `{s}`: use a positive number like 1.5"
+        )),
+    }
+}
+
+static OPTIONS: Mutex<Option<TxArgs>> = Mutex::new(None);
+
+pub(crate) fn set_options(options: TxArgs) -> Result<()> {
+    if let (Some(max_fee), Some(priority_fee)) = (options.max_fee, options.priority_fee) {
+        anyhow::ensure!(
+            priority_fee <= max_fee,
+            CliError::invalid_argument("--priority-fee can't be more than --max-fee")
+        );
+    }
+    *OPTIONS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(options);
+    Ok(())
+}
+
+/// Whether `--simulate` was given.
+pub(crate) fn simulating() -> bool {
+    OPTIONS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+        .is_some_and(|options| options.simulate)
+}
+
+/// The options for the next transaction. Takes the nonce, so a command
+/// sending several transactions numbers them from `--nonce` up; under
+/// `--simulate` nothing is sent, so every check keeps `--nonce`.
+fn next_options() -> TxArgs {
+    let mut options = OPTIONS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let current = options.clone().unwrap_or_default();
+    if let Some(options) = options.as_mut()
+        && !options.simulate
+    {
+        options.nonce = options.nonce.map(|n| n + 1);
+    }
+    current
+}
+
+/// Scale `wei` by `--gas-multiplier`.
+fn scale(wei: u128, options: &TxArgs) -> u128 {
+    options
+        .gas_multiplier
+        .map_or(wei, |m| (wei as f64 * m).round() as u128)
+}
+
+/// The network's current max fee and priority fee, scaled by
+/// `--gas-multiplier`.
+async fn estimate_fees(provider: &impl Provider, options: &TxArgs) -> Result<(u128, u128)> {
+    let estimate = provider
+        .estimate_eip1559_fees()
+        .await
+        .context("Failed to estimate gas fees")?;
+    Ok((
+        scale(estimate.max_fee_per_gas, options),
+        scale(estimate.max_priority_fee_per_gas, options),
+    ))
+}
+
+/// Apply `options` to `tx`, estimating whichever fee isn't given when one
+/// of them or a multiplier is.
+async fn apply_options(
+    provider: &impl Provider,
+    mut tx: TransactionRequest,
+    options: &TxArgs,
+) -> Result<TransactionRequest> {
+    if let Some(gas_limit) = options.gas_limit {
+        tx.set_gas_limit(gas_limit);
+    }
+    if let Some(nonce) = options.nonce {
+        tx.set_nonce(nonce);
+    }
+    if options.max_fee.is_none()
+        && options.priority_fee.is_none()
+        && options.gas_multiplier.is_none()
+    {
+        return Ok(tx);
+    }
+    let (max_fee, priority_fee) = match (options.max_fee, options.priority_fee) {
+        (Some(max_fee), Some(priority_fee)) => (max_fee, priority_fee),
+        (max_fee, priority_fee) => {
+            let (estimated_max, estimated_priority) = estimate_fees(provider, options).await?;
+            let priority_fee = priority_fee.unwrap_or(estimated_priority);
+            let max_fee = max_fee.unwrap_or_else(|| estimated_max.max(priority_fee));
+            (max_fee, priority_fee.min(max_fee))
+        }
+    };
+    tx.set_max_fee_per_gas(max_fee);
+    tx.set_max_priority_fee_per_gas(priority_fee);
+    Ok(tx)
+}
+
+/// Fill in the nonce, gas limit and fees the options left open, so the
+/// journal records the transaction as it's sent.
+async fn fill(provider: &impl Provider, mut tx: TransactionRequest) -> Result<TransactionRequest> {
+    let from = tx.from.context("Transaction has no sender")?;
+    if tx.nonce.is_none() {
+        let nonce = provider
+            .get_transaction_count(from)
+            .pending()
+            .await
+            .context("Failed to get the nonce")?;
+        tx.set_nonce(nonce);
+    }
+    if tx.gas.is_none() {
+        let gas = provider
+            .estimate_gas(tx.clone())
+            .await
+            .context("Failed to estimate gas")?;
+        tx.set_gas_limit(gas);
+    }
+    if tx.max_fee_per_gas.is_none() || tx.max_priority_fee_per_gas.is_none() {
+        let estimate = provider
+            .estimate_eip1559_fees()
+            .await
+            .context("Failed to estimate gas fees")?;
+        tx.set_max_fee_per_gas(estimate.max_fee_per_gas);
+        tx.set_max_priority_fee_per_gas(estimate.max_priority_fee_per_gas);
+    }
+    Ok(tx)
+}
+
+/// `CallType.Call` of the proxy wallet factory.
+const PROXY_CALL: u8 = 1;
+
+/// The account contract calls act for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Sender {
+    Eoa(Address),
+    /// A Polymarket proxy wallet, reached through its factory.
+    Proxy {
+        wallet: Address,
+        factory: Address,
+        owner: Address,
+    },
+    /// A Gnosis Safe owned by the key, reached with `execTransaction`.
+    Safe {
+        wallet: Address,
+        owner: Address,
+    },
+}
+
+impl Sender {
+    /// The sender for the active signature type: the EOA, its proxy wallet,
+    /// or the funder Safe (else the Safe derived from the key).
+    pub(crate) async fn resolve(
+        private_key: Option<&str>,
+        signature_type: Option<SignatureType>,
+    ) -> Result<Self> {
+        let (eoa, _) = config::resolve_address(private_key)?;
+        let eoa = eoa.ok_or_else(|| CliError::auth(config::NO_WALLET_MSG))?;
+        let signature_type = config::resolve_signature_type(signature_type)?;
+        let chain = config::chain();
+        match signature_type {
+            SignatureType::Eoa => Ok(Self::Eoa(eoa)),
+            SignatureType::Proxy => {
+                let (Some(wallet), Some(factory)) = (
+                    config::derived_wallet(eoa, signature_type),
+                    wallet_contract_config(chain.id()).and_then(|c| c.proxy_factory),
+                ) else {
+                    anyhow::bail!(CliError::invalid_argument(format!(
+                        "No proxy wallets on {}; use --signature-type eoa or gnosis-safe",
+                        chain.name()
+                    )));
+                };
+                // The factory always calls the proxy of `msg.sender`.
+                if let Some(funder) = config::funder()?
+                    && funder != wallet
+                {
+                    anyhow::bail!(CliError::invalid_argument(format!(
+                        "The proxy factory only reaches {wallet}, the proxy of your key, \
+                         not the funder {funder}"
+                    )));
+                }
+                Ok(Self::Proxy {
+                    wallet,
+                    factory,
+                    owner: eoa,
+                })
+            }
+            SignatureType::GnosisSafe => {
+                let wallet = match config::funder()? {
+                    Some(funder) => funder,
+                    None => config::derived_wallet(eoa, signature_type).ok_or_else(|| {
+                        CliError::invalid_argument(format!("No Safe wallets on {}", chain.name()))
+                    })?,
+                };
+                ensure_single_owner(wallet).await?;
+                Ok(Self::Safe { wallet, owner: eoa })
+            }
+        }
+    }
+
+    /// The address whose tokens the calls move.
+    pub(crate) fn address(self) -> Address {
+        match self {
+            Self::Eoa(address)
+            | Self::Proxy {
+                wallet: address, ..
+            }
+            | Self::Safe {
+                wallet: address, ..
+            } => address,
+        }
+    }
+
+    /// The key's own address, which sends the transactions.
+    fn key_address(self) -> Address {
+        match self {
+            Self::Eoa(address)
+            | Self::Proxy { owner: address, .. }
+            | Self::Safe { owner: address, .. } => address,
+        }
+    }
+
+    pub(crate) fn signature_type(self) -> SignatureType {
+        match self {
+            Self::Eoa(_) => SignatureType::Eoa,
+            Self::Proxy { .. } => SignatureType::Proxy,
+            Self::Safe { .. } => SignatureType::GnosisSafe,
+        }
+    }
+
+    /// The transaction the key sends to make `call` from this sender.
+    fn wrap(self, call: Call) -> TransactionRequest {
+        // A proxy or Safe sends the value from its own balance.
+        let (to, input, value): (_, Bytes, _) = match self {
+            Self::Eoa(_) => (call.to, call.data, call.value),
+            Self::Proxy { factory, .. } => {
+                let calls = vec![ProxyCall {
+                    typeCode: PROXY_CALL,
+                    to: call.to,
+                    value: call.value,
+                    data: call.data,
+                }];
+                let input = IProxyWalletFactory::proxyCall { calls }.abi_encode();
+                (factory, input.into(), U256::ZERO)
+            }
+            Self::Safe { wallet, owner } => {
+                let input = IGnosisSafe::execTransactionCall {
+                    to: call.to,
+                    value: call.value,
+                    data: call.data,
+                    operation: 0,
+                    safeTxGas: U256::ZERO,
+                    baseGas: U256::ZERO,
+                    gasPrice: U256::ZERO,
+                    gasToken: Address::ZERO,
+                    refundReceiver: Address::ZERO,
+                    signatures: approved_by_sender(owner),
+                }
+                .abi_encode();
+                (wallet, input.into(), U256::ZERO)
+            }
+        };
+        let tx = TransactionRequest::default().with_to(to).with_input(input);
+        if value.is_zero() {
+            tx
+        } else {
+            tx.with_value(value)
+        }
+    }
+}
+
+/// Fail unless the Safe `wallet` executes with one signature, the only kind
+/// [`approved_by_sender`] gives. A Safe that isn't deployed yet is left to
+/// fail when it's called.
+async fn ensure_single_owner(wallet: Address) -> Result<()> {
+    let provider = auth::create_readonly_provider().await?;
+    let code = provider
+        .get_code_at(wallet)
+        .await
+        .context("Failed to read the Safe wallet")?;
+    if code.is_empty() {
+        return Ok(());
+    }
+    let threshold = ISafeOwners::new(wallet, &provider)
+        .getThreshold()
+        .call()
+        .await
+        .context("Failed to read the Safe's threshold")?;
+    anyhow::ensure!(
+        threshold <= U256::from(1),
+        CliError::invalid_argument(format!(
+            "Safe {wallet} needs {threshold} signatures per transaction; only 1-of-1 Safes \
+             can send from the CLI"
+        ))
+    );
+    Ok(())
+}
+
+/// A Safe signature that approves by `msg.sender` being `owner`, so a 1/1
+/// Safe needs no separate signing step.
+fn approved_by_sender(owner: Address) -> Bytes {
+    let mut signature = [0u8; 65];
+    signature[12..32].copy_from_slice(owner.as_slice());
+    signature[64] = 1;
+    Bytes::copy_from_slice(&signature)
+}
+
+/// A contract call to make from a [`Sender`].
+#[derive(Clone)]
+pub(crate) struct Call {
+    pub(crate) to: Address,
+    pub(crate) data: Bytes,
+    /// POL sent along, from the sender's own balance.
+    pub(crate) value: U256,
+}
+
+impl Call {
+    pub(crate) fn new(to: Address, call: &impl SolCall) -> Self {
+        Self {
+            to,
+            data: call.abi_encode().into(),
+            value: U256::ZERO,
+        }
+    }
+
+    /// A plain transfer of `value` POL to `to`.
+    pub(crate) fn native(to: Address, value: U256) -> Self {
+        Self {
+            to,
+            data: Bytes::new(),
+            value,
+        }
+    }
+}
+
+/// Reject sending funds to the zero address, where they'd be burned.
+pub(crate) fn check_recipient(to: Address) -> Result<()> {
+    anyhow::ensure!(
+        !to.is_zero(),
+        CliError::invalid_argument("Refusing to send to the zero address")
+    );
+    Ok(())
+}
+
+pub(crate) struct Sent {
+    pub(crate) transaction_hash: B256,
+    pub(crate) block_number: u64,
+    pub(crate) gas_used: u64,
+    /// Fee paid, in POL.
+    pub(crate) fee: Decimal,
+}
+
+/// Send `call` from `sender` with the transaction options and wait for it to
+/// be confirmed.
+pub(crate) async fn send(provider: &impl Provider, sender: Sender, call: Call) -> Result<Sent> {
+    let options = next_options();
+    let tx = sender.wrap(call).with_from(sender.key_address());
+    let tx = apply_options(provider, tx, &options).await?;
+    broadcast(provider, tx, &options, None).await
+}
+
+/// The journal is a convenience: failing to write it mustn't fail a
+/// transaction that was already sent.
+fn warn_unjournaled(result: Result<()>) {
+    if let Err(e) = result {
+        eprintln!("Warning: {e:#}");
+    }
+}
+
+/// Send `tx`, record it in the journal and wait for it to be confirmed.
+async fn broadcast(
+    provider: &impl Provider,
+    tx: TransactionRequest,
+    options: &TxArgs,
+    replaces: Option<B256>,
+) -> Result<Sent> {
+    let tx = fill(provider, tx).await?;
+    let pending = provider
+        .send_transaction(tx.clone())
+        .await
+        .context("Failed to send transaction")?;
+    let transaction_hash = *pending.tx_hash();
+    warn_unjournaled(journal::record(journal::Entry {
+        hash: transaction_hash,
+        chain_id: config::chain().id(),
+        from: tx.from.unwrap_or_default(),
+        nonce: tx.nonce.unwrap_or_default(),
+        to: tx.to.and_then(|to| to.to().copied()).unwrap_or_default(),
+        value: tx.value.unwrap_or_default(),
+        input: tx.input.input().cloned().unwrap_or_default(),
+        gas_limit: tx.gas.unwrap_or_default(),
+        max_fee_per_gas: tx.max_fee_per_gas.unwrap_or_default(),
+        max_priority_fee_per_gas: tx.max_priority_fee_per_gas.unwrap_or_default(),
+        sent_at: Some(chrono::Utc::now()),
+        status: journal::Status::Pending,
+        replaces,
+    }));
+    let receipt = pending
+        .with_required_confirmations(options.confirmations.unwrap_or(1))
+        .with_timeout(options.tx_timeout.map(Duration::from_secs))
+        .get_receipt()
+        .await
+        .map_err(|e| match e {
+            PendingTransactionError::TxWatcher(WatchTxError::Timeout) => CliError::new(
+                ErrorCode::Network,
+                format!(
+                    "Transaction {transaction_hash} wasn't confirmed within {}s; it may still be mined. \
+                     See `polymarket tx status {transaction_hash}`",
+                    options.tx_timeout.unwrap_or_default()
+                ),
+            )
+            .into(),
+            e => anyhow::Error::new(e).context("Failed to get transaction receipt"),
+        })?;
+    let status = if receipt.status() {
+        journal::Status::Confirmed
+    } else {
+        journal::Status::Reverted
+    };
+    warn_unjournaled(journal::set_status(transaction_hash, status));
+    anyhow::ensure!(receipt.status(), "Transaction {transaction_hash} reverted");
+    let fee = U256::from(receipt.gas_used()) * U256::from(receipt.effective_gas_price());
+    Ok(Sent {
+        transaction_hash,
+        block_number: receipt
+            .block_number()
+            .context("Block number not available in receipt")?,
+        gas_used: receipt.gas_used(),
+        fee: Decimal::from_str(&format_units(fee, 18)?)?.normalize(),
+    })
+}
+
+/// Gas limit of a plain transfer.
+const TRANSFER_GAS: u64 = 21_000;
+
+/// How to replace a pending transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Replacement {
+    /// Resend it with higher fees.
+    SpeedUp,
+    /// Send nothing to the sender itself instead.
+    Cancel,
+}
+
+/// The lowest fee a node accepts to replace a transaction paying `fee`.
+fn min_replacement_fee(fee: u128) -> u128 {
+    fee + fee.div_ceil(10)
+}
+
+/// Fees to replace a transaction paying `old`, as (max fee, priority fee):
+/// 12.5% more, to clear the 10% nodes require, or `estimate` if higher.
+fn replacement_fees(old: (u128, u128), estimate: (u128, u128)) -> (u128, u128) {
+    let bump = |fee: u128| fee + fee.div_ceil(8);
+    let priority_fee = bump(old.1).max(estimate.1);
+    (bump(old.0).max(estimate.0).max(priority_fee), priority_fee)
+}
+
+/// The transaction replacing `original`, at its nonce with fees raised
+/// past the minimum nodes accept.
+async fn replacement_tx(
+    provider: &impl Provider,
+    original: &journal::Entry,
+    replacement: Replacement,
+    options: &TxArgs,
+) -> Result<TransactionRequest> {
+    let old = (original.max_fee_per_gas, original.max_priority_fee_per_gas);
+    let (max_fee, priority_fee) = match (options.max_fee, options.priority_fee) {
+        (Some(max_fee), Some(priority_fee)) => (max_fee, priority_fee),
+        (max_fee, priority_fee) => {
+            let bumped = replacement_fees(old, estimate_fees(provider, options).await?);
+            let priority_fee = priority_fee.unwrap_or(bumped.1);
+            let max_fee = max_fee.unwrap_or_else(|| bumped.0.max(priority_fee));
+            (max_fee, priority_fee.min(max_fee))
+        }
+    };
+    anyhow::ensure!(
+        max_fee >= min_replacement_fee(old.0) && priority_fee >= min_replacement_fee(old.1),
+        CliError::invalid_argument(format!(
+            "A replacement must pay at least 10% more: a max fee of {} gwei and a \
+             priority fee of {} gwei",
+            format_units(min_replacement_fee(old.0), "gwei")?,
+            format_units(min_replacement_fee(old.1), "gwei")?
+        ))
+    );
+    Ok(match replacement {
+        Replacement::SpeedUp => TransactionRequest::default()
+            .with_to(original.to)
+            .with_value(original.value)
+            .with_input(original.input.clone())
+            .with_gas_limit(options.gas_limit.unwrap_or(original.gas_limit)),
+        Replacement::Cancel => TransactionRequest::default()
+            .with_to(original.from)
+            .with_value(U256::ZERO)
+            .with_gas_limit(options.gas_limit.unwrap_or(TRANSFER_GAS)),
+    }
+    .with_from(original.from)
+    .with_nonce(original.nonce)
+    .with_max_fee_per_gas(max_fee)
+    .with_max_priority_fee_per_gas(priority_fee))
+}
+
+/// Replace the pending `original` at its nonce and wait for the replacement
+/// to be confirmed. `--max-fee` and `--priority-fee` override the bumped fees.
+pub(crate) async fn replace(
+    provider: &impl Provider,
+    original: &journal::Entry,
+    replacement: Replacement,
+) -> Result<Sent> {
+    let options = next_options();
+    let tx = replacement_tx(provider, original, replacement, &options).await?;
+    broadcast(provider, tx, &options, Some(original.hash)).await
+}
+
+/// A replacement checked under `--simulate`, with the fees it would pay.
+pub(crate) struct SimulatedReplacement {
+    pub(crate) max_fee_per_gas: u128,
+    pub(crate) max_priority_fee_per_gas: u128,
+    pub(crate) simulation: Simulation,
+}
+
+/// `--simulate` for [`replace`]: check the replacement without sending it.
+pub(crate) async fn simulate_replacement(
+    provider: &impl Provider,
+    original: &journal::Entry,
+    replacement: Replacement,
+) -> Result<SimulatedReplacement> {
+    let options = next_options();
+    let tx = replacement_tx(provider, original, replacement, &options).await?;
+    let simulation = match provider.estimate_gas(tx.clone()).await {
+        Ok(gas) => Simulation {
+            gas_estimate: Some(gas),
+            revert_reason: None,
+        },
+        Err(e) => Simulation {
+            gas_estimate: None,
+            revert_reason: Some(revert_reason(e)?),
+        },
+    };
+    Ok(SimulatedReplacement {
+        max_fee_per_gas: tx.max_fee_per_gas.unwrap_or_default(),
+        max_priority_fee_per_gas: tx.max_priority_fee_per_gas.unwrap_or_default(),
+        simulation,
+    })
+}
+
+/// What sending a call would do.
+pub(crate) struct Simulation {
+    /// Gas the transaction would use; `None` when it reverts.
+    pub(crate) gas_estimate: Option<u64>,
+    pub(crate) revert_reason: Option<String>,
+}
+
+/// The reason a call reverted, decoded from the known contract errors.
+fn decode_revert(data: &[u8]) -> String {
+    if let Some(selector) = data.get(..4).and_then(|s| <[u8; 4]>::try_from(s).ok())
+        && let Some(name) = KnownErrors::KnownErrorsErrors::name_by_selector(selector)
+    {
+        return name.to_string();
+    }
+    if let Some(reason) = alloy::sol_types::decode_revert_reason(data) {
+        return reason;
+    }
+    format!("unknown error {}", Bytes::copy_from_slice(data))
+}
+
+/// The revert reason of a failed `eth_call` or `eth_estimateGas`, or the
+/// error itself if the node couldn't run the call.
+fn revert_reason(error: TransportError) -> Result<String> {
+    match error.as_error_resp() {
+        Some(payload) => Ok(payload
+            .as_revert_data()
+            .filter(|data| !data.is_empty())
+            .map_or_else(|| payload.message.to_string(), |data| decode_revert(&data))),
+        None => Err(anyhow::Error::new(error).context("Failed to simulate the transaction")),
+    }
+}
+
+/// Check `call` from `sender` against the current chain state without
+/// sending it.
+pub(crate) async fn simulate(
+    provider: &impl Provider,
+    sender: Sender,
+    call: &Call,
+) -> Result<Simulation> {
+    // Run the call as the wallet itself first: a proxy or Safe would swallow
+    // the revert reason of the contract.
+    let direct = TransactionRequest::default()
+        .with_from(sender.address())
+        .with_to(call.to)
+        .with_input(call.data.clone())
+        .with_value(call.value);
+    if let Err(e) = provider.call(direct).await {
+        return Ok(Simulation {
+            gas_estimate: None,
+            revert_reason: Some(revert_reason(e)?),
+        });
+    }
+    let tx = sender.wrap(call.clone()).with_from(sender.key_address());
+    match provider.estimate_gas(tx).await {
+        Ok(gas) => Ok(Simulation {
+            gas_estimate: Some(gas),
+            revert_reason: None,
+        }),
+        Err(e) => Ok(Simulation {
+            gas_estimate: None,
+            revert_reason: Some(revert_reason(e)?),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EOA: Address = Address::repeat_byte(0x11);
+    const TARGET: Address = Address::repeat_byte(0x22);
+    const WALLET: Address = Address::repeat_byte(0x33);
+
+    fn call() -> Call {
+        Call {
+            to: TARGET,
+            data: Bytes::from_static(&[1, 2, 3]),
+            value: U256::ZERO,
+        }
+    }
+
+    #[test]
+    fn gwei_converts_to_wei() {
+        assert_eq!(parse_gwei("30").unwrap(), 30_000_000_000);
+        assert_eq!(parse_gwei("1.5").unwrap(), 1_500_000_000);
+        assert_eq!(parse_gwei("0.000000001").unwrap(), 1);
+        assert!(parse_gwei("0.0000000001").is_err());
+        assert!(parse_gwei("-1").is_err());
+        assert!(parse_gwei("fast").is_err());
+    }
+
+    #[test]
+    fn multiplier_must_be_positive() {
+        assert_eq!(parse_multiplier("1.5").unwrap(), 1.5);
+        assert!(parse_multiplier("0").is_err());
+        assert!(parse_multiplier("-2").is_err());
+        assert!(parse_multiplier("inf").is_err());
+    }
+
+    #[test]
+    fn priority_fee_cannot_exceed_max_fee() {
+        let options = TxArgs {
+            max_fee: Some(1),
+            priority_fee: Some(2),
+            ..TxArgs::default()
+        };
+        assert!(set_options(options).is_err());
+    }
+
+    #[tokio::test]
+    async fn options_set_gas_without_estimating_given_fees() {
+        // Nothing listens here: given both fees, nothing is estimated.
+        let provider = alloy::providers::ProviderBuilder::new()
+            .connect_http("http://127.0.0.1:9".parse().unwrap());
+        let options = TxArgs {
+            max_fee: Some(50_000_000_000),
+            priority_fee: Some(30_000_000_000),
+            gas_limit: Some(200_000),
+            nonce: Some(7),
+            ..TxArgs::default()
+        };
+        let tx = apply_options(&provider, Sender::Eoa(EOA).wrap(call()), &options)
+            .await
+            .unwrap();
+        assert_eq!(tx.gas, Some(200_000));
+        assert_eq!(tx.nonce, Some(7));
+        assert_eq!(tx.max_fee_per_gas, Some(50_000_000_000));
+        assert_eq!(tx.max_priority_fee_per_gas, Some(30_000_000_000));
+    }
+
+    #[test]
+    fn replacements_bump_fees_past_the_minimum() {
+        let old = (100_000_000_000, 30_000_000_000);
+        let (max_fee, priority_fee) = replacement_fees(old, (50_000_000_000, 1_000_000_000));
+        assert_eq!((max_fee, priority_fee), (112_500_000_000, 33_750_000_000));
+        assert!(max_fee >= min_replacement_fee(old.0));
+        assert!(priority_fee >= min_replacement_fee(old.1));
+        // A busier network than when it was sent: the estimate wins.
+        assert_eq!(
+            replacement_fees(old, (400_000_000_000, 60_000_000_000)),
+            (400_000_000_000, 60_000_000_000)
+        );
+        assert_eq!(min_replacement_fee(1), 2);
+    }
+
+    #[test]
+    fn reverts_are_decoded() {
+        use alloy::sol_types::{Revert, SolError};
+
+        let message = Revert::from("SafeMath: subtraction overflow").abi_encode();
+        assert_eq!(
+            decode_revert(&message),
+            "revert: SafeMath: subtraction overflow"
+        );
+        let custom = KnownErrors::UnexpectedCollateralToken {}.abi_encode();
+        assert_eq!(decode_revert(&custom), "UnexpectedCollateralToken");
+        assert_eq!(
+            decode_revert(&[0xde, 0xad, 0xbe, 0xef]),
+            "unknown error 0xdeadbeef"
+        );
+    }
+
+    #[test]
+    fn eoa_calls_the_target_directly() {
+        let tx = Sender::Eoa(EOA).wrap(call());
+        assert_eq!(TransactionBuilder::<Ethereum>::to(&tx), Some(TARGET));
+        assert_eq!(
+            TransactionBuilder::<Ethereum>::input(&tx),
+            Some(&Bytes::from_static(&[1, 2, 3]))
+        );
+    }
+
+    #[test]
+    fn proxy_calls_go_through_the_factory() {
+        let factory = Address::repeat_byte(0x44);
+        let tx = Sender::Proxy {
+            wallet: WALLET,
+            factory,
+            owner: EOA,
+        }
+        .wrap(call());
+        assert_eq!(TransactionBuilder::<Ethereum>::to(&tx), Some(factory));
+        let decoded = IProxyWalletFactory::proxyCall::abi_decode(
+            TransactionBuilder::<Ethereum>::input(&tx).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(decoded.calls.len(), 1);
+        assert_eq!(decoded.calls[0].typeCode, PROXY_CALL);
+        assert_eq!(decoded.calls[0].to, TARGET);
+        assert_eq!(decoded.calls[0].data, Bytes::from_static(&[1, 2, 3]));
+    }
+
+    #[test]
+    fn safe_calls_are_approved_by_the_owner() {
+        let tx = Sender::Safe {
+            wallet: WALLET,
+            owner: EOA,
+        }
+        .wrap(call());
+        assert_eq!(TransactionBuilder::<Ethereum>::to(&tx), Some(WALLET));
+        let decoded = IGnosisSafe::execTransactionCall::abi_decode(
+            TransactionBuilder::<Ethereum>::input(&tx).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(decoded.to, TARGET);
+        assert_eq!(decoded.operation, 0);
+        let signature = decoded.signatures;
+        assert_eq!(signature.len(), 65);
+        assert_eq!(&signature[12..32], EOA.as_slice());
+        assert_eq!(signature[64], 1);
+    }
+
+    #[test]
+    fn native_transfers_come_out_of_the_sending_wallet() {
+        let value = U256::from(5);
+        let tx = Sender::Eoa(EOA).wrap(Call::native(TARGET, value));
+        assert_eq!(TransactionBuilder::<Ethereum>::value(&tx), Some(value));
+
+        let tx = Sender::Proxy {
+            wallet: WALLET,
+            factory: Address::repeat_byte(0x44),
+            owner: EOA,
+        }
+        .wrap(Call::native(TARGET, value));
+        assert_eq!(TransactionBuilder::<Ethereum>::value(&tx), None);
+        let decoded = IProxyWalletFactory::proxyCall::abi_decode(
+            TransactionBuilder::<Ethereum>::input(&tx).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(decoded.calls[0].value, value);
+
+        let tx = Sender::Safe {
+            wallet: WALLET,
+            owner: EOA,
+        }
+        .wrap(Call::native(TARGET, value));
+        assert_eq!(TransactionBuilder::<Ethereum>::value(&tx), None);
+        let decoded = IGnosisSafe::execTransactionCall::abi_decode(
+            TransactionBuilder::<Ethereum>::input(&tx).unwrap(),
+        )
+        .unwrap();
+        assert_eq!((decoded.to, decoded.value), (TARGET, value));
+    }
+}
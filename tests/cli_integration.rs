@@ -7,9 +7,63 @@ fn polymarket() -> Command {
     let mut cmd = Command::cargo_bin("polymarket").unwrap();
     cmd.env_remove("POLYMARKET_PRIVATE_KEY");
     cmd.env_remove("POLYMARKET_SIGNATURE_TYPE");
+    cmd.env_remove("POLYMARKET_PASSWORD");
+    for var in [
+        "POLYMARKET_CHAIN",
+        "POLYMARKET_FUNDER",
+        "POLYMARKET_RPC_URL",
+        "POLYMARKET_GAMMA_URL",
+        "POLYMARKET_CLOB_URL",
+        "POLYMARKET_DATA_URL",
+        "POLYMARKET_BRIDGE_URL",
+    ] {
+        cmd.env_remove(var);
+    }
     cmd
 }
 
+/// A local HTTP server answering requests with the JSON body of the first
+/// matching route, or 404; returns its URL. Routes match the path, or with a
+/// `?` the start of the path and query, ignoring case.
+fn stub_api(routes: &'static [(&'static str, &'static str)]) -> String {
+    use std::io::{BufRead, BufReader, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                    break;
+                }
+            }
+            let target = request_line.split(' ').nth(1).unwrap_or_default();
+            let path = target.split('?').next().unwrap_or_default();
+            let (status, body) = routes
+                .iter()
+                .find(|(route, _)| {
+                    if route.contains('?') {
+                        target.to_lowercase().starts_with(&route.to_lowercase())
+                    } else {
+                        *route == path
+                    }
+                })
+                .map_or(("404 Not Found", "{}"), |(_, body)| ("200 OK", *body));
+            let response = format!(
+                "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            let _ = stream.write_all(response.as_bytes());
+        }
+    });
+    url
+}
+
 #[test]
 fn help_lists_all_top_level_commands() {
     polymarket().arg("--help").assert().success().stdout(
@@ -25,6 +79,7 @@ fn help_lists_all_top_level_commands() {
             .and(predicate::str::contains("approve"))
             .and(predicate::str::contains("clob"))
             .and(predicate::str::contains("ctf"))
+            .and(predicate::str::contains("tx"))
             .and(predicate::str::contains("data"))
             .and(predicate::str::contains("bridge"))
             .and(predicate::str::contains("wallet"))
@@ -121,10 +176,10 @@ fn comments_list_requires_entity_args() {
 }
 
 // Uses a guaranteed-to-fail command (nonexistent slug) to verify the error
-// output contract: JSON mode → structured error on stdout, table mode → stderr.
+// output contract: errors go to stderr in both modes, JSON mode → structured error object.
 
 #[test]
-fn json_mode_error_is_valid_json_with_error_key() {
+fn json_mode_error_is_structured_json_on_stderr() {
     let output = polymarket()
         .args([
             "--output",
@@ -137,13 +192,58 @@ fn json_mode_error_is_valid_json_with_error_key() {
         .unwrap();
 
     assert!(!output.status.success());
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let parsed: serde_json::Value = serde_json::from_str(stdout.trim())
-        .unwrap_or_else(|e| panic!("stdout not valid JSON: {e}\nstdout: {stdout}"));
     assert!(
-        parsed.get("error").is_some(),
-        "missing 'error' key: {parsed}"
+        output.stdout.is_empty(),
+        "stdout should stay empty on error"
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let parsed: serde_json::Value = serde_json::from_str(stderr.trim())
+        .unwrap_or_else(|e| panic!("stderr not valid JSON: {e}\nstderr: {stderr}"));
+    let error = &parsed["error"];
+    assert!(error["code"].is_string(), "missing 'code': {parsed}");
+    assert!(error["message"].is_string(), "missing 'message': {parsed}");
+    assert!(
+        error["retryable"].is_boolean(),
+        "missing 'retryable': {parsed}"
     );
+    assert_eq!(
+        error["exit_code"].as_i64(),
+        output.status.code().map(i64::from),
+        "exit code mismatch: {parsed}"
+    );
+}
+
+#[test]
+fn invalid_argument_exits_with_code_2() {
+    let output = polymarket()
+        .args([
+            "-o",
+            "json",
+            "ctf",
+            "split",
+            "--condition",
+            "0x0000000000000000000000000000000000000000000000000000000000000001",
+            "--amount",
+            "abc",
+        ])
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(2));
+    let parsed: serde_json::Value =
+        serde_json::from_str(String::from_utf8_lossy(&output.stderr).trim()).unwrap();
+    assert_eq!(parsed["error"]["code"], "invalid_argument");
+}
+
+#[test]
+fn missing_wallet_exits_with_auth_code() {
+    let home = std::env::temp_dir().join("polymarket-cli-test-empty-home");
+    polymarket()
+        .env("HOME", &home)
+        .args(["wallet", "address"])
+        .assert()
+        .code(3)
+        .stderr(predicate::str::contains("No wallet configured"));
 }
 
 #[test]
@@ -177,6 +277,28 @@ fn wallet_show_json_has_configured_field() {
     );
 }
 
+#[test]
+fn wallet_show_honours_signature_type_flag() {
+    let home = temp_home("show-signature-type");
+    let show = |signature_type: &str| -> serde_json::Value {
+        let output = polymarket()
+            .env("HOME", &home)
+            .args(["-o", "json", "--private-key", TEST_KEY])
+            .args(["--signature-type", signature_type, "wallet", "show"])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        serde_json::from_slice(&output.stdout).unwrap()
+    };
+    let safe = show("gnosis-safe");
+    let proxy = show("proxy");
+    let eoa = show("eoa");
+    assert_eq!(safe["signature_type"], "gnosis-safe");
+    assert_eq!(eoa["trading_wallet"], eoa["address"]);
+    assert_ne!(safe["trading_wallet"], proxy["trading_wallet"]);
+    assert_ne!(safe["trading_wallet"], safe["address"]);
+}
+
 #[test]
 fn tags_help_lists_subcommands() {
     polymarket()
@@ -318,18 +440,17 @@ fn clob_price_requires_token() {
     polymarket().args(["clob", "price"]).assert().failure();
 }
 
-#[test]
-fn data_positions_requires_address() {
-    polymarket().args(["data", "positions"]).assert().failure();
-}
-
 #[test]
 fn approve_help_lists_subcommands() {
     polymarket()
         .args(["approve", "--help"])
         .assert()
         .success()
-        .stdout(predicate::str::contains("check").and(predicate::str::contains("set")));
+        .stdout(
+            predicate::str::contains("check")
+                .and(predicate::str::contains("set"))
+                .and(predicate::str::contains("revoke")),
+        );
 }
 
 #[test]
@@ -470,6 +591,31 @@ fn json_flag_short_form_works() {
         .success();
 }
 
+#[test]
+fn template_output_requires_template() {
+    polymarket()
+        .args(["-o", "template", "wallet", "show"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("--template"));
+}
+
+#[test]
+fn template_output_renders_json_fields() {
+    polymarket()
+        .args([
+            "-o",
+            "template",
+            "--template",
+            "configured={{ configured }}",
+            "wallet",
+            "show",
+        ])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("configured="));
+}
+
 #[test]
 fn table_output_is_default() {
     polymarket()
@@ -486,3 +632,1387 @@ fn wallet_address_succeeds_or_fails_gracefully() {
     // Either succeeds or fails with an error message — not a panic
     assert!(output.status.success() || !output.stderr.is_empty());
 }
+
+#[test]
+fn schema_prints_json_schema_for_command() {
+    let output = polymarket()
+        .args(["schema", "markets", "list"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let schema: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(schema["title"], "polymarket markets list");
+    assert_eq!(schema["type"], "array");
+}
+
+#[test]
+fn schema_json_lists_commands() {
+    let output = polymarket()
+        .args(["-o", "json", "schema"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(parsed["schema_version"].is_u64());
+    assert!(
+        parsed["commands"]
+            .as_array()
+            .unwrap()
+            .contains(&serde_json::json!("clob book"))
+    );
+}
+
+#[test]
+fn schema_for_unknown_command_is_invalid_argument() {
+    polymarket()
+        .args(["schema", "nonexistent"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("No schema"));
+}
+
+#[test]
+fn envelope_wraps_json_output() {
+    let output = polymarket()
+        .args(["-o", "json", "--envelope", "wallet", "show"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(parsed["schema_version"].is_u64());
+    assert!(parsed["data"]["configured"].is_boolean());
+    assert!(parsed["next_cursor"].is_null());
+}
+
+#[test]
+fn invalid_timezone_is_rejected() {
+    polymarket()
+        .args(["--timezone", "Mars/Olympus", "wallet", "show"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid timezone"));
+}
+
+#[test]
+fn display_flags_are_global() {
+    polymarket()
+        .args([
+            "wallet",
+            "show",
+            "--timezone",
+            "local",
+            "--numbers",
+            "full",
+            "--price-style",
+            "american",
+        ])
+        .assert()
+        .success();
+}
+
+fn temp_home(name: &str) -> std::path::PathBuf {
+    let home = std::env::temp_dir().join(format!("polymarket-cli-test-{name}"));
+    let _ = std::fs::remove_dir_all(&home);
+    std::fs::create_dir_all(home.join(".config/polymarket")).unwrap();
+    home
+}
+
+#[test]
+fn legacy_config_is_read_as_default_profile() {
+    let home = temp_home("legacy-config");
+    std::fs::write(
+        home.join(".config/polymarket/config.json"),
+        r#"{"private_key": "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318", "chain_id": 137, "signature_type": "eoa"}"#,
+    )
+    .unwrap();
+    let output = polymarket()
+        .env("HOME", &home)
+        .args(["-o", "json", "wallet", "list"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed[0]["name"], "default");
+    assert_eq!(parsed[0]["signature_type"], "eoa");
+    assert_eq!(parsed[0]["active"], true);
+}
+
+#[test]
+fn watch_only_profile_is_selected_with_profile_flag() {
+    let home = temp_home("watch-only");
+    polymarket()
+        .env("HOME", &home)
+        .args([
+            "wallet",
+            "import",
+            "--address",
+            "0x56687bf447db6ffa42ffe2204a05edaa20f55839",
+            "--name",
+            "watcher",
+        ])
+        .assert()
+        .success();
+    polymarket()
+        .env("HOME", &home)
+        .env("POLYMARKET_PROFILE", "watcher")
+        .args(["wallet", "address"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "0x56687BF447DB6fFA42FFE2204a05EDAA20f55839",
+        ));
+    polymarket()
+        .env("HOME", &home)
+        .args(["--profile", "watcher", "clob", "orders"])
+        .assert()
+        .code(3)
+        .stderr(predicate::str::contains("watch-only"));
+}
+
+#[test]
+fn unknown_profile_is_invalid_argument() {
+    let home = temp_home("unknown-profile");
+    polymarket()
+        .env("HOME", &home)
+        .args(["--profile", "nope", "wallet", "address"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("Profile `nope` not found"));
+}
+
+const TEST_KEY: &str = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+
+#[test]
+fn imported_key_is_stored_in_encrypted_keystore() {
+    let home = temp_home("keystore");
+    polymarket()
+        .env("HOME", &home)
+        .env("POLYMARKET_PASSWORD", "hunter2")
+        .args(["wallet", "import", TEST_KEY])
+        .assert()
+        .success();
+    let config = std::fs::read_to_string(home.join(".config/polymarket/config.json")).unwrap();
+    assert!(!config.contains("private_key"));
+    assert!(
+        home.join(".config/polymarket/keystores/0x2c7536e3605d9c16a7a3d7b1898e529396a65c23.json")
+            .exists()
+    );
+
+    polymarket()
+        .env("HOME", &home)
+        .args(["wallet", "address"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23",
+        ));
+
+    let output = polymarket()
+        .env("HOME", &home)
+        .env("POLYMARKET_PASSWORD", "hunter2")
+        .args(["-o", "json", "wallet", "export"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["private_key"], TEST_KEY);
+
+    polymarket()
+        .env("HOME", &home)
+        .env("POLYMARKET_PASSWORD", "wrong")
+        .args(["wallet", "export"])
+        .assert()
+        .code(3)
+        .stderr(predicate::str::contains("Wrong keystore password"));
+}
+
+const OTHER_KEY: &str = "0x8f2a55949038a9610f50fb23b5883af3b4ecb3c3bb792cbcefbd1542c692be63";
+
+fn exported_key(home: &std::path::Path, profile: &str) -> String {
+    let output = polymarket()
+        .env("HOME", home)
+        .env("POLYMARKET_PASSWORD", "hunter2")
+        .args(["-o", "json", "--profile", profile, "wallet", "export"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    parsed["private_key"].as_str().unwrap().to_string()
+}
+
+#[test]
+fn renamed_profile_keeps_its_keystore() {
+    let home = temp_home("keystore-rename");
+    for (key, force) in [(TEST_KEY, false), (OTHER_KEY, true)] {
+        let mut cmd = polymarket();
+        cmd.env("HOME", &home)
+            .env("POLYMARKET_PASSWORD", "hunter2")
+            .args(["wallet", "import", key, "--name", "main"]);
+        if force {
+            cmd.arg("--force");
+        }
+        cmd.assert().success();
+        if !force {
+            polymarket()
+                .env("HOME", &home)
+                .args(["wallet", "rename", "main", "old"])
+                .assert()
+                .success();
+        }
+    }
+    assert_eq!(exported_key(&home, "old"), TEST_KEY);
+    assert_eq!(exported_key(&home, "main"), OTHER_KEY);
+
+    // The same key imported twice gets a keystore of its own.
+    polymarket()
+        .env("HOME", &home)
+        .env("POLYMARKET_PASSWORD", "hunter2")
+        .args(["wallet", "import", TEST_KEY, "--name", "again"])
+        .assert()
+        .success();
+    assert_eq!(exported_key(&home, "again"), TEST_KEY);
+    assert_eq!(exported_key(&home, "old"), TEST_KEY);
+}
+
+#[test]
+fn profile_names_are_validated() {
+    let home = temp_home("profile-names");
+    polymarket()
+        .env("HOME", &home)
+        .args([
+            "wallet",
+            "import",
+            TEST_KEY,
+            "--plaintext",
+            "--name",
+            "../x",
+        ])
+        .assert()
+        .code(2);
+    polymarket()
+        .env("HOME", &home)
+        .args([
+            "wallet",
+            "import",
+            TEST_KEY,
+            "--plaintext",
+            "--name",
+            "main",
+        ])
+        .assert()
+        .success();
+    polymarket()
+        .env("HOME", &home)
+        .args(["wallet", "rename", "main", "a/b"])
+        .assert()
+        .code(2);
+}
+
+#[test]
+fn plaintext_key_can_be_encrypted_later() {
+    let home = temp_home("encrypt");
+    polymarket()
+        .env("HOME", &home)
+        .args(["wallet", "import", TEST_KEY, "--plaintext"])
+        .assert()
+        .success();
+    let config_path = home.join(".config/polymarket/config.json");
+    assert!(
+        std::fs::read_to_string(&config_path)
+            .unwrap()
+            .contains("private_key")
+    );
+
+    polymarket()
+        .env("HOME", &home)
+        .args(["wallet", "encrypt", "--password-command", "echo hunter2"])
+        .assert()
+        .success();
+    assert!(
+        !std::fs::read_to_string(&config_path)
+            .unwrap()
+            .contains("private_key")
+    );
+
+    polymarket()
+        .env("HOME", &home)
+        .args(["wallet", "export"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(&TEST_KEY[2..]));
+}
+
+#[test]
+fn remote_signer_profile_has_no_local_key() {
+    let home = temp_home("remote-signer");
+    polymarket()
+        .env("HOME", &home)
+        .args([
+            "wallet",
+            "import",
+            "--address",
+            "0x56687bf447db6ffa42ffe2204a05edaa20f55839",
+            "--signer-url",
+            "http://127.0.0.1:9000",
+        ])
+        .assert()
+        .success();
+    let output = polymarket()
+        .env("HOME", &home)
+        .args(["-o", "json", "wallet", "show"])
+        .output()
+        .unwrap();
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["source"], "remote signer");
+    assert_eq!(parsed["signer_url"], "http://127.0.0.1:9000");
+    polymarket()
+        .env("HOME", &home)
+        .args(["wallet", "export"])
+        .assert()
+        .code(3)
+        .stderr(predicate::str::contains("remote signer"));
+}
+
+#[test]
+fn signer_url_requires_address() {
+    polymarket()
+        .args(["wallet", "import", "--signer-url", "http://127.0.0.1:9000"])
+        .assert()
+        .failure();
+}
+
+const TEST_MNEMONIC: &str =
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+#[test]
+fn wallet_derive_lists_accounts() {
+    let output = polymarket()
+        .args([
+            "-o", "json", "wallet", "derive", "--count", "2", "--start", "1",
+        ])
+        .write_stdin(TEST_MNEMONIC)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed.as_array().unwrap().len(), 2);
+    assert_eq!(parsed[0]["index"], 1);
+    assert_eq!(parsed[0]["derivation_path"], "m/44'/60'/0'/0/1");
+    assert_eq!(
+        parsed[0]["address"],
+        "0x6Fac4D18c912343BF86fa7049364Dd4E424Ab9C0"
+    );
+}
+
+#[test]
+fn wallet_import_mnemonic_uses_index() {
+    let home = temp_home("mnemonic");
+    let output = polymarket()
+        .env("HOME", &home)
+        .args([
+            "-o",
+            "json",
+            "wallet",
+            "import",
+            "--mnemonic",
+            "--index",
+            "2",
+            "--plaintext",
+        ])
+        .write_stdin(format!("{TEST_MNEMONIC}\n"))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        parsed["address"],
+        "0xb6716976A3ebe8D39aCEB04372f22Ff8e6802D7A"
+    );
+    assert_eq!(parsed["derivation_path"], "m/44'/60'/0'/0/2");
+}
+
+#[test]
+fn recovery_phrase_is_not_an_argument() {
+    polymarket()
+        .args(["wallet", "derive", "--mnemonic", TEST_MNEMONIC])
+        .assert()
+        .code(2);
+    polymarket()
+        .args(["wallet", "import", "--mnemonic", TEST_MNEMONIC])
+        .assert()
+        .code(2);
+    polymarket()
+        .args(["wallet", "derive"])
+        .write_stdin("")
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("No recovery phrase"));
+}
+
+#[test]
+fn wallet_index_requires_mnemonic() {
+    polymarket()
+        .args(["wallet", "import", TEST_KEY, "--index", "1"])
+        .assert()
+        .code(2);
+}
+
+#[test]
+fn config_set_changes_defaults() {
+    let home = temp_home("config-set");
+    polymarket()
+        .env("HOME", &home)
+        .args(["config", "set", "limit", "5"])
+        .assert()
+        .success();
+    polymarket()
+        .env("HOME", &home)
+        .args(["config", "set", "output", "json"])
+        .assert()
+        .success();
+
+    let output = polymarket()
+        .env("HOME", &home)
+        .args(["config", "get", "limit"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["value"], 5);
+    assert_eq!(parsed["source"], "config");
+
+    polymarket()
+        .env("HOME", &home)
+        .args(["config", "unset", "output"])
+        .assert()
+        .success();
+    polymarket()
+        .env("HOME", &home)
+        .args(["config", "get", "output"])
+        .assert()
+        .success()
+        .stdout("table\n");
+}
+
+#[test]
+fn config_set_for_profile() {
+    let home = temp_home("config-profile");
+    polymarket()
+        .env("HOME", &home)
+        .args([
+            "wallet",
+            "import",
+            "--address",
+            "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23",
+            "--name",
+            "desk",
+        ])
+        .assert()
+        .success();
+    polymarket()
+        .env("HOME", &home)
+        .args(["--profile", "desk", "config", "set", "timeout", "30"])
+        .assert()
+        .success();
+
+    let output = polymarket()
+        .env("HOME", &home)
+        .args([
+            "-o",
+            "json",
+            "--profile",
+            "desk",
+            "config",
+            "get",
+            "timeout",
+        ])
+        .output()
+        .unwrap();
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["value"], 30);
+    assert_eq!(parsed["source"], "profile desk");
+
+    polymarket()
+        .env("HOME", &home)
+        .args(["--profile", "missing", "config", "set", "timeout", "30"])
+        .assert()
+        .code(2);
+}
+
+#[test]
+fn config_rejects_bad_values() {
+    let home = temp_home("config-invalid");
+    for args in [
+        ["config", "set", "limit", "abc"],
+        ["config", "set", "output", "xml"],
+        ["config", "set", "gamma_url", "not a url"],
+        ["config", "set", "nope", "1"],
+    ] {
+        polymarket().env("HOME", &home).args(args).assert().code(2);
+    }
+}
+
+#[test]
+fn gamma_url_flag_redirects_requests() {
+    let url = stub_api(&[("/status", "OK")]);
+    polymarket()
+        .args(["-o", "json", "--gamma-url", &url, "status"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(r#""status": "OK""#));
+}
+
+#[test]
+fn gamma_url_env_var_redirects_requests() {
+    let url = stub_api(&[("/markets", "[]")]);
+    polymarket()
+        .env("POLYMARKET_GAMMA_URL", &url)
+        .args(["-o", "json", "markets", "list"])
+        .assert()
+        .success()
+        .stdout("[]\n");
+}
+
+#[test]
+fn clob_url_flag_redirects_requests() {
+    let url = stub_api(&[("/midpoint", r#"{"mid": "0.42"}"#)]);
+    polymarket()
+        .args(["-o", "json", "--clob-url", &url, "clob", "midpoint", "123"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("0.42"));
+}
+
+#[test]
+fn data_url_under_a_path_prefix() {
+    let url = stub_api(&[("/data/positions", "[]")]);
+    polymarket()
+        .env("POLYMARKET_DATA_URL", format!("{url}/data"))
+        .args([
+            "-o",
+            "json",
+            "data",
+            "positions",
+            "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23",
+        ])
+        .assert()
+        .success()
+        .stdout("[]\n");
+}
+
+#[test]
+fn flag_overrides_env_var_and_config() {
+    let home = temp_home("url-precedence");
+    let url = stub_api(&[("/status", "OK")]);
+    polymarket()
+        .env("HOME", &home)
+        .args(["config", "set", "gamma_url", "http://127.0.0.1:9"])
+        .assert()
+        .success();
+    polymarket()
+        .env("HOME", &home)
+        .env("POLYMARKET_GAMMA_URL", "http://127.0.0.1:9")
+        .args(["--gamma-url", &url, "status"])
+        .assert()
+        .success();
+
+    let output = polymarket()
+        .env("HOME", &home)
+        .env("POLYMARKET_GAMMA_URL", &url)
+        .args(["-o", "json", "config", "get", "gamma_url"])
+        .output()
+        .unwrap();
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["value"], url.as_str());
+    assert_eq!(parsed["source"], "POLYMARKET_GAMMA_URL");
+}
+
+#[test]
+fn api_error_from_stub_is_reported() {
+    let url = stub_api(&[]);
+    polymarket()
+        .args(["-o", "json", "--gamma-url", &url, "status"])
+        .assert()
+        .code(9)
+        .stderr(predicate::str::contains(r#""code":"not_found""#));
+}
+
+#[test]
+fn invalid_api_url_is_rejected() {
+    polymarket()
+        .args(["--gamma-url", "not a url", "status"])
+        .assert()
+        .code(2);
+}
+
+#[test]
+fn chain_flag_is_saved_with_new_profiles() {
+    let home = temp_home("chain-amoy");
+    polymarket()
+        .env("HOME", &home)
+        .args([
+            "--chain",
+            "amoy",
+            "wallet",
+            "import",
+            TEST_KEY,
+            "--plaintext",
+            "--signature-type",
+            "eoa",
+        ])
+        .assert()
+        .success();
+    let config = std::fs::read_to_string(home.join(".config/polymarket/config.json")).unwrap();
+    assert!(config.contains(r#""chain_id": 80002"#));
+
+    let output = polymarket()
+        .env("HOME", &home)
+        .args(["-o", "json", "wallet", "show"])
+        .output()
+        .unwrap();
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["chain"], "amoy");
+    assert_eq!(parsed["trading_wallet"], parsed["address"]);
+
+    let output = polymarket()
+        .env("HOME", &home)
+        .args(["-o", "json", "config", "get", "rpc_url"])
+        .output()
+        .unwrap();
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["value"], "https://rpc-amoy.polygon.technology");
+
+    let output = polymarket()
+        .env("HOME", &home)
+        .args(["-o", "json", "--chain", "polygon", "wallet", "show"])
+        .output()
+        .unwrap();
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["chain"], "polygon");
+}
+
+#[test]
+fn unknown_chain_is_rejected() {
+    polymarket()
+        .args(["--chain", "mainnet", "wallet", "show"])
+        .assert()
+        .code(2);
+    polymarket()
+        .env("POLYMARKET_CHAIN", "mainnet")
+        .args(["wallet", "show"])
+        .assert()
+        .code(2);
+}
+
+#[test]
+fn data_commands_default_to_the_wallet() {
+    let home = temp_home("data-self");
+    polymarket()
+        .env("HOME", &home)
+        .args(["wallet", "import", TEST_KEY, "--plaintext"])
+        .assert()
+        .success();
+    let proxy = polymarket()
+        .env("HOME", &home)
+        .args(["-o", "json", "wallet", "show"])
+        .output()
+        .unwrap();
+    let proxy: serde_json::Value = serde_json::from_slice(&proxy.stdout).unwrap();
+    let proxy = proxy["trading_wallet"].as_str().unwrap().to_string();
+    let route: &'static str = Box::leak(format!("/value?user={proxy}").into_boxed_str());
+    let routes: &'static [(&str, &str)] = Box::leak(Box::new([
+        (
+            route,
+            r#"[{"user": "0x0000000000000000000000000000000000000001", "value": 5}]"#,
+        ),
+        (
+            "/value?user=0x2c7536e3605d9c16a7a3d7b1898e529396a65c23",
+            "[]",
+        ),
+    ]));
+    let url = stub_api(routes);
+
+    for args in [
+        vec!["data", "value"],
+        vec!["data", "value", "--address", "proxy"],
+    ] {
+        polymarket()
+            .env("HOME", &home)
+            .args(["-o", "json", "--data-url", &url])
+            .args(args)
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("5"));
+    }
+    polymarket()
+        .env("HOME", &home)
+        .args(["-o", "json", "--data-url", &url, "data", "value", "eoa"])
+        .assert()
+        .success()
+        .stdout("[]\n");
+    polymarket()
+        .env("HOME", &home)
+        .args(["-o", "json", "--data-url", &url, "--signature-type", "eoa"])
+        .args(["data", "value", "self"])
+        .assert()
+        .success()
+        .stdout("[]\n");
+}
+
+#[test]
+fn data_address_needs_a_wallet_or_an_address() {
+    let home = temp_home("data-no-wallet");
+    polymarket()
+        .env("HOME", &home)
+        .args(["data", "positions"])
+        .assert()
+        .code(3);
+    polymarket()
+        .args(["data", "positions", "nobody"])
+        .assert()
+        .code(2);
+    polymarket()
+        .args(["data", "positions", "eoa", "--address", "proxy"])
+        .assert()
+        .code(2);
+}
+
+#[test]
+fn set_funder_is_checked_and_shown() {
+    let home = temp_home("set-funder");
+    let funder = "0x56687bf447db6ffa42ffe2204a05edaa20f55839";
+    polymarket()
+        .env("HOME", &home)
+        .args(["wallet", "import", TEST_KEY, "--plaintext"])
+        .assert()
+        .success();
+
+    // The proxy derived from the key verifies without a network call.
+    let output = polymarket()
+        .env("HOME", &home)
+        .args(["-o", "json", "wallet", "show"])
+        .output()
+        .unwrap();
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let proxy = parsed["trading_wallet"].as_str().unwrap().to_string();
+    let output = polymarket()
+        .env("HOME", &home)
+        .args(["-o", "json", "wallet", "set-funder", &proxy])
+        .output()
+        .unwrap();
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["verified"], true);
+
+    polymarket()
+        .env("HOME", &home)
+        .args(["wallet", "set-funder", funder])
+        .env("POLYMARKET_SIGNATURE_TYPE", "eoa")
+        .assert()
+        .code(2);
+    polymarket()
+        .env("HOME", &home)
+        .args([
+            "--rpc-url",
+            "http://127.0.0.1:9",
+            "wallet",
+            "set-funder",
+            funder,
+        ])
+        .env("POLYMARKET_SIGNATURE_TYPE", "gnosis-safe")
+        .assert()
+        .code(7);
+
+    let output = polymarket()
+        .env("HOME", &home)
+        .args(["-o", "json", "wallet", "set-funder", funder])
+        .output()
+        .unwrap();
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["verified"], false);
+
+    let output = polymarket()
+        .env("HOME", &home)
+        .args(["-o", "json", "wallet", "show"])
+        .output()
+        .unwrap();
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        parsed["funder"].as_str().unwrap().to_lowercase(),
+        funder.to_lowercase()
+    );
+
+    let output = polymarket()
+        .env("HOME", &home)
+        .args(["-o", "json", "--funder", &proxy, "wallet", "show"])
+        .output()
+        .unwrap();
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["funder"], proxy.as_str());
+
+    polymarket()
+        .env("HOME", &home)
+        .args(["wallet", "set-funder", "--clear"])
+        .assert()
+        .success();
+    let config = std::fs::read_to_string(home.join(".config/polymarket/config.json")).unwrap();
+    assert!(!config.contains("funder"));
+}
+
+#[test]
+fn signature_type_values_are_strict() {
+    let home = temp_home("strict-sig-type");
+    polymarket()
+        .env("HOME", &home)
+        .args(["wallet", "create", "--signature-type", "gnosis_safe"])
+        .assert()
+        .code(2);
+    polymarket()
+        .env("HOME", &home)
+        .args(["wallet", "import", TEST_KEY, "--plaintext"])
+        .assert()
+        .success();
+    polymarket()
+        .env("HOME", &home)
+        .env("POLYMARKET_SIGNATURE_TYPE", "safe")
+        .args(["wallet", "show"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("POLYMARKET_SIGNATURE_TYPE"));
+}
+
+#[test]
+fn wallet_detect_needs_a_wallet_and_the_rpc() {
+    let home = temp_home("detect");
+    polymarket()
+        .env("HOME", &home)
+        .args(["wallet", "detect"])
+        .assert()
+        .code(3);
+    polymarket()
+        .env("HOME", &home)
+        .args(["wallet", "import", TEST_KEY, "--plaintext"])
+        .assert()
+        .success();
+    polymarket()
+        .env("HOME", &home)
+        .args(["--rpc-url", "http://127.0.0.1:9", "wallet", "detect"])
+        .assert()
+        .code(7);
+}
+
+fn position_json(
+    condition: &str,
+    outcome: &str,
+    index: u8,
+    size: &str,
+    price: &str,
+    neg_risk: bool,
+) -> String {
+    serde_json::json!({
+        "proxyWallet": "0x2c7536e3605d9c16a7a3d7b1898e529396a65c23",
+        "asset": format!("{}", 100 + index),
+        "conditionId": condition,
+        "size": size,
+        "avgPrice": "0.5",
+        "initialValue": "1",
+        "currentValue": "1",
+        "cashPnl": "0",
+        "percentPnl": "0",
+        "totalBought": "1",
+        "realizedPnl": "0",
+        "percentRealizedPnl": "0",
+        "curPrice": price,
+        "redeemable": true,
+        "mergeable": false,
+        "title": "Test market",
+        "slug": "test-market",
+        "icon": "",
+        "eventSlug": "test-event",
+        "eventId": "1",
+        "outcome": outcome,
+        "outcomeIndex": index,
+        "oppositeOutcome": if index == 0 { "No" } else { "Yes" },
+        "oppositeAsset": format!("{}", 101 - index),
+        "endDate": "2026-01-01",
+        "negativeRisk": neg_risk,
+    })
+    .to_string()
+}
+
+#[test]
+fn redeem_all_dry_run_groups_by_condition() {
+    let home = temp_home("redeem-all");
+    polymarket()
+        .env("HOME", &home)
+        .args(["wallet", "import", TEST_KEY, "--plaintext"])
+        .assert()
+        .success();
+    let standard = format!("0x{}", "11".repeat(32));
+    let neg_risk = format!("0x{}", "22".repeat(32));
+    let positions = format!(
+        "[{},{},{}]",
+        position_json(&standard, "Yes", 0, "10", "1", false),
+        position_json(&neg_risk, "Yes", 0, "2", "0", true),
+        position_json(&neg_risk, "No", 1, "4.5", "1", true),
+    );
+    let proxy = polymarket()
+        .env("HOME", &home)
+        .args(["-o", "json", "wallet", "show"])
+        .output()
+        .unwrap();
+    let proxy: serde_json::Value = serde_json::from_slice(&proxy.stdout).unwrap();
+    let proxy = proxy["trading_wallet"].as_str().unwrap().to_string();
+    let proxy_route: &'static str = Box::leak(format!("/positions?user={proxy}").into_boxed_str());
+    let routes: &'static [(&str, &str)] = Box::leak(Box::new([
+        (
+            "/positions?user=0x2c7536e3605d9c16a7a3d7b1898e529396a65c23",
+            &*Box::leak(positions.into_boxed_str()),
+        ),
+        (proxy_route, "[]"),
+    ]));
+    let url = stub_api(routes);
+
+    // Proxy users redeem what their proxy wallet holds.
+    let output = polymarket()
+        .env("HOME", &home)
+        .args(["-o", "json", "--data-url", &url])
+        .args(["ctf", "redeem-all", "--dry-run"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["address"], proxy);
+    assert_eq!(parsed["redemptions"], serde_json::json!([]));
+
+    let output = polymarket()
+        .env("HOME", &home)
+        .args(["-o", "json", "--data-url", &url, "--signature-type", "eoa"])
+        .args(["ctf", "redeem-all", "--dry-run"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(parsed["dry_run"], true);
+    assert_eq!(parsed["total_payout"], "14.5");
+    let redemptions = parsed["redemptions"].as_array().unwrap();
+    assert_eq!(redemptions.len(), 2);
+    assert_eq!(redemptions[0]["neg_risk"], false);
+    assert_eq!(redemptions[1]["neg_risk"], true);
+    assert_eq!(redemptions[1]["outcomes"].as_array().unwrap().len(), 2);
+    assert!(redemptions[1]["transaction_hash"].is_null());
+}
+
+#[test]
+fn merge_all_reads_balances_on_chain() {
+    let home = temp_home("merge-all");
+    polymarket()
+        .env("HOME", &home)
+        .args(["wallet", "import", TEST_KEY, "--plaintext"])
+        .assert()
+        .success();
+    polymarket()
+        .env("HOME", &home)
+        .args(["ctf", "merge-all", "--keep", "-1"])
+        .assert()
+        .code(2);
+
+    let condition = format!("0x{}", "33".repeat(32));
+    let positions = format!(
+        "[{}]",
+        position_json(&condition, "Yes", 0, "10", "0.5", false)
+    );
+    let routes: &'static [(&str, &str)] = Box::leak(Box::new([(
+        "/positions?user=0x2c7536e3605d9c16a7a3d7b1898e529396a65c23",
+        &*Box::leak(positions.into_boxed_str()),
+    )]));
+    let url = stub_api(routes);
+    polymarket()
+        .env("HOME", &home)
+        .args(["--data-url", &url, "--rpc-url", "http://127.0.0.1:9"])
+        .args(["--signature-type", "eoa", "ctf", "merge-all", "--dry-run"])
+        .assert()
+        .code(7);
+}
+
+#[test]
+fn ctf_proxy_sender_must_be_the_derived_proxy() {
+    let home = temp_home("ctf-sender");
+    polymarket()
+        .env("HOME", &home)
+        .args(["wallet", "import", TEST_KEY, "--plaintext"])
+        .assert()
+        .success();
+    polymarket()
+        .env("HOME", &home)
+        .args([
+            "--funder",
+            "0x56687bf447db6ffa42ffe2204a05edaa20f55839",
+            "ctf",
+            "redeem",
+            "--condition",
+            &format!("0x{}", "11".repeat(32)),
+        ])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("proxy factory"));
+}
+
+#[test]
+fn transaction_options_are_validated() {
+    let home = temp_home("tx-options");
+    let condition = format!("0x{}", "11".repeat(32));
+    for bad in [
+        vec!["--max-fee", "fast"],
+        vec!["--priority-fee", "-1"],
+        vec!["--gas-multiplier", "0"],
+        vec!["--confirmations", "0"],
+    ] {
+        polymarket()
+            .env("HOME", &home)
+            .args(["ctf", "redeem", "--condition", &condition])
+            .args(bad)
+            .assert()
+            .code(2);
+    }
+    polymarket()
+        .env("HOME", &home)
+        .args(["--private-key", TEST_KEY, "--signature-type", "eoa"])
+        .args(["--rpc-url", "http://127.0.0.1:9"])
+        .args(["ctf", "redeem", "--condition", &condition])
+        .args(["--max-fee", "1", "--priority-fee", "2"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("--priority-fee"));
+}
+
+#[test]
+fn safe_sender_reads_the_safe_before_sending() {
+    let home = temp_home("safe-sender");
+    polymarket()
+        .env("HOME", &home)
+        .args(["--private-key", TEST_KEY, "--signature-type", "gnosis-safe"])
+        .args(["--rpc-url", "http://127.0.0.1:9"])
+        .args([
+            "ctf",
+            "split",
+            "--condition",
+            &format!("0x{}", "11".repeat(32)),
+        ])
+        .args(["--amount", "1"])
+        .assert()
+        .code(7)
+        .stderr(predicate::str::contains("Safe wallet"));
+}
+
+#[test]
+fn transaction_options_belong_to_sending_commands() {
+    for args in [
+        vec!["markets", "list", "--nonce", "5"],
+        vec!["data", "positions", "--simulate"],
+        vec!["--simulate", "approve", "set"],
+        vec!["ctf", "condition-id", "--gas-limit", "1"],
+    ] {
+        polymarket().args(args).assert().code(2);
+    }
+}
+
+#[test]
+fn simulate_reaches_the_rpc_without_signing() {
+    let home = temp_home("simulate");
+    let condition = format!("0x{}", "11".repeat(32));
+    let pending = "cc".repeat(32);
+    std::fs::write(
+        home.join(".config/polymarket/transactions.json"),
+        format!("[{}]", journal_entry(&pending, 137, "pending")),
+    )
+    .unwrap();
+    let pending = format!("0x{pending}");
+    // `approve set` reads the current approvals before simulating.
+    for (command, error) in [
+        (
+            vec!["ctf", "split", "--condition", &condition, "--amount", "10"],
+            "simulate",
+        ),
+        (vec!["approve", "set"], "allowance"),
+        (
+            vec!["wallet", "send", "--to", TEST_RECIPIENT, "--amount", "1"],
+            "simulate",
+        ),
+        (
+            vec![
+                "ctf",
+                "transfer",
+                "--position",
+                "1",
+                "--amount",
+                "1",
+                "--to",
+                TEST_RECIPIENT,
+            ],
+            "simulate",
+        ),
+        (vec!["tx", "speedup", &pending], "transaction receipt"),
+    ] {
+        polymarket()
+            .env("HOME", &home)
+            .args(["--private-key", TEST_KEY, "--signature-type", "eoa"])
+            .args(["--rpc-url", "http://127.0.0.1:9"])
+            .args(command)
+            .arg("--simulate")
+            .assert()
+            .code(7)
+            .stderr(predicate::str::contains(error));
+    }
+}
+
+fn journal_entry(hash: &str, chain_id: u64, status: &str) -> String {
+    format!(
+        r#"{{"hash": "0x{hash}", "chain_id": {chain_id}, "from": "0x2c7536e3605d9c16a7a3d7b1898e529396a65c23", "nonce": 4, "to": "0x4d97dcd97ec945f40cf65f87097ace5ea0476045", "input": "0x", "gas_limit": 21000, "max_fee_per_gas": 50000000000, "max_priority_fee_per_gas": 30000000000, "sent_at": "2026-01-02T03:04:05Z", "status": "{status}"}}"#
+    )
+}
+
+#[test]
+fn tx_list_reads_the_journal_for_the_active_chain() {
+    let home = temp_home("tx-journal");
+    polymarket()
+        .env("HOME", &home)
+        .args(["tx", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No transactions found."));
+
+    std::fs::write(
+        home.join(".config/polymarket/transactions.json"),
+        format!(
+            "[{}, {}]",
+            journal_entry(&"aa".repeat(32), 137, "confirmed"),
+            journal_entry(&"bb".repeat(32), 80002, "reverted")
+        ),
+    )
+    .unwrap();
+    let output = polymarket()
+        .env("HOME", &home)
+        .args(["-o", "json", "tx", "list"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let entries = parsed.as_array().unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["status"], "confirmed");
+    assert_eq!(entries[0]["max_fee"], "50");
+    assert_eq!(entries[0]["nonce"], 4);
+
+    polymarket()
+        .env("HOME", &home)
+        .args(["-o", "json", "tx", "list", "--pending"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("[]"));
+}
+
+#[test]
+fn tx_list_keeps_stored_status_when_the_rpc_fails() {
+    let home = temp_home("tx-journal-offline");
+    std::fs::write(
+        home.join(".config/polymarket/transactions.json"),
+        format!(
+            "[{}, {}]",
+            journal_entry(&"aa".repeat(32), 137, "confirmed"),
+            journal_entry(&"bb".repeat(32), 137, "pending")
+        ),
+    )
+    .unwrap();
+    let output = polymarket()
+        .env("HOME", &home)
+        .args([
+            "--rpc-url",
+            "http://127.0.0.1:9",
+            "-o",
+            "json",
+            "tx",
+            "list",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Warning"));
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let statuses: Vec<_> = parsed
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["status"].as_str().unwrap())
+        .collect();
+    assert_eq!(statuses, ["pending", "confirmed"]);
+}
+
+#[test]
+fn tx_replacements_need_a_wallet_and_a_hash() {
+    let home = temp_home("tx-replace");
+    polymarket()
+        .env("HOME", &home)
+        .args(["tx", "speedup", "0x1234"])
+        .assert()
+        .code(2);
+    polymarket()
+        .env("HOME", &home)
+        .args(["tx", "cancel", &format!("0x{}", "aa".repeat(32))])
+        .assert()
+        .code(3);
+}
+
+#[test]
+fn wallet_balances_reads_the_chain() {
+    let home = temp_home("wallet-balances");
+    polymarket()
+        .env("HOME", &home)
+        .args(["wallet", "balances", "--token", "1"])
+        .assert()
+        .code(3);
+    polymarket()
+        .env("HOME", &home)
+        .args([
+            "--private-key",
+            TEST_KEY,
+            "wallet",
+            "balances",
+            "--token",
+            "yes",
+        ])
+        .assert()
+        .code(2);
+    polymarket()
+        .env("HOME", &home)
+        .args(["--private-key", TEST_KEY, "--signature-type", "eoa"])
+        .args([
+            "--rpc-url",
+            "http://127.0.0.1:9",
+            "--clob-url",
+            "http://127.0.0.1:9",
+        ])
+        .args(["wallet", "balances", "--token", "1,2"])
+        .assert()
+        .code(7);
+}
+
+#[test]
+fn approve_targets_and_amounts_are_validated() {
+    let home = temp_home("approve-targets");
+    for args in [
+        vec!["approve", "set", "--target", "router"],
+        vec!["approve", "revoke", "--target", "usdc"],
+        vec!["approve", "set", "--amount", "-5"],
+        vec!["approve", "set", "--amount", "0.0000001"],
+    ] {
+        polymarket()
+            .env("HOME", &home)
+            .args(["--private-key", TEST_KEY])
+            .args(args)
+            .assert()
+            .code(2);
+    }
+}
+
+#[test]
+fn approve_check_reads_the_trading_wallet() {
+    let home = temp_home("approve-check");
+    polymarket()
+        .env("HOME", &home)
+        .args(["approve", "check"])
+        .assert()
+        .code(3);
+    polymarket()
+        .env("HOME", &home)
+        .args([
+            "approve",
+            "check",
+            "--all",
+            "0x0000000000000000000000000000000000000001",
+        ])
+        .assert()
+        .code(2);
+    polymarket()
+        .env("HOME", &home)
+        .args(["--private-key", TEST_KEY, "--signature-type", "proxy"])
+        .args(["--rpc-url", "http://127.0.0.1:9"])
+        .args(["approve", "check", "--all"])
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("eoa ")
+                .and(predicate::str::contains("proxy "))
+                .and(predicate::str::contains("RPC error")),
+        );
+}
+
+const TEST_RECIPIENT: &str = "0x00000000000000000000000000000000000000aa";
+
+#[test]
+fn transfers_are_validated() {
+    let home = temp_home("transfers");
+    let zero = "0x0000000000000000000000000000000000000000";
+    for args in [
+        vec!["wallet", "send", "--to", zero, "--amount", "1"],
+        vec!["wallet", "send", "--to", TEST_RECIPIENT, "--amount", "-1"],
+        vec![
+            "wallet",
+            "send",
+            "--to",
+            TEST_RECIPIENT,
+            "--amount",
+            "1",
+            "--token",
+            "dai",
+        ],
+        vec![
+            "wallet",
+            "send",
+            "--to",
+            TEST_RECIPIENT,
+            "--amount",
+            "0.0000001",
+        ],
+        vec![
+            "ctf",
+            "transfer",
+            "--position",
+            "1",
+            "--amount",
+            "0",
+            "--to",
+            TEST_RECIPIENT,
+        ],
+        vec![
+            "ctf",
+            "transfer",
+            "--position",
+            "1",
+            "--amount",
+            "1",
+            "--to",
+            zero,
+        ],
+    ] {
+        polymarket()
+            .env("HOME", &home)
+            .args(["--private-key", TEST_KEY])
+            .args(args)
+            .assert()
+            .code(2);
+    }
+}
+
+#[test]
+fn ctf_condition_reads_the_chain() {
+    let condition = format!("0x{}", "11".repeat(32));
+    polymarket()
+        .args(["ctf", "condition", "0x1234"])
+        .assert()
+        .code(2);
+    polymarket()
+        .args(["--rpc-url", "http://127.0.0.1:9"])
+        .args(["ctf", "condition", &condition])
+        .assert()
+        .code(7);
+}